@@ -0,0 +1,63 @@
+use std::{fs, path::Path};
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion, Throughput};
+
+/// Stylesheets of increasing complexity, relative to `benches/fixtures`
+const FIXTURES: &[(&str, &str)] = &[
+    ("trivial", "trivial.scss"),
+    ("nested", "nested.scss"),
+    ("each", "each.scss"),
+    ("design_system", "design_system/index.scss"),
+];
+
+/// The size of a fixture, including every other file in its directory, as
+/// these may be imported
+fn input_size(path: &Path) -> u64 {
+    let dir = path.parent().unwrap();
+    if dir.ends_with("fixtures") {
+        return fs::metadata(path).unwrap().len();
+    }
+    fs::read_dir(dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().metadata().unwrap().len())
+        .sum()
+}
+
+pub fn compile(c: &mut Criterion) {
+    let fixtures = Path::new(env!("CARGO_MANIFEST_DIR")).join("benches/fixtures");
+    let options = grass::Options::default();
+
+    let mut group = c.benchmark_group("compile");
+    for (name, file) in FIXTURES {
+        let path = fixtures.join(file);
+        let path = path.to_str().unwrap();
+        group.throughput(Throughput::Bytes(input_size(Path::new(path))));
+        group.bench_function(*name, |b| {
+            b.iter(|| grass::from_path(black_box(path), &options).unwrap())
+        });
+    }
+
+    let input = each_map(1000);
+    group.throughput(Throughput::Bytes(input.len() as u64));
+    group.bench_function("each_map", |b| {
+        b.iter(|| grass::from_string(black_box(input.clone()), &options).unwrap())
+    });
+
+    group.finish();
+}
+
+/// Nested `@each` loops over a local map with `len` entries, so that the
+/// inner loop is entered `len` times within a scope holding the whole map
+fn each_map(len: usize) -> String {
+    let mut input = String::from(".a {\n  $map: (\n");
+    for i in 1..=len {
+        input.push_str(&format!("    \"key-{}\": {}px,\n", i, i));
+    }
+    input.push_str(
+        "  );\n\n  @each $key, $value in $map {\n    @each $side in top, bottom {\n      .#{$key}-#{$side} {\n        margin-#{$side}: $value;\n      }\n    }\n  }\n}\n",
+    );
+    input
+}
+
+criterion_group!(benches, compile);
+criterion_main!(benches);
@@ -0,0 +1,30 @@
+fn main() {
+    #[cfg(feature = "ffi")]
+    generate_c_header();
+}
+
+/// Write a C header for the FFI interface to `$OUT_DIR/grass.h`
+#[cfg(feature = "ffi")]
+fn generate_c_header() {
+    use std::{env, path::Path};
+
+    let crate_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let out_dir = env::var("OUT_DIR").unwrap();
+
+    println!("cargo:rerun-if-changed=src/grass_c.rs");
+
+    // C enum variants share a single namespace, so they are prefixed with the
+    // name of their enum, e.g. `GRASS_SOURCE_MAP_NONE`
+    let mut config = cbindgen::Config::default();
+    config.enumeration.rename_variants = cbindgen::RenameRule::QualifiedScreamingSnakeCase;
+
+    cbindgen::Builder::new()
+        .with_config(config)
+        .with_src(Path::new(&crate_dir).join("src").join("grass_c.rs"))
+        .with_language(cbindgen::Language::C)
+        .with_include_guard("GRASS_H")
+        .with_documentation(true)
+        .generate()
+        .expect("unable to generate C header")
+        .write_to_file(Path::new(&out_dir).join("grass.h"));
+}
@@ -0,0 +1,14 @@
+//! Compile arbitrary input, looking for panics and hangs
+//!
+//! Errors are expected for most inputs, and are ignored. Run with
+//! `cargo fuzz run parse`, passing e.g. `-- -timeout=10` to report inputs
+//! that take too long to compile.
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(input) = std::str::from_utf8(data) {
+        let _ = grass::from_string(input.to_owned(), &grass::Options::default());
+    }
+});
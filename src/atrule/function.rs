@@ -1,12 +1,19 @@
+use std::path::PathBuf;
+
 use codemap::Span;
 
-use crate::{args::FuncArgs, scope::Scope, Token};
+use crate::{args::FuncArgs, module::ModuleId, scope::Scope, Token};
 
 #[derive(Debug, Clone)]
 pub(crate) struct Function {
     pub scope: Scope,
     pub args: FuncArgs,
     pub body: Vec<Token>,
+    /// The module this function was declared in
+    pub module: ModuleId,
+    /// The file this function was declared in, against which urls in its
+    /// body are resolved
+    pub path: PathBuf,
     pos: Span,
 }
 
@@ -19,11 +26,20 @@ impl PartialEq for Function {
 impl Eq for Function {}
 
 impl Function {
-    pub fn new(scope: Scope, args: FuncArgs, body: Vec<Token>, pos: Span) -> Self {
+    pub fn new(
+        scope: Scope,
+        args: FuncArgs,
+        body: Vec<Token>,
+        module: ModuleId,
+        path: PathBuf,
+        pos: Span,
+    ) -> Self {
         Function {
             scope,
             args,
             body,
+            module,
+            path,
             pos,
         }
     }
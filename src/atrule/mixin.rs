@@ -1,4 +1,6 @@
-use crate::{args::FuncArgs, scope::Scope, Token};
+use std::path::PathBuf;
+
+use crate::{args::FuncArgs, module::ModuleId, parse::common::ContextFlags, scope::Scope, Token};
 
 #[derive(Debug, Clone)]
 pub(crate) struct Mixin {
@@ -6,6 +8,11 @@ pub(crate) struct Mixin {
     pub args: FuncArgs,
     pub body: Vec<Token>,
     pub accepts_content_block: bool,
+    /// The module this mixin was declared in
+    pub module: ModuleId,
+    /// The file this mixin was declared in, against which urls in its body
+    /// are resolved
+    pub path: PathBuf,
 }
 
 impl Mixin {
@@ -14,12 +21,16 @@ impl Mixin {
         args: FuncArgs,
         body: Vec<Token>,
         accepts_content_block: bool,
+        module: ModuleId,
+        path: PathBuf,
     ) -> Self {
         Mixin {
             scope,
             args,
             body,
             accepts_content_block,
+            module,
+            path,
         }
     }
 }
@@ -29,6 +40,12 @@ pub(crate) struct Content {
     pub content: Option<Vec<Token>>,
     pub content_args: Option<FuncArgs>,
     pub scope: Scope,
+    /// The module the content block was written in
+    pub module: ModuleId,
+    /// The file the content block was written in
+    pub path: PathBuf,
+    /// The context of the `@include` the content block was passed to
+    pub flags: ContextFlags,
 }
 
 impl Content {
@@ -37,6 +54,9 @@ impl Content {
             content: None,
             content_args: None,
             scope: Scope::new(),
+            module: ModuleId::ROOT,
+            path: PathBuf::new(),
+            flags: ContextFlags::empty(),
         }
     }
 }
@@ -7,6 +7,7 @@ use crate::{
     color::Color,
     common::QuoteKind,
     error::SassResult,
+    module::Module,
     parse::Parser,
     unit::Unit,
     value::{Number, Value},
@@ -515,3 +516,12 @@ pub(crate) fn declare(f: &mut GlobalFunctionMap) {
     f.insert("complement", Builtin::new(complement));
     f.insert("invert", Builtin::new(invert));
 }
+
+pub(crate) fn declare_module(module: &mut Module) {
+    module.insert_builtin_fn("hue", hue);
+    module.insert_builtin_fn("saturation", saturation);
+    module.insert_builtin_fn("lightness", lightness);
+    module.insert_builtin_fn("grayscale", grayscale);
+    module.insert_builtin_fn("complement", complement);
+    module.insert_builtin_fn("invert", invert);
+}
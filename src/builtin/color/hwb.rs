@@ -0,0 +1,138 @@
+use num_traits::One;
+
+use crate::{
+    args::CallArgs,
+    color::Color,
+    error::SassResult,
+    module::Module,
+    parse::Parser,
+    unit::Unit,
+    value::{Number, Value},
+};
+
+fn hwb(mut args: CallArgs, parser: &mut Parser<'_>) -> SassResult<Value> {
+    args.max_args(4)?;
+
+    let hue = match parser.arg(&mut args, 0, "hue")? {
+        Value::Dimension(n, _) => n,
+        v => {
+            return Err((
+                format!("$hue: {} is not a number.", v.to_css_string(args.span())?),
+                args.span(),
+            )
+                .into())
+        }
+    };
+
+    let whiteness = match parser.arg(&mut args, 1, "whiteness")? {
+        Value::Dimension(n, Unit::Percent) => bound!(args, "whiteness", n, Unit::Percent, 0, 100),
+        v @ Value::Dimension(..) => {
+            return Err((
+                format!(
+                    "$whiteness: Expected {} to have unit \"%\".",
+                    v.to_css_string(args.span())?
+                ),
+                args.span(),
+            )
+                .into())
+        }
+        v => {
+            return Err((
+                format!(
+                    "$whiteness: {} is not a number.",
+                    v.to_css_string(args.span())?
+                ),
+                args.span(),
+            )
+                .into())
+        }
+    };
+
+    let blackness = match parser.arg(&mut args, 2, "blackness")? {
+        Value::Dimension(n, Unit::Percent) => bound!(args, "blackness", n, Unit::Percent, 0, 100),
+        v @ Value::Dimension(..) => {
+            return Err((
+                format!(
+                    "$blackness: Expected {} to have unit \"%\".",
+                    v.to_css_string(args.span())?
+                ),
+                args.span(),
+            )
+                .into())
+        }
+        v => {
+            return Err((
+                format!(
+                    "$blackness: {} is not a number.",
+                    v.to_css_string(args.span())?
+                ),
+                args.span(),
+            )
+                .into())
+        }
+    };
+
+    let alpha = match parser.default_arg(
+        &mut args,
+        3,
+        "alpha",
+        Value::Dimension(Number::one(), Unit::None),
+    )? {
+        Value::Dimension(n, Unit::None) => n,
+        Value::Dimension(n, Unit::Percent) => n / Number::from(100),
+        v @ Value::Dimension(..) => {
+            return Err((
+                format!(
+                    "$alpha: Expected {} to have no units or \"%\".",
+                    v.to_css_string(args.span())?
+                ),
+                args.span(),
+            )
+                .into())
+        }
+        v => {
+            return Err((
+                format!("$alpha: {} is not a number.", v.to_css_string(args.span())?),
+                args.span(),
+            )
+                .into())
+        }
+    };
+
+    Ok(Value::Color(Box::new(Color::from_hwb(
+        hue,
+        whiteness / Number::from(100),
+        blackness / Number::from(100),
+        alpha,
+    ))))
+}
+
+fn whiteness(mut args: CallArgs, parser: &mut Parser<'_>) -> SassResult<Value> {
+    args.max_args(1)?;
+    match parser.arg(&mut args, 0, "color")? {
+        Value::Color(c) => Ok(Value::Dimension(c.whiteness(), Unit::Percent)),
+        v => Err((
+            format!("$color: {} is not a color.", v.to_css_string(args.span())?),
+            args.span(),
+        )
+            .into()),
+    }
+}
+
+fn blackness(mut args: CallArgs, parser: &mut Parser<'_>) -> SassResult<Value> {
+    args.max_args(1)?;
+    match parser.arg(&mut args, 0, "color")? {
+        Value::Color(c) => Ok(Value::Dimension(c.blackness(), Unit::Percent)),
+        v => Err((
+            format!("$color: {} is not a color.", v.to_css_string(args.span())?),
+            args.span(),
+        )
+            .into()),
+    }
+}
+
+pub(crate) fn declare_module(module: &mut Module) {
+    module.insert_builtin_fn("hwb", hwb);
+    module.insert_builtin_fn("whiteness", whiteness);
+    module.insert_builtin_fn("blackness", blackness);
+}
@@ -1,6 +1,9 @@
 use super::{Builtin, GlobalFunctionMap};
 
+use crate::module::Module;
+
 mod hsl;
+mod hwb;
 mod opacity;
 mod other;
 mod rgb;
@@ -11,3 +14,11 @@ pub(crate) fn declare(f: &mut GlobalFunctionMap) {
     other::declare(f);
     rgb::declare(f);
 }
+
+pub(crate) fn declare_module(module: &mut Module) {
+    hsl::declare_module(module);
+    hwb::declare_module(module);
+    opacity::declare_module(module);
+    other::declare_module(module);
+    rgb::declare_module(module);
+}
@@ -1,8 +1,8 @@
 use super::{Builtin, GlobalFunctionMap};
 
 use crate::{
-    args::CallArgs, common::QuoteKind, error::SassResult, parse::Parser, unit::Unit, value::Number,
-    value::Value,
+    args::CallArgs, common::QuoteKind, error::SassResult, module::Module, parse::Parser,
+    unit::Unit, value::Number, value::Value,
 };
 
 fn alpha(mut args: CallArgs, parser: &mut Parser<'_>) -> SassResult<Value> {
@@ -153,3 +153,7 @@ pub(crate) fn declare(f: &mut GlobalFunctionMap) {
     f.insert("transparentize", Builtin::new(transparentize));
     f.insert("fade-out", Builtin::new(fade_out));
 }
+
+pub(crate) fn declare_module(module: &mut Module) {
+    module.insert_builtin_fn("alpha", alpha);
+}
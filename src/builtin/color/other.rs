@@ -7,6 +7,7 @@ use crate::{
     color::Color,
     common::QuoteKind,
     error::SassResult,
+    module::Module,
     parse::Parser,
     unit::Unit,
     value::{Number, Value},
@@ -54,6 +55,39 @@ macro_rules! opt_hsl {
     };
 }
 
+macro_rules! opt_hwb {
+    ($args:ident, $name:ident, $arg:literal, $low:literal, $high:literal, $parser:ident) => {
+        let $name = match $parser.default_named_arg(&mut $args, $arg, Value::Null)? {
+            Value::Dimension(n, Unit::Percent) => {
+                Some(bound!($args, $arg, n, Unit::Percent, $low, $high) / Number::from(100))
+            }
+            v @ Value::Dimension(..) => {
+                return Err((
+                    format!(
+                        "${}: Expected {} to have unit \"%\".",
+                        $arg,
+                        v.to_css_string($args.span())?
+                    ),
+                    $args.span(),
+                )
+                    .into())
+            }
+            Value::Null => None,
+            v => {
+                return Err((
+                    format!(
+                        "${}: {} is not a number.",
+                        $arg,
+                        v.to_css_string($args.span())?
+                    ),
+                    $args.span(),
+                )
+                    .into())
+            }
+        };
+    };
+}
+
 fn change_color(mut args: CallArgs, parser: &mut Parser<'_>) -> SassResult<Value> {
     if parser.positional_arg(&mut args, 1).is_some() {
         return Err((
@@ -138,15 +172,6 @@ fn adjust_color(mut args: CallArgs, parser: &mut Parser<'_>) -> SassResult<Value
     opt_rgba!(args, green, "green", -255, 255, parser);
     opt_rgba!(args, blue, "blue", -255, 255, parser);
 
-    if red.is_some() || green.is_some() || blue.is_some() {
-        return Ok(Value::Color(Box::new(Color::from_rgba(
-            color.red() + red.unwrap_or_else(Number::zero),
-            color.green() + green.unwrap_or_else(Number::zero),
-            color.blue() + blue.unwrap_or_else(Number::zero),
-            color.alpha() + alpha.unwrap_or_else(Number::zero),
-        ))));
-    }
-
     let hue = match parser.default_named_arg(&mut args, "hue", Value::Null)? {
         Value::Dimension(n, _) => Some(n),
         Value::Null => None,
@@ -161,8 +186,54 @@ fn adjust_color(mut args: CallArgs, parser: &mut Parser<'_>) -> SassResult<Value
 
     opt_hsl!(args, saturation, "saturation", -100, 100, parser);
     opt_hsl!(args, luminance, "lightness", -100, 100, parser);
+    opt_hwb!(args, whiteness, "whiteness", -100, 100, parser);
+    opt_hwb!(args, blackness, "blackness", -100, 100, parser);
 
-    if hue.is_some() || saturation.is_some() || luminance.is_some() {
+    let has_rgb = red.is_some() || green.is_some() || blue.is_some();
+    let has_sl = saturation.is_some() || luminance.is_some();
+    let has_wb = whiteness.is_some() || blackness.is_some();
+
+    if has_rgb && (has_sl || has_wb || hue.is_some()) {
+        return Err((
+            format!(
+                "RGB parameters may not be passed along with {} parameters.",
+                if has_wb { "HWB" } else { "HSL" }
+            ),
+            args.span(),
+        )
+            .into());
+    }
+
+    if has_sl && has_wb {
+        return Err((
+            "HSL parameters may not be passed along with HWB parameters.",
+            args.span(),
+        )
+            .into());
+    }
+
+    if has_rgb {
+        return Ok(Value::Color(Box::new(Color::from_rgba(
+            color.red() + red.unwrap_or_else(Number::zero),
+            color.green() + green.unwrap_or_else(Number::zero),
+            color.blue() + blue.unwrap_or_else(Number::zero),
+            color.alpha() + alpha.unwrap_or_else(Number::zero),
+        ))));
+    }
+
+    if has_wb {
+        let this_hue = color.as_hsla().0;
+        return Ok(Value::Color(Box::new(Color::from_hwb(
+            this_hue + hue.unwrap_or_else(Number::zero),
+            (color.whiteness() / Number::from(100) + whiteness.unwrap_or_else(Number::zero))
+                .clamp(0, 1),
+            (color.blackness() / Number::from(100) + blackness.unwrap_or_else(Number::zero))
+                .clamp(0, 1),
+            color.alpha() + alpha.unwrap_or_else(Number::zero),
+        ))));
+    }
+
+    if hue.is_some() || has_sl {
         // Color::as_hsla() returns more exact values than Color::hue(), etc.
         let (this_hue, this_saturation, this_luminance, this_alpha) = color.as_hsla();
         return Ok(Value::Color(Box::new(Color::from_hsla(
@@ -315,9 +386,72 @@ fn ie_hex_str(mut args: CallArgs, parser: &mut Parser<'_>) -> SassResult<Value>
     Ok(Value::String(color.to_ie_hex_str(), QuoteKind::None))
 }
 
+/// Color spaces which are known, but which grass can't yet represent
+pub(super) const UNSUPPORTED_SPACES: &[&str] = &[
+    "lab",
+    "lch",
+    "oklab",
+    "oklch",
+    "display-p3",
+    "a98-rgb",
+    "prophoto-rgb",
+    "rec2020",
+    "srgb-linear",
+    "xyz",
+    "xyz-d50",
+    "xyz-d65",
+];
+
+fn to_space(mut args: CallArgs, parser: &mut Parser<'_>) -> SassResult<Value> {
+    args.max_args(2)?;
+    let color = match parser.arg(&mut args, 0, "color")? {
+        Value::Color(c) => c,
+        v => {
+            return Err((
+                format!("$color: {} is not a color.", v.to_css_string(args.span())?),
+                args.span(),
+            )
+                .into())
+        }
+    };
+    let space = match parser.arg(&mut args, 1, "space")? {
+        Value::String(s, ..) => s.to_ascii_lowercase(),
+        v => {
+            return Err((
+                format!("$space: {} is not a string.", v.to_css_string(args.span())?),
+                args.span(),
+            )
+                .into())
+        }
+    };
+
+    match space.as_str() {
+        // colors are always stored as RGB, which may be emitted as any of these
+        "rgb" | "srgb" | "hsl" | "hwb" => Ok(Value::Color(color)),
+        s if UNSUPPORTED_SPACES.contains(&s) => Err((
+            format!("$space: The \"{}\" color space isn't supported yet.", s),
+            args.span(),
+        )
+            .into()),
+        s => Err((
+            format!("$space: Unknown color space \"{}\".", s),
+            args.span(),
+        )
+            .into()),
+    }
+}
+
 pub(crate) fn declare(f: &mut GlobalFunctionMap) {
     f.insert("change-color", Builtin::new(change_color));
     f.insert("adjust-color", Builtin::new(adjust_color));
     f.insert("scale-color", Builtin::new(scale_color));
     f.insert("ie-hex-str", Builtin::new(ie_hex_str));
 }
+
+pub(crate) fn declare_module(module: &mut Module) {
+    module.insert_builtin_fn("adjust", adjust_color);
+    module.insert_builtin_fn("change", change_color);
+    module.insert_builtin_fn("scale", scale_color);
+    module.insert_builtin_fn("ie-hex-str", ie_hex_str);
+    module.insert_builtin_fn("to-space", to_space);
+}
@@ -1,4 +1,4 @@
-use super::{Builtin, GlobalFunctionMap};
+use super::{other::UNSUPPORTED_SPACES, Builtin, GlobalFunctionMap};
 
 use num_traits::One;
 
@@ -7,6 +7,7 @@ use crate::{
     color::Color,
     common::QuoteKind,
     error::SassResult,
+    module::Module,
     parse::Parser,
     unit::Unit,
     value::{Number, Value},
@@ -388,7 +389,7 @@ fn blue(mut args: CallArgs, parser: &mut Parser<'_>) -> SassResult<Value> {
 }
 
 fn mix(mut args: CallArgs, parser: &mut Parser<'_>) -> SassResult<Value> {
-    args.max_args(3)?;
+    args.max_args(4)?;
     let color1 = match parser.arg(&mut args, 0, "color1")? {
         Value::Color(c) => c,
         v => {
@@ -429,7 +430,38 @@ fn mix(mut args: CallArgs, parser: &mut Parser<'_>) -> SassResult<Value> {
                 .into())
         }
     };
-    Ok(Value::Color(Box::new(color1.mix(&color2, weight))))
+
+    let method = match parser.default_arg(&mut args, 3, "method", Value::Null)? {
+        Value::String(s, ..) => Some(s.to_ascii_lowercase()),
+        Value::Null => None,
+        v => {
+            return Err((
+                format!(
+                    "$method: {} is not a string.",
+                    v.to_css_string(args.span())?
+                ),
+                args.span(),
+            )
+                .into())
+        }
+    };
+
+    match method.as_deref() {
+        None => Ok(Value::Color(Box::new(color1.mix(&color2, weight)))),
+        Some("rgb") | Some("srgb") => Ok(Value::Color(Box::new(
+            color1.interpolate_srgb(&color2, weight),
+        ))),
+        Some(s) if s == "hsl" || s == "hwb" || UNSUPPORTED_SPACES.contains(&s) => Err((
+            format!("$method: The \"{}\" color space isn't supported yet.", s),
+            args.span(),
+        )
+            .into()),
+        Some(s) => Err((
+            format!("$method: Unknown color space \"{}\".", s),
+            args.span(),
+        )
+            .into()),
+    }
 }
 
 pub(crate) fn declare(f: &mut GlobalFunctionMap) {
@@ -440,3 +472,10 @@ pub(crate) fn declare(f: &mut GlobalFunctionMap) {
     f.insert("blue", Builtin::new(blue));
     f.insert("mix", Builtin::new(mix));
 }
+
+pub(crate) fn declare_module(module: &mut Module) {
+    module.insert_builtin_fn("red", red);
+    module.insert_builtin_fn("green", green);
+    module.insert_builtin_fn("blue", blue);
+    module.insert_builtin_fn("mix", mix);
+}
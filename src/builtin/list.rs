@@ -6,6 +6,7 @@ use crate::{
     args::CallArgs,
     common::{Brackets, ListSeparator, QuoteKind},
     error::SassResult,
+    module::Module,
     parse::{HigherIntermediateValue, Parser, ValueVisitor},
     unit::Unit,
     value::{Number, Value},
@@ -136,9 +137,10 @@ fn append(mut args: CallArgs, parser: &mut Parser<'_>) -> SassResult<Value> {
             "auto" => sep,
             "comma" => ListSeparator::Comma,
             "space" => ListSeparator::Space,
+            "slash" => ListSeparator::Slash,
             _ => {
                 return Err((
-                    "$separator: Must be \"space\", \"comma\", or \"auto\".",
+                    "$separator: Must be \"space\", \"comma\", \"slash\", or \"auto\".",
                     args.span(),
                 )
                     .into())
@@ -189,9 +191,10 @@ fn join(mut args: CallArgs, parser: &mut Parser<'_>) -> SassResult<Value> {
             }
             "comma" => ListSeparator::Comma,
             "space" => ListSeparator::Space,
+            "slash" => ListSeparator::Slash,
             _ => {
                 return Err((
-                    "$separator: Must be \"space\", \"comma\", or \"auto\".",
+                    "$separator: Must be \"space\", \"comma\", \"slash\", or \"auto\".",
                     args.span(),
                 )
                     .into())
@@ -291,6 +294,43 @@ fn zip(args: CallArgs, parser: &mut Parser<'_>) -> SassResult<Value> {
     Ok(Value::List(result, ListSeparator::Comma, Brackets::None))
 }
 
+fn slash(args: CallArgs, parser: &mut Parser<'_>) -> SassResult<Value> {
+    let span = args.span();
+    let elements = parser
+        .variadic_args(args)?
+        .into_iter()
+        .map(|x| x.node)
+        .collect::<Vec<Value>>();
+
+    if elements.len() < 2 {
+        return Err(("At least two elements are required.", span).into());
+    }
+
+    Ok(Value::List(elements, ListSeparator::Slash, Brackets::None))
+}
+
+/// `nth($list, 1)` or `nth($list, -1)`, for `list.first` and `list.last`
+fn first_or_last(mut args: CallArgs, parser: &mut Parser<'_>, first: bool) -> SassResult<Value> {
+    args.max_args(1)?;
+    let mut list = parser.arg(&mut args, 0, "list")?.as_list();
+    if list.is_empty() {
+        return Err(("$list: List may not be empty.", args.span()).into());
+    }
+    Ok(if first {
+        list.swap_remove(0)
+    } else {
+        list.pop().unwrap()
+    })
+}
+
+fn first(args: CallArgs, parser: &mut Parser<'_>) -> SassResult<Value> {
+    first_or_last(args, parser, true)
+}
+
+fn last(args: CallArgs, parser: &mut Parser<'_>) -> SassResult<Value> {
+    first_or_last(args, parser, false)
+}
+
 pub(crate) fn declare(f: &mut GlobalFunctionMap) {
     f.insert("length", Builtin::new(length));
     f.insert("nth", Builtin::new(nth));
@@ -302,3 +342,18 @@ pub(crate) fn declare(f: &mut GlobalFunctionMap) {
     f.insert("index", Builtin::new(index));
     f.insert("zip", Builtin::new(zip));
 }
+
+pub(crate) fn declare_module(module: &mut Module) {
+    module.insert_builtin_fn("append", append);
+    module.insert_builtin_fn("index", index);
+    module.insert_builtin_fn("is-bracketed", is_bracketed);
+    module.insert_builtin_fn("join", join);
+    module.insert_builtin_fn("length", length);
+    module.insert_builtin_fn("nth", nth);
+    module.insert_builtin_fn("separator", list_separator);
+    module.insert_builtin_fn("set-nth", set_nth);
+    module.insert_builtin_fn("zip", zip);
+    module.insert_builtin_fn("slash", slash);
+    module.insert_builtin_fn("first", first);
+    module.insert_builtin_fn("last", last);
+}
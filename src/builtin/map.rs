@@ -4,6 +4,7 @@ use crate::{
     args::CallArgs,
     common::{Brackets, ListSeparator},
     error::SassResult,
+    module::Module,
     parse::Parser,
     value::{SassMap, Value},
 };
@@ -39,7 +40,7 @@ fn map_has_key(mut args: CallArgs, parser: &mut Parser<'_>) -> SassResult<Value>
                 .into())
         }
     };
-    Ok(Value::bool(map.get(&key, args.span(), parser)?.is_some()))
+    Ok(Value::bool(map.contains_key(&key, args.span(), parser)?))
 }
 
 fn map_keys(mut args: CallArgs, parser: &mut Parser<'_>) -> SassResult<Value> {
@@ -106,7 +107,7 @@ fn map_merge(mut args: CallArgs, parser: &mut Parser<'_>) -> SassResult<Value> {
                 .into())
         }
     };
-    map1.merge(map2);
+    map1.merge(map2, args.span(), parser)?;
     Ok(Value::Map(map1))
 }
 
@@ -122,9 +123,10 @@ fn map_remove(mut args: CallArgs, parser: &mut Parser<'_>) -> SassResult<Value>
                 .into())
         }
     };
+    let span = args.span();
     let keys = parser.variadic_args(args)?;
     for key in keys {
-        map.remove(&key);
+        map.remove(&key, span, parser)?;
     }
     Ok(Value::Map(map))
 }
@@ -137,3 +139,12 @@ pub(crate) fn declare(f: &mut GlobalFunctionMap) {
     f.insert("map-merge", Builtin::new(map_merge));
     f.insert("map-remove", Builtin::new(map_remove));
 }
+
+pub(crate) fn declare_module(module: &mut Module) {
+    module.insert_builtin_fn("get", map_get);
+    module.insert_builtin_fn("has-key", map_has_key);
+    module.insert_builtin_fn("keys", map_keys);
+    module.insert_builtin_fn("values", map_values);
+    module.insert_builtin_fn("merge", map_merge);
+    module.insert_builtin_fn("remove", map_remove);
+}
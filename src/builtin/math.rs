@@ -8,6 +8,7 @@ use rand::Rng;
 use crate::{
     args::CallArgs,
     error::SassResult,
+    module::Module,
     parse::{HigherIntermediateValue, Parser, ValueVisitor},
     unit::Unit,
     value::{Number, Value},
@@ -140,7 +141,7 @@ fn random(mut args: CallArgs, parser: &mut Parser<'_>) -> SassResult<Value> {
     let limit = match parser.default_arg(&mut args, 0, "limit", Value::Null)? {
         Value::Dimension(n, _) => n,
         Value::Null => {
-            let mut rng = rand::thread_rng();
+            let mut rng = super::rng();
             return Ok(Value::Dimension(
                 Number::from(rng.gen_range(0.0, 1.0)),
                 Unit::None,
@@ -182,7 +183,7 @@ fn random(mut args: CallArgs, parser: &mut Parser<'_>) -> SassResult<Value> {
         }
     };
 
-    let mut rng = rand::thread_rng();
+    let mut rng = super::rng();
     Ok(Value::Dimension(
         Number::from(rng.gen_range(0, limit) + 1),
         Unit::None,
@@ -260,4 +261,31 @@ pub(crate) fn declare(f: &mut GlobalFunctionMap) {
     f.insert("comparable", Builtin::new(comparable));
     #[cfg(feature = "random")]
     f.insert("random", Builtin::new(random));
+    #[cfg(not(feature = "random"))]
+    f.insert("random", Builtin::new(super::random_disabled));
+}
+
+pub(crate) fn declare_module(module: &mut Module) {
+    module.insert_builtin_var(
+        "pi",
+        Value::Dimension(Number::from(std::f64::consts::PI), Unit::None),
+    );
+    module.insert_builtin_var(
+        "e",
+        Value::Dimension(Number::from(std::f64::consts::E), Unit::None),
+    );
+    module.insert_builtin_fn("percentage", percentage);
+    module.insert_builtin_fn("round", round);
+    module.insert_builtin_fn("ceil", ceil);
+    module.insert_builtin_fn("floor", floor);
+    module.insert_builtin_fn("abs", abs);
+    module.insert_builtin_fn("min", min);
+    module.insert_builtin_fn("max", max);
+    module.insert_builtin_fn("compatible", comparable);
+    module.insert_builtin_fn("unit", super::meta::unit);
+    module.insert_builtin_fn("is-unitless", super::meta::unitless);
+    #[cfg(feature = "random")]
+    module.insert_builtin_fn("random", random);
+    #[cfg(not(feature = "random"))]
+    module.insert_builtin_fn("random", super::random_disabled);
 }
@@ -1,16 +1,49 @@
 use super::{Builtin, GlobalFunctionMap, GLOBAL_FUNCTIONS};
 
-use codemap::Spanned;
+use codemap::{Span, Spanned};
 
 use crate::{
     args::CallArgs,
-    common::QuoteKind,
+    common::{Brackets, Identifier, ListSeparator, QuoteKind},
     error::SassResult,
-    parse::Parser,
+    module::{Configuration, Module, ModuleId},
+    parse::{Parser, Stmt},
     unit::Unit,
-    value::{SassFunction, Value},
+    value::{css_function, SassFunction, SassMap, Value},
+    Token,
 };
 
+/// Parse the optional `$module` argument, the namespace of a module loaded
+/// with `@use`
+fn module_arg(
+    args: &mut CallArgs,
+    parser: &mut Parser<'_>,
+    position: usize,
+) -> SassResult<Option<String>> {
+    match parser.default_arg(args, position, "module", Value::Null)? {
+        Value::String(s, ..) => Ok(Some(s)),
+        Value::Null => Ok(None),
+        v => Err((
+            format!(
+                "$module: {} is not a string.",
+                v.to_css_string(args.span())?
+            ),
+            args.span(),
+        )
+            .into()),
+    }
+}
+
+fn get_module(namespace: String, span: Span, parser: &Parser<'_>) -> SassResult<ModuleId> {
+    parser.modules.get_namespace(
+        parser.module,
+        &Spanned {
+            node: namespace.into(),
+            span,
+        },
+    )
+}
+
 fn if_(mut args: CallArgs, parser: &mut Parser<'_>) -> SassResult<Value> {
     args.max_args(3)?;
     if parser.arg(&mut args, 0, "condition")?.is_true() {
@@ -53,7 +86,7 @@ fn feature_exists(mut args: CallArgs, parser: &mut Parser<'_>) -> SassResult<Val
     }
 }
 
-fn unit(mut args: CallArgs, parser: &mut Parser<'_>) -> SassResult<Value> {
+pub(super) fn unit(mut args: CallArgs, parser: &mut Parser<'_>) -> SassResult<Value> {
     args.max_args(1)?;
     let unit = match parser.arg(&mut args, 0, "number")? {
         Value::Dimension(_, u) => u.to_string(),
@@ -77,7 +110,7 @@ fn type_of(mut args: CallArgs, parser: &mut Parser<'_>) -> SassResult<Value> {
     Ok(Value::String(value.kind().to_owned(), QuoteKind::None))
 }
 
-fn unitless(mut args: CallArgs, parser: &mut Parser<'_>) -> SassResult<Value> {
+pub(super) fn unitless(mut args: CallArgs, parser: &mut Parser<'_>) -> SassResult<Value> {
     args.max_args(1)?;
     #[allow(clippy::match_same_arms)]
     Ok(match parser.arg(&mut args, 0, "number")? {
@@ -105,7 +138,7 @@ fn variable_exists(mut args: CallArgs, parser: &mut Parser<'_>) -> SassResult<Va
             parser
                 .scopes
                 .last()
-                .var_exists(&s.into(), parser.global_scope),
+                .var_exists(&s.into(), parser.global_scope()),
         )),
         v => Err((
             format!("$name: {} is not a string.", v.to_css_string(args.span())?),
@@ -116,49 +149,72 @@ fn variable_exists(mut args: CallArgs, parser: &mut Parser<'_>) -> SassResult<Va
 }
 
 fn global_variable_exists(mut args: CallArgs, parser: &mut Parser<'_>) -> SassResult<Value> {
-    args.max_args(1)?;
-    match parser.arg(&mut args, 0, "name")? {
-        Value::String(s, _) => Ok(Value::bool(
-            parser.global_scope.var_exists_no_global(&s.into()),
-        )),
-        v => Err((
-            format!("$name: {} is not a string.", v.to_css_string(args.span())?),
-            args.span(),
-        )
-            .into()),
-    }
+    args.max_args(2)?;
+    let name = match parser.arg(&mut args, 0, "name")? {
+        Value::String(s, _) => s,
+        v => {
+            return Err((
+                format!("$name: {} is not a string.", v.to_css_string(args.span())?),
+                args.span(),
+            )
+                .into())
+        }
+    };
+    Ok(Value::bool(match module_arg(&mut args, parser, 1)? {
+        Some(module) => {
+            let module = get_module(module, args.span(), parser)?;
+            parser
+                .modules
+                .get_var(
+                    module,
+                    Spanned {
+                        node: name.into(),
+                        span: args.span(),
+                    },
+                )
+                .is_ok()
+        }
+        None => parser.global_scope().var_exists_no_global(&name.into()),
+    }))
 }
 
 fn mixin_exists(mut args: CallArgs, parser: &mut Parser<'_>) -> SassResult<Value> {
     args.max_args(2)?;
-    match parser.arg(&mut args, 0, "name")? {
-        Value::String(s, _) => Ok(Value::bool(
-            parser.scopes.last().mixin_exists(&s, parser.global_scope),
-        )),
-        v => Err((
-            format!("$name: {} is not a string.", v.to_css_string(args.span())?),
-            args.span(),
-        )
-            .into()),
-    }
+    let name = match parser.arg(&mut args, 0, "name")? {
+        Value::String(s, _) => s,
+        v => {
+            return Err((
+                format!("$name: {} is not a string.", v.to_css_string(args.span())?),
+                args.span(),
+            )
+                .into())
+        }
+    };
+    Ok(Value::bool(match module_arg(&mut args, parser, 1)? {
+        Some(module) => {
+            let module = get_module(module, args.span(), parser)?;
+            let name = Identifier::from(name);
+            parser.modules.get_builtin_mixin(module, &name).is_some()
+                || parser
+                    .modules
+                    .get_mixin(
+                        module,
+                        Spanned {
+                            node: name,
+                            span: args.span(),
+                        },
+                    )
+                    .is_ok()
+        }
+        None => parser
+            .scopes
+            .last()
+            .mixin_exists(&name, parser.global_scope()),
+    }))
 }
 
 fn function_exists(mut args: CallArgs, parser: &mut Parser<'_>) -> SassResult<Value> {
     args.max_args(2)?;
-    match parser.arg(&mut args, 0, "name")? {
-        Value::String(s, _) => Ok(Value::bool(
-            parser.scopes.last().fn_exists(&s, parser.global_scope),
-        )),
-        v => Err((
-            format!("$name: {} is not a string.", v.to_css_string(args.span())?),
-            args.span(),
-        )
-            .into()),
-    }
-}
-
-fn get_function(mut args: CallArgs, parser: &mut Parser<'_>) -> SassResult<Value> {
-    args.max_args(3)?;
     let name = match parser.arg(&mut args, 0, "name")? {
         Value::String(s, _) => s,
         v => {
@@ -169,23 +225,42 @@ fn get_function(mut args: CallArgs, parser: &mut Parser<'_>) -> SassResult<Value
                 .into())
         }
     };
-    let css = parser
-        .default_arg(&mut args, 1, "css", Value::False)?
-        .is_true();
-    let module = match parser.default_arg(&mut args, 2, "module", Value::Null)? {
-        Value::String(s, ..) => Some(s),
-        Value::Null => None,
+    Ok(Value::bool(match module_arg(&mut args, parser, 1)? {
+        Some(module) => {
+            let module = get_module(module, args.span(), parser)?;
+            let name = Identifier::from(name);
+            parser.modules.get_builtin_fn(module, &name).is_some()
+                || parser
+                    .modules
+                    .get_fn(
+                        module,
+                        Spanned {
+                            node: name,
+                            span: args.span(),
+                        },
+                    )
+                    .is_ok()
+        }
+        None => parser.scopes.last().fn_exists(&name, parser.global_scope()),
+    }))
+}
+
+fn get_function(mut args: CallArgs, parser: &mut Parser<'_>) -> SassResult<Value> {
+    args.max_args(3)?;
+    let name = match parser.arg(&mut args, 0, "name")? {
+        Value::String(s, _) => s,
         v => {
             return Err((
-                format!(
-                    "$module: {} is not a string.",
-                    v.to_css_string(args.span())?
-                ),
+                format!("$name: {} is not a string.", v.to_css_string(args.span())?),
                 args.span(),
             )
                 .into())
         }
     };
+    let css = parser
+        .default_arg(&mut args, 1, "css", Value::False)?
+        .is_true();
+    let module = module_arg(&mut args, parser, 2)?;
 
     if module.is_some() && css {
         return Err((
@@ -195,16 +270,43 @@ fn get_function(mut args: CallArgs, parser: &mut Parser<'_>) -> SassResult<Value
             .into());
     }
 
-    let func = match parser.scopes.last().get_fn(
-        Spanned {
-            node: &name,
-            span: args.span(),
-        },
-        parser.global_scope,
-    ) {
-        Ok(f) => SassFunction::UserDefined(Box::new(f), name.into()),
-        Err(..) => match GLOBAL_FUNCTIONS.get(name.as_str()) {
-            Some(f) => SassFunction::Builtin(f.clone(), name.into()),
+    if let Some(module) = module {
+        let module = get_module(module, args.span(), parser)?;
+        let ident = Identifier::from(&name);
+        if let Some(f) = parser.modules.get_builtin_fn(module, &ident) {
+            return Ok(Value::FunctionRef(SassFunction::Builtin(f, ident)));
+        }
+        return match parser.modules.get_fn(
+            module,
+            Spanned {
+                node: ident.clone(),
+                span: args.span(),
+            },
+        ) {
+            Ok(f) => Ok(Value::FunctionRef(SassFunction::UserDefined(
+                Box::new(f),
+                ident,
+            ))),
+            Err(..) => Err((format!("Function not found: {}", name), args.span()).into()),
+        };
+    }
+
+    if css {
+        return Ok(Value::FunctionRef(SassFunction::Plain(name)));
+    }
+
+    let ident = Identifier::from(&name);
+    let func = match parser.get_fn(Spanned {
+        node: ident.clone(),
+        span: args.span(),
+    }) {
+        Ok(f) => SassFunction::UserDefined(Box::new(f), ident),
+        Err(..) => match parser
+            .modules
+            .get_global_builtin_fn(parser.module, &ident)
+            .or_else(|| GLOBAL_FUNCTIONS.get(ident.as_str()).cloned())
+        {
+            Some(f) => SassFunction::Builtin(f, ident),
             None => return Err((format!("Function not found: {}", name), args.span()).into()),
         },
     };
@@ -229,6 +331,114 @@ fn call(mut args: CallArgs, parser: &mut Parser<'_>) -> SassResult<Value> {
     func.call(args.decrement(), parser)
 }
 
+fn keywords(mut args: CallArgs, parser: &mut Parser<'_>) -> SassResult<Value> {
+    args.max_args(1)?;
+    match parser.arg(&mut args, 0, "args")? {
+        Value::ArgList(_, keywords) => Ok(Value::Map(keywords)),
+        v => Err((
+            format!(
+                "$args: {} is not an argument list.",
+                v.inspect(args.span())?
+            ),
+            args.span(),
+        )
+            .into()),
+    }
+}
+
+fn module_variables(mut args: CallArgs, parser: &mut Parser<'_>) -> SassResult<Value> {
+    args.max_args(1)?;
+    let module = match parser.arg(&mut args, 0, "module")? {
+        Value::String(s, ..) => get_module(s, args.span(), parser)?,
+        v => {
+            return Err((
+                format!(
+                    "$module: {} is not a string.",
+                    v.to_css_string(args.span())?
+                ),
+                args.span(),
+            )
+                .into())
+        }
+    };
+
+    let mut map = SassMap::new();
+    for (name, value) in parser.modules.variables(module, args.span()) {
+        map.insert(Value::String(name.into_inner(), QuoteKind::Quoted), value);
+    }
+    Ok(Value::Map(map))
+}
+
+fn module_functions(mut args: CallArgs, parser: &mut Parser<'_>) -> SassResult<Value> {
+    args.max_args(1)?;
+    let module = match parser.arg(&mut args, 0, "module")? {
+        Value::String(s, ..) => get_module(s, args.span(), parser)?,
+        v => {
+            return Err((
+                format!(
+                    "$module: {} is not a string.",
+                    v.to_css_string(args.span())?
+                ),
+                args.span(),
+            )
+                .into())
+        }
+    };
+
+    let mut map = SassMap::new();
+    for (name, func) in parser.modules.functions(module, args.span()) {
+        map.insert(
+            Value::String(name.into_inner(), QuoteKind::Quoted),
+            Value::FunctionRef(func),
+        );
+    }
+    Ok(Value::Map(map))
+}
+
+fn calc_args(mut args: CallArgs, parser: &mut Parser<'_>) -> SassResult<Value> {
+    args.max_args(1)?;
+    let calc = match parser.arg(&mut args, 0, "calc")? {
+        Value::Calc(calc) => calc,
+        v => {
+            return Err((
+                format!("$calc: {} is not a calculation.", v.inspect(args.span())?),
+                args.span(),
+            )
+                .into())
+        }
+    };
+    let span = args.span();
+
+    let args = css_function::calc_args(&calc)
+        .into_iter()
+        .map(|arg| {
+            if css_function::is_number(arg) {
+                let toks = arg.chars().map(|c| Token::new(span, c)).collect();
+                Ok(parser.parse_value_from_vec(toks, false)?.node)
+            } else if css_function::is_calculation(arg) {
+                Ok(Value::Calc(arg.to_owned()))
+            } else {
+                Ok(Value::String(arg.to_owned(), QuoteKind::None))
+            }
+        })
+        .collect::<SassResult<Vec<Value>>>()?;
+
+    Ok(Value::List(args, ListSeparator::Comma, Brackets::None))
+}
+
+fn calc_value(mut args: CallArgs, parser: &mut Parser<'_>) -> SassResult<Value> {
+    args.max_args(1)?;
+    match parser.arg(&mut args, 0, "number")? {
+        Value::Dimension(n, u) => Ok(Value::Calc(format!("calc({}{})", n, u))),
+        v @ Value::Calc(..) => Ok(v),
+        v => Err((
+            format!("$number: {} is not a number.", v.inspect(args.span())?),
+            args.span(),
+        )
+            .into()),
+    }
+}
+
 #[allow(clippy::needless_pass_by_value)]
 fn content_exists(args: CallArgs, parser: &mut Parser<'_>) -> SassResult<Value> {
     args.max_args(0)?;
@@ -244,6 +454,81 @@ fn content_exists(args: CallArgs, parser: &mut Parser<'_>) -> SassResult<Value>
     ))
 }
 
+fn load_css(mut args: CallArgs, parser: &mut Parser<'_>) -> SassResult<Vec<Stmt>> {
+    args.max_args(2)?;
+    let span = args.span();
+    let url = match parser.arg(&mut args, 0, "url")? {
+        Value::String(s, ..) => s,
+        v => {
+            return Err((
+                format!("$url: {} is not a string.", v.to_css_string(span)?),
+                span,
+            )
+                .into())
+        }
+    };
+    let with = match parser.default_arg(&mut args, 1, "with", Value::Null)? {
+        Value::Map(map) => Some(map),
+        Value::Null => None,
+        v => {
+            return Err((
+                format!("$with: {} is not a map.", v.to_css_string(span)?),
+                span,
+            )
+                .into())
+        }
+    };
+
+    // built-in modules contain no CSS
+    if url.starts_with("sass:") {
+        if with.is_some() {
+            return Err((
+                format!("Built-in module {} can't be configured.", url),
+                span,
+            )
+                .into());
+        }
+        return Ok(Vec::new());
+    }
+
+    let mut configuration = Configuration::new();
+    for (name, value) in with.into_iter().flatten() {
+        match name {
+            Value::String(name, ..) => {
+                configuration.insert(name.into(), value.span(span));
+            }
+            v => {
+                return Err((
+                    format!("$with key: {} is not a string.", v.inspect(span)?),
+                    span,
+                )
+                    .into())
+            }
+        }
+    }
+
+    parser.load_css(Spanned { node: url, span }, configuration)
+}
+
+pub(crate) fn declare_module(module: &mut Module) {
+    module.insert_builtin_fn("feature-exists", feature_exists);
+    module.insert_builtin_fn("type-of", type_of);
+    module.insert_builtin_fn("inspect", inspect);
+    module.insert_builtin_fn("variable-exists", variable_exists);
+    module.insert_builtin_fn("global-variable-exists", global_variable_exists);
+    module.insert_builtin_fn("mixin-exists", mixin_exists);
+    module.insert_builtin_fn("function-exists", function_exists);
+    module.insert_builtin_fn("get-function", get_function);
+    module.insert_builtin_fn("call", call);
+    module.insert_builtin_fn("content-exists", content_exists);
+    module.insert_builtin_fn("keywords", keywords);
+    module.insert_builtin_fn("module-variables", module_variables);
+    module.insert_builtin_fn("module-functions", module_functions);
+    module.insert_builtin_fn("calc-args", calc_args);
+    module.insert_builtin_fn("calc-value", calc_value);
+    module.insert_builtin_mixin("load-css", load_css);
+}
+
 pub(crate) fn declare(f: &mut GlobalFunctionMap) {
     f.insert("if", Builtin::new(if_));
     f.insert("feature-exists", Builtin::new(feature_exists));
@@ -261,4 +546,5 @@ pub(crate) fn declare(f: &mut GlobalFunctionMap) {
     f.insert("get-function", Builtin::new(get_function));
     f.insert("call", Builtin::new(call));
     f.insert("content-exists", Builtin::new(content_exists));
+    f.insert("keywords", Builtin::new(keywords));
 }
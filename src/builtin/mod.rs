@@ -5,7 +5,13 @@ use std::{
 
 use once_cell::sync::Lazy;
 
-use crate::{args::CallArgs, error::SassResult, parse::Parser, value::Value};
+use crate::{
+    args::CallArgs,
+    error::SassResult,
+    module::Module,
+    parse::{Parser, Stmt},
+    value::Value,
+};
 
 #[macro_use]
 mod macros;
@@ -23,7 +29,7 @@ pub(crate) type GlobalFunctionMap = HashMap<&'static str, Builtin>;
 static FUNCTION_COUNT: AtomicUsize = AtomicUsize::new(0);
 
 // TODO: impl Fn
-#[derive(Clone)]
+#[derive(Clone, Debug)]
 pub(crate) struct Builtin(
     pub fn(CallArgs, &mut Parser<'_>) -> SassResult<Value>,
     usize,
@@ -42,6 +48,32 @@ impl PartialEq for Builtin {
     }
 }
 
+/// A mixin provided by a built-in module, such as `meta.load-css`
+pub(crate) type BuiltinMixin = fn(CallArgs, &mut Parser<'_>) -> SassResult<Vec<Stmt>>;
+
+/// The random number generator used by `random()` and `unique-id()`
+///
+/// `thread_rng` is seeded by the operating system, which is not available
+/// when compiled to web assembly, so there we seed from `Math.random()`
+#[cfg(all(feature = "random", not(feature = "wasm")))]
+fn rng() -> impl rand::Rng {
+    rand::thread_rng()
+}
+
+#[cfg(all(feature = "random", feature = "wasm"))]
+fn rng() -> impl rand::Rng {
+    use rand::SeedableRng;
+    rand::rngs::StdRng::seed_from_u64((js_sys::Math::random() * u64::MAX as f64) as u64)
+}
+
+/// Stands in for `random()` and `unique-id()` when the `random` feature is
+/// disabled, so that they fail rather than being emitted as plain CSS
+#[cfg(not(feature = "random"))]
+#[allow(clippy::needless_pass_by_value)]
+fn random_disabled(args: CallArgs, _: &mut Parser<'_>) -> SassResult<Value> {
+    Err(("grass was built without the 'random' feature", args.span()).into())
+}
+
 pub(crate) static GLOBAL_FUNCTIONS: Lazy<GlobalFunctionMap> = Lazy::new(|| {
     let mut m = HashMap::new();
     color::declare(&mut m);
@@ -53,3 +85,19 @@ pub(crate) static GLOBAL_FUNCTIONS: Lazy<GlobalFunctionMap> = Lazy::new(|| {
     string::declare(&mut m);
     m
 });
+
+/// Create the module loaded by `@use "sass:<name>"`
+pub(crate) fn builtin_module(name: &str) -> Option<Module> {
+    let mut module = Module::default();
+    match name {
+        "color" => color::declare_module(&mut module),
+        "list" => list::declare_module(&mut module),
+        "map" => map::declare_module(&mut module),
+        "math" => math::declare_module(&mut module),
+        "meta" => meta::declare_module(&mut module),
+        "selector" => selector::declare_module(&mut module),
+        "string" => string::declare_module(&mut module),
+        _ => return None,
+    }
+    Some(module)
+}
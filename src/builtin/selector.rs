@@ -4,6 +4,7 @@ use crate::{
     args::CallArgs,
     common::{Brackets, ListSeparator, QuoteKind},
     error::SassResult,
+    module::Module,
     parse::Parser,
     selector::{ComplexSelector, ComplexSelectorComponent, Extender, Selector, SelectorList},
     value::Value,
@@ -214,3 +215,14 @@ pub(crate) fn declare(f: &mut GlobalFunctionMap) {
     f.insert("selector-replace", Builtin::new(selector_replace));
     f.insert("selector-unify", Builtin::new(selector_unify));
 }
+
+pub(crate) fn declare_module(module: &mut Module) {
+    module.insert_builtin_fn("is-superselector", is_superselector);
+    module.insert_builtin_fn("simple-selectors", simple_selectors);
+    module.insert_builtin_fn("parse", selector_parse);
+    module.insert_builtin_fn("nest", selector_nest);
+    module.insert_builtin_fn("append", selector_append);
+    module.insert_builtin_fn("extend", selector_extend);
+    module.insert_builtin_fn("replace", selector_replace);
+    module.insert_builtin_fn("unify", selector_unify);
+}
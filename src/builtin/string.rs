@@ -1,15 +1,16 @@
 use super::{Builtin, GlobalFunctionMap};
 
 use num_bigint::BigInt;
-use num_traits::{Signed, ToPrimitive, Zero};
+use num_traits::{One, Signed, ToPrimitive, Zero};
 
 #[cfg(feature = "random")]
-use rand::{distributions::Alphanumeric, thread_rng, Rng};
+use rand::{distributions::Alphanumeric, Rng};
 
 use crate::{
     args::CallArgs,
-    common::QuoteKind,
+    common::{Brackets, ListSeparator, QuoteKind},
     error::SassResult,
+    module::Module,
     parse::Parser,
     unit::Unit,
     value::{Number, Value},
@@ -338,11 +339,105 @@ fn str_insert(mut args: CallArgs, parser: &mut Parser<'_>) -> SassResult<Value>
     Ok(Value::String(string, quotes))
 }
 
+fn split(mut args: CallArgs, parser: &mut Parser<'_>) -> SassResult<Value> {
+    args.max_args(3)?;
+    let (string, quotes) = match parser.arg(&mut args, 0, "string")? {
+        Value::String(s, q) => (s, q),
+        v => {
+            return Err((
+                format!(
+                    "$string: {} is not a string.",
+                    v.to_css_string(args.span())?
+                ),
+                args.span(),
+            )
+                .into())
+        }
+    };
+
+    let separator = match parser.arg(&mut args, 1, "separator")? {
+        Value::String(s, _) => s,
+        v => {
+            return Err((
+                format!(
+                    "$separator: {} is not a string.",
+                    v.to_css_string(args.span())?
+                ),
+                args.span(),
+            )
+                .into())
+        }
+    };
+
+    let limit = match parser.default_arg(&mut args, 2, "limit", Value::Null)? {
+        Value::Null => None,
+        Value::Dimension(n, Unit::None) if n.is_decimal() => {
+            return Err((format!("$limit: {} is not an int.", n), args.span()).into())
+        }
+        Value::Dimension(n, Unit::None) if n < Number::one() => {
+            return Err((
+                format!("$limit: Must be 1 or greater, was {}.", n),
+                args.span(),
+            )
+                .into())
+        }
+        Value::Dimension(n, Unit::None) => n.to_integer().to_usize(),
+        v @ Value::Dimension(..) => {
+            return Err((
+                format!(
+                    "$limit: Expected {} to have no units.",
+                    v.to_css_string(args.span())?
+                ),
+                args.span(),
+            )
+                .into())
+        }
+        v => {
+            return Err((
+                format!("$limit: {} is not a number.", v.to_css_string(args.span())?),
+                args.span(),
+            )
+                .into())
+        }
+    };
+
+    let parts: Vec<String> = if string.is_empty() {
+        Vec::new()
+    } else if separator.is_empty() {
+        // an empty separator splits the string into its characters
+        let mut chars: Vec<String> = string.chars().map(String::from).collect();
+        if let Some(limit) = limit {
+            if limit < chars.len() {
+                let rest = chars.split_off(limit).concat();
+                chars.push(rest);
+            }
+        }
+        chars
+    } else {
+        match limit {
+            Some(limit) => string
+                .splitn(limit + 1, separator.as_str())
+                .map(String::from)
+                .collect(),
+            None => string.split(separator.as_str()).map(String::from).collect(),
+        }
+    };
+
+    Ok(Value::List(
+        parts
+            .into_iter()
+            .map(|part| Value::String(part, quotes))
+            .collect(),
+        ListSeparator::Comma,
+        Brackets::Bracketed,
+    ))
+}
+
 #[cfg(feature = "random")]
 #[allow(clippy::needless_pass_by_value)]
 fn unique_id(args: CallArgs, _: &mut Parser<'_>) -> SassResult<Value> {
     args.max_args(0)?;
-    let mut rng = thread_rng();
+    let mut rng = super::rng();
     let string = std::iter::repeat(())
         .map(|()| rng.sample(Alphanumeric))
         .take(7)
@@ -361,4 +456,22 @@ pub(crate) fn declare(f: &mut GlobalFunctionMap) {
     f.insert("str-insert", Builtin::new(str_insert));
     #[cfg(feature = "random")]
     f.insert("unique-id", Builtin::new(unique_id));
+    #[cfg(not(feature = "random"))]
+    f.insert("unique-id", Builtin::new(super::random_disabled));
+}
+
+pub(crate) fn declare_module(module: &mut Module) {
+    module.insert_builtin_fn("quote", quote);
+    module.insert_builtin_fn("unquote", unquote);
+    module.insert_builtin_fn("index", str_index);
+    module.insert_builtin_fn("insert", str_insert);
+    module.insert_builtin_fn("length", str_length);
+    module.insert_builtin_fn("slice", str_slice);
+    module.insert_builtin_fn("split", split);
+    module.insert_builtin_fn("to-upper-case", to_upper_case);
+    module.insert_builtin_fn("to-lower-case", to_lower_case);
+    #[cfg(feature = "random")]
+    module.insert_builtin_fn("unique-id", unique_id);
+    #[cfg(not(feature = "random"))]
+    module.insert_builtin_fn("unique-id", super::random_disabled);
 }
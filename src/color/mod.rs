@@ -15,10 +15,7 @@
 //! Named colors retain their original casing,
 //! so `rEd` should be emitted as `rEd`.
 
-use std::{
-    cmp::{max, min},
-    fmt::{self, Display},
-};
+use std::fmt::{self, Display};
 
 use crate::value::Number;
 pub(crate) use name::NAMED_COLORS;
@@ -27,7 +24,7 @@ use num_traits::{One, Signed, ToPrimitive, Zero};
 
 mod name;
 
-#[derive(Debug, Clone, Eq, PartialEq)]
+#[derive(Debug, Clone)]
 pub(crate) struct Color {
     rgba: Rgba,
     hsla: Option<Hsla>,
@@ -187,6 +184,29 @@ impl Color {
             self.alpha() * weight.clone() + other.alpha() * (Number::one() - weight),
         )
     }
+
+    /// Mix two colors together with weight, interpolating in sRGB with
+    /// premultiplied alpha as CSS `color-mix()` does
+    pub fn interpolate_srgb(self, other: &Color, weight: Number) -> Self {
+        let weight = weight.clamp(0, 1);
+        let alpha1 = self.alpha() * weight.clone();
+        let alpha2 = other.alpha() * (Number::one() - weight.clone());
+        let alpha = alpha1.clone() + alpha2.clone();
+
+        // with no alpha left, there is nothing to premultiply by
+        let (weight1, weight2) = if alpha.is_zero() {
+            (weight.clone(), Number::one() - weight)
+        } else {
+            (alpha1 / alpha.clone(), alpha2 / alpha.clone())
+        };
+
+        Color::from_rgba(
+            self.red() * weight1.clone() + other.red() * weight2.clone(),
+            self.green() * weight1.clone() + other.green() * weight2.clone(),
+            self.blue() * weight1 + other.blue() * weight2,
+            alpha,
+        )
+    }
 }
 
 /// HSLA color functions
@@ -201,8 +221,8 @@ impl Color {
         let red = self.red() / Number::from(255);
         let green = self.green() / Number::from(255);
         let blue = self.blue() / Number::from(255);
-        let min = min(&red, min(&green, &blue)).clone();
-        let max = max(&red, max(&green, &blue)).clone();
+        let min = red.clone().min(green.clone().min(blue.clone()));
+        let max = red.clone().max(green.clone().max(blue.clone()));
         if min == max {
             return Number::zero();
         }
@@ -216,10 +236,10 @@ impl Color {
         };
 
         if hue.is_negative() {
-            hue += Number::from(360);
+            hue += Number::from(6);
         }
 
-        (hue * Number::from(60)).round()
+        hue * Number::from(60)
     }
 
     /// Calculate saturation from RGBA values
@@ -232,7 +252,7 @@ impl Color {
         let green = self.green() / Number::from(255);
         let blue = self.blue() / Number::from(255);
 
-        let min = min(&red, min(&green, &blue)).clone();
+        let min = red.clone().min(green.clone().min(blue.clone()));
         let max = red.max(green.max(blue));
 
         if min == max {
@@ -258,7 +278,7 @@ impl Color {
         let red = self.red() / Number::from(255);
         let green = self.green() / Number::from(255);
         let blue = self.blue() / Number::from(255);
-        let min = min(&red, min(&green, &blue)).clone();
+        let min = red.clone().min(green.clone().min(blue.clone()));
         let max = red.max(green.max(blue));
         (((min + max) / Number::from(2)) * Number::from(100)).round()
     }
@@ -271,8 +291,8 @@ impl Color {
         let red = self.red() / Number::from(255);
         let green = self.green() / Number::from(255);
         let blue = self.blue() / Number::from(255);
-        let min = min(&red, min(&green, &blue)).clone();
-        let max = max(&red, max(&green, &blue)).clone();
+        let min = red.clone().min(green.clone().min(blue.clone()));
+        let max = red.clone().max(green.clone().max(blue.clone()));
 
         let lightness = (min.clone() + max.clone()) / Number::from(2);
 
@@ -438,6 +458,52 @@ impl Color {
     }
 }
 
+/// HWB color functions
+impl Color {
+    /// The whiteness of the color as a percentage
+    pub fn whiteness(&self) -> Number {
+        let min = self.red().min(self.green().min(self.blue()));
+        min / Number::from(255) * Number::from(100)
+    }
+
+    /// The blackness of the color as a percentage
+    pub fn blackness(&self) -> Number {
+        let max = self.red().max(self.green().max(self.blue()));
+        Number::from(100) - max / Number::from(255) * Number::from(100)
+    }
+
+    /// Create RGBA representation from HWB values, where `whiteness` and
+    /// `blackness` are between 0 and 1
+    ///
+    /// Algorithm adapted from <https://www.w3.org/TR/css-color-4/#hwb-to-rgb>
+    pub fn from_hwb(
+        hue: Number,
+        mut whiteness: Number,
+        mut blackness: Number,
+        alpha: Number,
+    ) -> Self {
+        let sum = whiteness.clone() + blackness.clone();
+        if sum > Number::one() {
+            whiteness /= sum.clone();
+            blackness /= sum;
+        }
+
+        let factor = Number::one() - whiteness.clone() - blackness;
+        let pure = Color::from_hsla(hue, Number::one(), Number::small_ratio(1, 2), Number::one());
+
+        let channel = |c: Number| {
+            (c / Number::from(255) * factor.clone() + whiteness.clone()) * Number::from(255)
+        };
+
+        Color::from_rgba(
+            channel(pure.rgba.red),
+            channel(pure.rgba.green),
+            channel(pure.rgba.blue),
+            alpha,
+        )
+    }
+}
+
 /// Opacity color functions
 impl Color {
     pub fn alpha(&self) -> Number {
@@ -507,6 +573,19 @@ fn repr(red: &Number, green: &Number, blue: &Number, alpha: &Number) -> String {
     }
 }
 
+/// Colors are equal if their channels are, regardless of how they were
+/// written, so `transparent == rgba(0, 0, 0, 0)`
+impl PartialEq for Color {
+    fn eq(&self, other: &Self) -> bool {
+        self.red() == other.red()
+            && self.green() == other.green()
+            && self.blue() == other.blue()
+            && self.alpha() == other.alpha()
+    }
+}
+
+impl Eq for Color {}
+
 impl Display for Color {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{}", self.repr)
@@ -48,15 +48,12 @@ impl Op {
     /// If precedence is equal, the leftmost operation is evaluated first
     pub fn precedence(self) -> usize {
         match self {
-            Self::And | Self::Or | Self::Not => 0,
-            Self::Equal
-            | Self::NotEqual
-            | Self::GreaterThan
-            | Self::GreaterThanEqual
-            | Self::LessThan
-            | Self::LessThanEqual => 1,
-            Self::Plus | Self::Minus => 2,
-            Self::Mul | Self::Div | Self::Rem => 3,
+            Self::Or | Self::Not => 0,
+            Self::And => 1,
+            Self::Equal | Self::NotEqual => 2,
+            Self::GreaterThan | Self::GreaterThanEqual | Self::LessThan | Self::LessThanEqual => 3,
+            Self::Plus | Self::Minus => 4,
+            Self::Mul | Self::Div | Self::Rem => 5,
         }
     }
 }
@@ -87,6 +84,8 @@ pub(crate) enum Brackets {
 pub(crate) enum ListSeparator {
     Space,
     Comma,
+    /// Only created by `list.slash`
+    Slash,
 }
 
 impl ListSeparator {
@@ -94,6 +93,7 @@ impl ListSeparator {
         match self {
             Self::Space => " ",
             Self::Comma => ", ",
+            Self::Slash => " / ",
         }
     }
 
@@ -101,11 +101,12 @@ impl ListSeparator {
         match self {
             Self::Space => "space",
             Self::Comma => "comma",
+            Self::Slash => "slash",
         }
     }
 }
 
-#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
 pub(crate) struct Identifier(String);
 
 impl From<String> for Identifier {
@@ -143,6 +144,16 @@ impl Identifier {
     pub fn into_inner(self) -> String {
         self.0
     }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Whether this names a member that isn't visible outside of the module
+    /// declaring it, i.e. one beginning with `-` or `_`
+    pub fn is_private(&self) -> bool {
+        self.0.starts_with('-')
+    }
 }
 
 /// Returns `name` without a vendor prefix.
@@ -2,8 +2,9 @@ use std::{
     error::Error,
     fmt::{self, Display},
     io,
-    rc::Rc,
+    path::Path,
     string::FromUtf8Error,
+    sync::Arc,
 };
 
 use codemap::{Span, SpanLoc};
@@ -61,9 +62,9 @@ enum SassErrorKind {
         message: String,
         loc: SpanLoc,
     },
-    // we put IoErrors in an `Rc` to allow it to be
-    // cloneable
-    IoError(Rc<io::Error>),
+    // we put IoErrors in an `Arc` to allow it to be
+    // cloneable and sendable across threads
+    IoError(Arc<io::Error>),
     FromUtf8Error(String),
 }
 
@@ -77,28 +78,43 @@ impl Display for SassError {
             SassErrorKind::ParseError { message, loc } => (message, loc),
             SassErrorKind::FromUtf8Error(s) => return writeln!(f, "Error: {}", s),
             SassErrorKind::IoError(s) => return writeln!(f, "Error: {}", s),
-            SassErrorKind::Raw(..) => todo!(),
+            // raw errors are given a location before they leave the crate, so
+            // there is no source to show
+            SassErrorKind::Raw(message, ..) => return writeln!(f, "Error: {}", message),
         };
         let line = loc.begin.line + 1;
         let col = loc.begin.column + 1;
+        let source_line = loc.file.source_line(loc.begin.line);
+
+        // only the first line of a span covering several is shown, so the
+        // pointer runs to the end of that line. An empty span still gets a
+        // pointer
+        let end_column = if loc.end.line > loc.begin.line {
+            source_line.len()
+        } else {
+            loc.end.column
+        };
+        let pointer_len = end_column.saturating_sub(loc.begin.column).max(1);
+
         writeln!(f, "Error: {}", message)?;
-        let padding = vec![' '; format!("{}", line).len() + 1]
-            .iter()
-            .collect::<String>();
+        let padding = " ".repeat(line.to_string().len() + 1);
         writeln!(f, "{}|", padding)?;
-        writeln!(f, "{} | {}", line, loc.file.source_line(loc.begin.line))?;
+        writeln!(f, "{} | {}", line, source_line)?;
         writeln!(
             f,
             "{}| {}{}",
             padding,
-            vec![' '; loc.begin.column].iter().collect::<String>(),
-            vec!['^'; loc.end.column.max(loc.begin.column) - loc.begin.column.min(loc.end.column)]
-                .iter()
-                .collect::<String>()
+            " ".repeat(loc.begin.column),
+            "^".repeat(pointer_len)
         )?;
         writeln!(f, "{}|", padding)?;
-        writeln!(f, "./{}:{}:{}", loc.file.name(), line, col)?;
-        Ok(())
+
+        let name = loc.file.name();
+        if Path::new(name).is_absolute() {
+            writeln!(f, "{}:{}:{}", name, line, col)
+        } else {
+            writeln!(f, "./{}:{}:{}", name, line, col)
+        }
     }
 }
 
@@ -106,7 +122,7 @@ impl From<io::Error> for Box<SassError> {
     #[inline]
     fn from(error: io::Error) -> Box<SassError> {
         Box::new(SassError {
-            kind: SassErrorKind::IoError(Rc::new(error)),
+            kind: SassErrorKind::IoError(Arc::new(error)),
         })
     }
 }
@@ -117,7 +133,7 @@ impl From<FromUtf8Error> for Box<SassError> {
         Box::new(SassError {
             kind: SassErrorKind::FromUtf8Error(format!(
                 "Invalid UTF-8 character \"\\x{:X?}\"",
-                error.as_bytes()[0]
+                error.as_bytes()[error.utf8_error().valid_up_to()]
             )),
         })
     }
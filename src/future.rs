@@ -0,0 +1,86 @@
+//! # Asynchronous compilation
+//!
+//! Compilation is CPU-bound and does not yield, so rather than tie `grass` to
+//! a particular async runtime, each compilation is run to completion on its own
+//! thread. The returned future resolves once that thread has finished, and can
+//! be awaited from any executor.
+use std::{
+    fmt,
+    future::Future,
+    panic::{self, AssertUnwindSafe},
+    pin::Pin,
+    sync::{Arc, Mutex},
+    task::{Context, Poll, Waker},
+    thread,
+};
+
+use crate::Result;
+
+#[derive(Default)]
+struct State {
+    /// If compilation panicked, the panic is resumed when this future is
+    /// polled, just as it would have been had compilation been synchronous
+    result: Option<thread::Result<Result<String>>>,
+    waker: Option<Waker>,
+}
+
+impl fmt::Debug for State {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("State")
+            .field("finished", &self.result.is_some())
+            .field("waker", &self.waker)
+            .finish()
+    }
+}
+
+/// A compilation running on a background thread
+#[derive(Debug)]
+pub(crate) struct Compilation {
+    state: Arc<Mutex<State>>,
+}
+
+impl Compilation {
+    /// Run `compile` on a new thread
+    ///
+    /// If the thread cannot be spawned, the future resolves to that error
+    pub fn spawn<F>(compile: F) -> Self
+    where
+        F: FnOnce() -> Result<String> + Send + 'static,
+    {
+        let state: Arc<Mutex<State>> = Arc::default();
+        let thread_state = Arc::clone(&state);
+
+        let spawned = thread::Builder::new()
+            .name("grass".to_owned())
+            .spawn(move || {
+                let result = panic::catch_unwind(AssertUnwindSafe(compile));
+                let mut state = thread_state.lock().unwrap();
+                state.result = Some(result);
+                if let Some(waker) = state.waker.take() {
+                    waker.wake();
+                }
+            });
+
+        if let Err(e) = spawned {
+            state.lock().unwrap().result = Some(Ok(Err(e.into())));
+        }
+
+        Compilation { state }
+    }
+}
+
+impl Future for Compilation {
+    type Output = Result<String>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut state = self.state.lock().unwrap();
+        match state.result.take() {
+            Some(Ok(result)) => Poll::Ready(result),
+            Some(Err(payload)) => panic::resume_unwind(payload),
+            None => {
+                state.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
@@ -0,0 +1,240 @@
+//! # C interface
+//!
+//! A C-compatible API for embedding `grass` in projects not written in Rust.
+//! The header for this interface is generated by the build script, and is
+//! written to `grass.h` in the build's `OUT_DIR`.
+//!
+//! All strings returned by these functions are allocated by Rust and must be
+//! freed with `grass_free_string`; likewise, compilation contexts must be freed
+//! with `grass_context_free`.
+//!
+//! An internal error is reported as an error message only when `grass` is
+//! built with `panic = "unwind"`, as in debug builds. The release profile sets
+//! `panic = "abort"`, under which an internal error aborts the process.
+use std::{
+    ffi::{CStr, CString},
+    os::raw::c_char,
+    panic::{self, AssertUnwindSafe},
+    ptr,
+};
+
+use crate::{from_string, CharsetBehavior, LineEnding, Options, SourceMapOption};
+
+/// Options for a single compilation
+///
+/// A null pointer to this struct may be passed to use the defaults, which
+/// are also returned by `grass_options_default`
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct GrassOptions {
+    /// The maximum number of nested mixin and function calls
+    pub recursion_limit: usize,
+    /// Whether to generate a source map, and how to emit it
+    pub source_map: GrassSourceMap,
+    /// When to emit an `@charset "UTF-8";` declaration
+    pub charset: GrassCharset,
+    /// How lines should end in the output
+    pub line_ending: GrassLineEnding,
+}
+
+/// How a source map should be emitted, if at all
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub enum GrassSourceMap {
+    /// Do not generate a source map
+    None,
+    /// Append the source map to the CSS as a base64 encoded data URL
+    Inline,
+}
+
+/// When to emit an `@charset "UTF-8";` declaration
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub enum GrassCharset {
+    /// Emit `@charset` only if the output contains non-ASCII characters
+    Auto,
+    /// Always emit `@charset`, unless there is no output at all
+    Always,
+    /// Never emit `@charset`
+    Never,
+}
+
+/// How lines should end in the output
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub enum GrassLineEnding {
+    /// `\n`
+    Lf,
+    /// `\r\n`
+    CrLf,
+    /// `\r\n` if the first line of the input ends with `\r\n`, otherwise `\n`
+    Auto,
+}
+
+impl From<GrassOptions> for Options {
+    fn from(options: GrassOptions) -> Self {
+        Options::default()
+            .recursion_limit(options.recursion_limit)
+            .source_map(match options.source_map {
+                GrassSourceMap::None => SourceMapOption::None,
+                GrassSourceMap::Inline => SourceMapOption::Inline,
+            })
+            .charset(match options.charset {
+                GrassCharset::Auto => CharsetBehavior::Auto,
+                GrassCharset::Always => CharsetBehavior::Always,
+                GrassCharset::Never => CharsetBehavior::Never,
+            })
+            .line_ending(match options.line_ending {
+                GrassLineEnding::Lf => LineEnding::Lf,
+                GrassLineEnding::CrLf => LineEnding::CrLf,
+                GrassLineEnding::Auto => LineEnding::Auto,
+            })
+    }
+}
+
+/// The options used when `grass_compile_string` is passed null, to be
+/// modified before compiling
+#[no_mangle]
+pub extern "C" fn grass_options_default() -> GrassOptions {
+    let options = Options::default();
+    GrassOptions {
+        recursion_limit: options.recursion_limit,
+        source_map: match options.source_map {
+            SourceMapOption::None => GrassSourceMap::None,
+            SourceMapOption::Inline => GrassSourceMap::Inline,
+        },
+        charset: match options.charset {
+            CharsetBehavior::Auto => GrassCharset::Auto,
+            CharsetBehavior::Always => GrassCharset::Always,
+            CharsetBehavior::Never => GrassCharset::Never,
+        },
+        line_ending: match options.line_ending {
+            LineEnding::Lf => GrassLineEnding::Lf,
+            LineEnding::CrLf => GrassLineEnding::CrLf,
+            LineEnding::Auto => GrassLineEnding::Auto,
+        },
+    }
+}
+
+/// Holds the error message from the most recent compilation, if it failed
+#[derive(Debug, Default)]
+pub struct GrassCompileCtx {
+    error: Option<CString>,
+}
+
+/// Create a new compilation context
+///
+/// The context must be freed with `grass_context_free`
+#[no_mangle]
+pub extern "C" fn grass_context_new() -> *mut GrassCompileCtx {
+    Box::into_raw(Box::new(GrassCompileCtx::default()))
+}
+
+/// Free a compilation context created by `grass_context_new`
+///
+/// # Safety
+///
+/// `ctx` must either be null or have been returned by `grass_context_new`,
+/// and must not be used after this call
+#[no_mangle]
+pub unsafe extern "C" fn grass_context_free(ctx: *mut GrassCompileCtx) {
+    if !ctx.is_null() {
+        drop(Box::from_raw(ctx));
+    }
+}
+
+/// Compile a null-terminated, UTF-8 encoded string of SCSS
+///
+/// Returns the compiled CSS, which must be freed with `grass_free_string`.
+/// On failure, returns null and, if `ctx` is not null, stores the error
+/// message in `ctx` to be read with `grass_error_message`. With
+/// `panic = "abort"`, as in release builds, an internal error aborts the
+/// process instead.
+///
+/// # Safety
+///
+/// `scss` must be a valid pointer to a null-terminated string. `options` and
+/// `ctx` must each either be null or valid pointers.
+#[no_mangle]
+pub unsafe extern "C" fn grass_compile_string(
+    ctx: *mut GrassCompileCtx,
+    scss: *const c_char,
+    options: *const GrassOptions,
+) -> *mut c_char {
+    let result = compile(scss, options);
+
+    let error = match result {
+        Ok(css) => match CString::new(css) {
+            Ok(css) => {
+                if let Some(ctx) = ctx.as_mut() {
+                    ctx.error = None;
+                }
+                return css.into_raw();
+            }
+            Err(..) => "Error: output contains a null byte".to_owned(),
+        },
+        Err(e) => e,
+    };
+
+    if let Some(ctx) = ctx.as_mut() {
+        // the message cannot contain null bytes, as those would have been
+        // rejected when converting the input to a `&str`
+        ctx.error = CString::new(error).ok();
+    }
+
+    ptr::null_mut()
+}
+
+unsafe fn compile(scss: *const c_char, options: *const GrassOptions) -> Result<String, String> {
+    if scss.is_null() {
+        return Err("Error: input is null".to_owned());
+    }
+
+    let scss = match CStr::from_ptr(scss).to_str() {
+        Ok(scss) => scss.to_owned(),
+        Err(..) => return Err("Error: input is not valid UTF-8".to_owned()),
+    };
+
+    let options = match options.as_ref() {
+        Some(options) => Options::from(*options),
+        None => Options::default(),
+    };
+
+    // unwinding across the FFI boundary is undefined behavior. This only has
+    // an effect with `panic = "unwind"`; otherwise, panics abort
+    match panic::catch_unwind(AssertUnwindSafe(|| from_string(scss, &options))) {
+        Ok(Ok(css)) => Ok(css),
+        Ok(Err(e)) => Err(e.to_string()),
+        Err(..) => Err("Error: internal compiler error".to_owned()),
+    }
+}
+
+/// Free a string returned by `grass_compile_string`
+///
+/// # Safety
+///
+/// `s` must either be null or have been returned by `grass_compile_string`,
+/// and must not be used after this call
+#[no_mangle]
+pub unsafe extern "C" fn grass_free_string(s: *mut c_char) {
+    if !s.is_null() {
+        drop(CString::from_raw(s));
+    }
+}
+
+/// The error message from the most recent compilation using `ctx`, or
+/// null if it succeeded
+///
+/// The message is owned by `ctx`, and is valid until `ctx` is next used
+/// or freed.
+///
+/// # Safety
+///
+/// `ctx` must either be null or have been returned by `grass_context_new`
+#[no_mangle]
+pub unsafe extern "C" fn grass_error_message(ctx: *const GrassCompileCtx) -> *const c_char {
+    match ctx.as_ref().and_then(|ctx| ctx.error.as_ref()) {
+        Some(error) => error.as_ptr(),
+        None => ptr::null(),
+    }
+}
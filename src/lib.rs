@@ -10,7 +10,10 @@ Spec progress as of 2020-07-04:
 ## Use as library
 ```
 fn main() -> Result<(), Box<grass::Error>> {
-    let sass = grass::from_string("a { b { color: &; } }".to_string())?;
+    let sass = grass::from_string(
+        "a { b { color: &; } }".to_string(),
+        &grass::Options::default()
+    )?;
     assert_eq!(sass, "a b {\n  color: a b;\n}\n");
     Ok(())
 }
@@ -78,21 +81,28 @@ grass input.scss
 )]
 #![cfg_attr(feature = "nightly", feature(track_caller))]
 #![cfg_attr(feature = "profiling", inline(never))]
-use std::{fs, path::Path};
+use std::{collections::HashSet, path::Path, sync::Arc};
+#[cfg(not(feature = "wasm"))]
+use std::{fs, future::Future, path::PathBuf};
 
+#[cfg(feature = "wasm")]
+use js_sys::{JsString, Reflect};
 #[cfg(feature = "wasm")]
 use wasm_bindgen::prelude::*;
 
 pub(crate) use beef::lean::Cow;
 
-use codemap::CodeMap;
+use codemap::{CodeMap, File};
 
 use peekmore::PeekMore;
 
 pub use crate::error::{SassError as Error, SassResult as Result};
+pub use crate::source_map::SourceMapOption;
 pub(crate) use crate::token::Token;
+pub use crate::version::{Version, VERSION};
 use crate::{
     lexer::Lexer,
+    module::{ModuleId, Modules},
     output::Css,
     parse::{
         common::{ContextFlags, NeverEmptyVec},
@@ -108,16 +118,352 @@ mod builtin;
 mod color;
 mod common;
 mod error;
+#[cfg(not(feature = "wasm"))]
+mod future;
+#[cfg(all(feature = "ffi", not(feature = "wasm")))]
+mod grass_c;
 mod lexer;
+mod module;
 mod output;
 mod parse;
 mod scope;
 mod selector;
+mod source_map;
+mod stack;
 mod style;
 mod token;
 mod unit;
 mod utils;
 mod value;
+mod version;
+
+/// Configuration for Sass compilation
+///
+/// The simplest usage is `grass::Options::default()`;
+/// however, a builder pattern is also exposed to offer
+/// more control.
+#[derive(Debug, Clone)]
+pub struct Options {
+    recursion_limit: usize,
+    source_map: SourceMapOption,
+    charset: CharsetBehavior,
+    line_ending: LineEnding,
+    dedup_imports: bool,
+    allow_import_globs: bool,
+    node_modules_resolution: bool,
+    /// Loads the files imported by the WASM build, in place of the file system
+    #[cfg(feature = "wasm")]
+    importer: Option<js_sys::Function>,
+}
+
+impl Default for Options {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            recursion_limit: 1000,
+            source_map: SourceMapOption::None,
+            charset: CharsetBehavior::Auto,
+            line_ending: LineEnding::Lf,
+            dedup_imports: false,
+            allow_import_globs: false,
+            node_modules_resolution: false,
+            #[cfg(feature = "wasm")]
+            importer: None,
+        }
+    }
+}
+
+impl Options {
+    /// The maximum number of nested mixin and function calls
+    ///
+    /// Once this depth is exceeded, compilation fails with an error rather
+    /// than overflowing the stack. By default, this is 1000.
+    ///
+    /// Deeply nested calls use a lot of stack space, so once the stack of the
+    /// calling thread runs low, more is allocated as needed. This can't be
+    /// done in the WASM build, where the limit should be lowered if deep
+    /// recursion overflows the stack.
+    #[must_use]
+    #[inline]
+    pub const fn recursion_limit(mut self, recursion_limit: usize) -> Self {
+        self.recursion_limit = recursion_limit;
+        self
+    }
+
+    /// Whether to generate a source map, and how to emit it
+    ///
+    /// By default, no source map is generated. With `SourceMapOption::Inline`,
+    /// the source map is appended to the CSS as a comment containing a base64
+    /// encoded `data:` URL.
+    #[must_use]
+    #[inline]
+    pub const fn source_map(mut self, source_map: SourceMapOption) -> Self {
+        self.source_map = source_map;
+        self
+    }
+
+    /// When to begin the output with `@charset "UTF-8";`
+    ///
+    /// By default, the declaration is only emitted when the CSS contains
+    /// non-ASCII characters, matching Dart Sass.
+    #[must_use]
+    #[inline]
+    pub const fn charset(mut self, charset: CharsetBehavior) -> Self {
+        self.charset = charset;
+        self
+    }
+
+    /// The line endings used in the output
+    ///
+    /// By default, lines end with `\n`, regardless of the line endings used in
+    /// the input.
+    #[must_use]
+    #[inline]
+    pub const fn line_ending(mut self, line_ending: LineEnding) -> Self {
+        self.line_ending = line_ending;
+        self
+    }
+
+    /// Whether to skip `@import`s of a file that has already been imported
+    ///
+    /// By default, `@import` evaluates a file every time it is imported,
+    /// duplicating its output. With this enabled, only the first import of
+    /// each file has any effect. Either way, importing the same file more
+    /// than once emits a deprecation warning.
+    #[must_use]
+    #[inline]
+    pub const fn dedup_imports(mut self, dedup_imports: bool) -> Self {
+        self.dedup_imports = dedup_imports;
+        self
+    }
+
+    /// Whether `@import` may be given a glob pattern, e.g.
+    /// `@import "components/**"`
+    ///
+    /// A glob imports every `.scss` file it matches, relative to the
+    /// importing file, in alphabetical order. This is not part of the Sass
+    /// language, so it is disabled by default, in which case the url is
+    /// treated as a plain path.
+    #[must_use]
+    #[inline]
+    pub const fn allow_import_globs(mut self, allow_import_globs: bool) -> Self {
+        self.allow_import_globs = allow_import_globs;
+        self
+    }
+
+    /// Whether an `@import` or `@use` url beginning with `~` is resolved from
+    /// `node_modules`, e.g. `@import "~bootstrap/scss/bootstrap"`
+    ///
+    /// The `~` is removed and the rest of the url is looked up in each
+    /// `node_modules` directory from that of the importing file up to the
+    /// root of the filesystem. This follows the convention of webpack's
+    /// `sass-loader` rather than the Sass language, so it is disabled by
+    /// default.
+    #[must_use]
+    #[inline]
+    pub const fn node_modules_resolution(mut self, node_modules_resolution: bool) -> Self {
+        self.node_modules_resolution = node_modules_resolution;
+        self
+    }
+}
+
+/// When to emit an `@charset "UTF-8";` declaration
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CharsetBehavior {
+    /// Emit `@charset` only if the output contains non-ASCII characters
+    Auto,
+    /// Always emit `@charset`, unless there is no output at all
+    Always,
+    /// Never emit `@charset`. This is useful when the output will be
+    /// concatenated with other CSS
+    Never,
+}
+
+impl Default for CharsetBehavior {
+    #[inline]
+    fn default() -> Self {
+        CharsetBehavior::Auto
+    }
+}
+
+/// How lines should end in the output
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LineEnding {
+    /// `\n`
+    Lf,
+    /// `\r\n`
+    CrLf,
+    /// `\r\n` if the first line of the input ends with `\r\n`, otherwise `\n`
+    Auto,
+}
+
+impl Default for LineEnding {
+    #[inline]
+    fn default() -> Self {
+        LineEnding::Lf
+    }
+}
+
+impl LineEnding {
+    /// Whether the output for `input` should use `\r\n`
+    fn is_crlf(self, input: &str) -> bool {
+        match self {
+            LineEnding::Lf => false,
+            LineEnding::CrLf => true,
+            LineEnding::Auto => input
+                .find('\n')
+                .map_or(false, |idx| input[..idx].ends_with('\r')),
+        }
+    }
+
+    /// Convert `css`, which always uses `\n`, to use these line endings
+    fn apply(self, css: String, input: &str) -> String {
+        if self.is_crlf(input) {
+            css.replace('\n', "\r\n")
+        } else {
+            css
+        }
+    }
+}
+
+#[cfg(feature = "wasm")]
+impl Options {
+    /// Read options from a JavaScript object, falling back to the
+    /// default for any key that is not present
+    fn from_js(options: &JsValue) -> std::result::Result<Self, JsValue> {
+        let mut opts = Options::default();
+
+        if options.is_undefined() || options.is_null() {
+            return Ok(opts);
+        }
+
+        let recursion_limit = Reflect::get(options, &JsValue::from_str("recursionLimit"))?;
+        if !recursion_limit.is_undefined() {
+            match recursion_limit.as_f64() {
+                Some(limit) if limit >= 0.0 && limit.fract() == 0.0 => {
+                    opts.recursion_limit = limit as usize;
+                }
+                _ => {
+                    return Err(JsValue::from_str(
+                        "recursionLimit must be a non-negative integer",
+                    ))
+                }
+            }
+        }
+
+        match js_string(options, "sourceMap")?.as_deref() {
+            None => {}
+            Some("none") => opts.source_map = SourceMapOption::None,
+            Some("inline") => opts.source_map = SourceMapOption::Inline,
+            Some(..) => {
+                return Err(JsValue::from_str(
+                    "sourceMap must be \"none\" or \"inline\"",
+                ))
+            }
+        }
+
+        match js_string(options, "charset")?.as_deref() {
+            None => {}
+            Some("auto") => opts.charset = CharsetBehavior::Auto,
+            Some("always") => opts.charset = CharsetBehavior::Always,
+            Some("never") => opts.charset = CharsetBehavior::Never,
+            Some(..) => {
+                return Err(JsValue::from_str(
+                    "charset must be \"auto\", \"always\" or \"never\"",
+                ))
+            }
+        }
+
+        match js_string(options, "lineEnding")?.as_deref() {
+            None => {}
+            Some("lf") => opts.line_ending = LineEnding::Lf,
+            Some("crlf") => opts.line_ending = LineEnding::CrLf,
+            Some("auto") => opts.line_ending = LineEnding::Auto,
+            Some(..) => {
+                return Err(JsValue::from_str(
+                    "lineEnding must be \"lf\", \"crlf\" or \"auto\"",
+                ))
+            }
+        }
+
+        let dedup_imports = Reflect::get(options, &JsValue::from_str("dedupImports"))?;
+        if !dedup_imports.is_undefined() {
+            match dedup_imports.as_bool() {
+                Some(dedup_imports) => opts.dedup_imports = dedup_imports,
+                None => return Err(JsValue::from_str("dedupImports must be a boolean")),
+            }
+        }
+
+        let importer = Reflect::get(options, &JsValue::from_str("importer"))?;
+        if !importer.is_undefined() {
+            if !importer.is_function() {
+                return Err(JsValue::from_str("importer must be a function"));
+            }
+            opts.importer = Some(js_sys::Function::from(importer));
+        }
+
+        Ok(opts)
+    }
+}
+
+/// The string at `key` of a JavaScript object, if there is one
+#[cfg(feature = "wasm")]
+fn js_string(options: &JsValue, key: &str) -> std::result::Result<Option<String>, JsValue> {
+    let value = Reflect::get(options, &JsValue::from_str(key))?;
+    if value.is_undefined() {
+        return Ok(None);
+    }
+    match value.as_string() {
+        Some(value) => Ok(Some(value)),
+        None => Err(JsValue::from_str(&format!("{} must be a string", key))),
+    }
+}
+
+/// A stylesheet which has been parsed and evaluated, but not yet written out
+/// as CSS
+///
+/// The same stylesheet may be written out several times with different
+/// options, without being parsed again.
+///
+/// ```
+/// fn main() -> Result<(), Box<grass::Error>> {
+///     let stylesheet = grass::parse_string("a { color: é; }".to_string(), &grass::Options::default())?;
+///     assert_eq!(
+///         stylesheet.to_css(&grass::Options::default())?,
+///         "@charset \"UTF-8\";\na {\n  color: é;\n}\n"
+///     );
+///     assert_eq!(
+///         stylesheet.to_css(&grass::Options::default().charset(grass::CharsetBehavior::Never))?,
+///         "a {\n  color: é;\n}\n"
+///     );
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug)]
+pub struct Stylesheet {
+    css: Css,
+    map: CodeMap,
+    file: Arc<File>,
+}
+
+impl Stylesheet {
+    /// Write this stylesheet out as CSS
+    ///
+    /// Only the options which control the output, such as
+    /// `Options::charset`, `Options::line_ending` and `Options::source_map`,
+    /// have any effect. All others were applied when the stylesheet was
+    /// parsed.
+    #[inline]
+    pub fn to_css(&self, options: &Options) -> Result<String> {
+        let css = self
+            .css
+            .clone()
+            .pretty_print(&self.map, options)
+            .map_err(|e| raw_to_parse_error(&self.map, *e))?;
+
+        Ok(options.line_ending.apply(css, self.file.source()))
+    }
+}
 
 fn raw_to_parse_error(map: &CodeMap, err: Error) -> Box<Error> {
     let (message, span) = err.raw();
@@ -128,7 +474,7 @@ fn raw_to_parse_error(map: &CodeMap, err: Error) -> Box<Error> {
 ///
 /// ```
 /// fn main() -> Result<(), Box<grass::Error>> {
-///     let sass = grass::from_path("input.scss")?;
+///     let sass = grass::from_path("input.scss", &grass::Options::default())?;
 ///     Ok(())
 /// }
 /// ```
@@ -136,10 +482,63 @@ fn raw_to_parse_error(map: &CodeMap, err: Error) -> Box<Error> {
 #[cfg_attr(feature = "profiling", inline(never))]
 #[cfg_attr(not(feature = "profiling"), inline)]
 #[cfg(not(feature = "wasm"))]
-pub fn from_path(p: &str) -> Result<String> {
+pub fn from_path(p: &str, options: &Options) -> Result<String> {
+    compile_path(p, options, &mut Vec::new())
+}
+
+/// Parse a stylesheet from a path, to be written out as CSS later
+///
+/// ```
+/// fn main() -> Result<(), Box<grass::Error>> {
+///     let stylesheet = grass::parse_path("input.scss", &grass::Options::default())?;
+///     let css = stylesheet.to_css(&grass::Options::default())?;
+///     Ok(())
+/// }
+/// ```
+#[cfg_attr(feature = "profiling", inline(never))]
+#[cfg_attr(not(feature = "profiling"), inline)]
+#[cfg(not(feature = "wasm"))]
+pub fn parse_path(p: &str, options: &Options) -> Result<Stylesheet> {
+    parse_path_with_deps(p, options, &mut Vec::new())
+}
+
+/// Compile CSS from a path, also returning every file that was loaded
+///
+/// The returned paths include `p` itself, followed by every file that was
+/// transitively imported, in the order they were first loaded. Build tools
+/// can use this to recompile only when one of these files changes.
+///
+/// ```
+/// fn main() -> Result<(), Box<grass::Error>> {
+///     let (css, dependencies) = grass::compile_with_deps("input.scss", &grass::Options::default())?;
+///     Ok(())
+/// }
+/// ```
+#[cfg_attr(feature = "profiling", inline(never))]
+#[cfg_attr(not(feature = "profiling"), inline)]
+#[cfg(not(feature = "wasm"))]
+pub fn compile_with_deps(p: &str, options: &Options) -> Result<(String, Vec<PathBuf>)> {
+    let mut dependencies = Vec::new();
+    let css = compile_path(p, options, &mut dependencies)?;
+    Ok((css, dependencies))
+}
+
+#[cfg(not(feature = "wasm"))]
+fn compile_path(p: &str, options: &Options, dependencies: &mut Vec<PathBuf>) -> Result<String> {
+    parse_path_with_deps(p, options, dependencies)?.to_css(options)
+}
+
+#[cfg(not(feature = "wasm"))]
+fn parse_path_with_deps(
+    p: &str,
+    options: &Options,
+    dependencies: &mut Vec<PathBuf>,
+) -> Result<Stylesheet> {
     let mut map = CodeMap::new();
+    dependencies.push(PathBuf::from(p));
     let file = map.add_file(p.into(), String::from_utf8(fs::read(p)?)?);
     let empty_span = file.span.subspan(0, 0);
+    let mut extender = Extender::new(empty_span);
 
     let stmts = Parser {
         toks: &mut Lexer::new(&file)
@@ -149,29 +548,61 @@ pub fn from_path(p: &str) -> Result<String> {
         map: &mut map,
         path: p.as_ref(),
         scopes: &mut NeverEmptyVec::new(Scope::new()),
-        global_scope: &mut Scope::new(),
+        modules: &mut Modules::new(),
+        module: ModuleId::ROOT,
         super_selectors: &mut NeverEmptyVec::new(Selector::new(empty_span)),
         span_before: empty_span,
         content: &mut Vec::new(),
+        call_stack: &mut Vec::new(),
+        options,
+        dependencies,
+        import_stack: &mut vec![PathBuf::from(p)],
+        imported: &mut HashSet::new(),
         flags: ContextFlags::empty(),
         at_root: true,
         at_root_has_selector: false,
-        extender: &mut Extender::new(empty_span),
+        extender: &mut extender,
+        media_queries: &mut Vec::new(),
     }
     .parse()
     .map_err(|e| raw_to_parse_error(&map, *e))?;
 
-    Css::from_stmts(stmts, false)
-        .map_err(|e| raw_to_parse_error(&map, *e))?
-        .pretty_print(&map)
-        .map_err(|e| raw_to_parse_error(&map, *e))
+    extender
+        .check_unsatisfied_extensions()
+        .map_err(|e| raw_to_parse_error(&map, *e))?;
+
+    let css = Css::from_stmts(stmts, false).map_err(|e| raw_to_parse_error(&map, *e))?;
+
+    Ok(Stylesheet { css, map, file })
+}
+
+/// Compile CSS from a path without blocking the current thread
+///
+/// Compilation runs on a background thread, so the returned future may be
+/// awaited from any async runtime.
+///
+/// ```
+/// async fn compile() -> Result<String, Box<grass::Error>> {
+///     grass::from_path_async("input.scss", &grass::Options::default()).await
+/// }
+/// ```
+#[cfg_attr(feature = "profiling", inline(never))]
+#[cfg_attr(not(feature = "profiling"), inline)]
+#[cfg(not(feature = "wasm"))]
+pub fn from_path_async(p: &str, options: &Options) -> impl Future<Output = Result<String>> {
+    let p = p.to_owned();
+    let options = options.clone();
+    future::Compilation::spawn(move || compile_path(&p, &options, &mut Vec::new()))
 }
 
 /// Compile CSS from a string
 ///
 /// ```
 /// fn main() -> Result<(), Box<grass::Error>> {
-///     let sass = grass::from_string("a { b { color: &; } }".to_string())?;
+///     let sass = grass::from_string(
+///         "a { b { color: &; } }".to_string(),
+///         &grass::Options::default()
+///     )?;
 ///     assert_eq!(sass, "a b {\n  color: a b;\n}\n");
 ///     Ok(())
 /// }
@@ -179,10 +610,20 @@ pub fn from_path(p: &str) -> Result<String> {
 #[cfg_attr(feature = "profiling", inline(never))]
 #[cfg_attr(not(feature = "profiling"), inline)]
 #[cfg(not(feature = "wasm"))]
-pub fn from_string(p: String) -> Result<String> {
+pub fn from_string(p: String, options: &Options) -> Result<String> {
+    compile_string(p, options)
+}
+
+fn compile_string(p: String, options: &Options) -> Result<String> {
+    parse_stylesheet(p, options)?.to_css(options)
+}
+
+fn parse_stylesheet(p: String, options: &Options) -> Result<Stylesheet> {
     let mut map = CodeMap::new();
     let file = map.add_file("stdin".into(), p);
     let empty_span = file.span.subspan(0, 0);
+    let mut extender = Extender::new(empty_span);
+
     let stmts = Parser {
         toks: &mut Lexer::new(&file)
             .collect::<Vec<Token>>()
@@ -191,53 +632,94 @@ pub fn from_string(p: String) -> Result<String> {
         map: &mut map,
         path: Path::new(""),
         scopes: &mut NeverEmptyVec::new(Scope::new()),
-        global_scope: &mut Scope::new(),
+        modules: &mut Modules::new(),
+        module: ModuleId::ROOT,
         super_selectors: &mut NeverEmptyVec::new(Selector::new(empty_span)),
         span_before: empty_span,
         content: &mut Vec::new(),
+        call_stack: &mut Vec::new(),
+        options,
+        dependencies: &mut Vec::new(),
+        import_stack: &mut Vec::new(),
+        imported: &mut HashSet::new(),
         flags: ContextFlags::empty(),
         at_root: true,
         at_root_has_selector: false,
-        extender: &mut Extender::new(empty_span),
+        extender: &mut extender,
+        media_queries: &mut Vec::new(),
     }
     .parse()
     .map_err(|e| raw_to_parse_error(&map, *e))?;
 
-    Css::from_stmts(stmts, false)
-        .map_err(|e| raw_to_parse_error(&map, *e))?
-        .pretty_print(&map)
-        .map_err(|e| raw_to_parse_error(&map, *e))
+    extender
+        .check_unsatisfied_extensions()
+        .map_err(|e| raw_to_parse_error(&map, *e))?;
+
+    let css = Css::from_stmts(stmts, false).map_err(|e| raw_to_parse_error(&map, *e))?;
+
+    Ok(Stylesheet { css, map, file })
+}
+
+/// Parse a stylesheet from a string, to be written out as CSS later
+#[cfg_attr(feature = "profiling", inline(never))]
+#[cfg_attr(not(feature = "profiling"), inline)]
+#[cfg(not(feature = "wasm"))]
+pub fn parse_string(p: String, options: &Options) -> Result<Stylesheet> {
+    parse_stylesheet(p, options)
+}
+
+/// Compile CSS from a byte slice
+///
+/// The input must be valid UTF-8
+///
+/// ```
+/// fn main() -> Result<(), Box<grass::Error>> {
+///     let sass = grass::from_bytes(b"a { b { color: &; } }", &grass::Options::default())?;
+///     assert_eq!(sass, "a b {\n  color: a b;\n}\n");
+///     Ok(())
+/// }
+/// ```
+#[cfg_attr(feature = "profiling", inline(never))]
+#[cfg_attr(not(feature = "profiling"), inline)]
+#[cfg(not(feature = "wasm"))]
+pub fn from_bytes(input: &[u8], options: &Options) -> Result<String> {
+    from_string(String::from_utf8(input.to_vec())?, options)
 }
 
 #[cfg(feature = "wasm")]
 #[wasm_bindgen]
 pub fn from_string(p: String) -> std::result::Result<String, JsValue> {
-    let mut map = CodeMap::new();
-    let file = map.add_file("stdin".into(), p);
-    let empty_span = file.span.subspan(0, 0);
-
-    let stmts = Parser {
-        toks: &mut Lexer::new(&file)
-            .collect::<Vec<Token>>()
-            .into_iter()
-            .peekmore(),
-        map: &mut map,
-        path: Path::new(""),
-        scopes: &mut NeverEmptyVec::new(Scope::new()),
-        global_scope: &mut Scope::new(),
-        super_selectors: &mut NeverEmptyVec::new(Selector::new(empty_span)),
-        span_before: empty_span,
-        content: &mut Vec::new(),
-        flags: ContextFlags::empty(),
-        at_root: true,
-        at_root_has_selector: false,
-        extender: &mut Extender::new(empty_span),
-    }
-    .parse()
-    .map_err(|e| raw_to_parse_error(&map, *e).to_string())?;
+    compile_string(p, &Options::default()).map_err(|e| e.to_string().into())
+}
 
-    Ok(Css::from_stmts(stmts, false)
-        .map_err(|e| raw_to_parse_error(&map, *e).to_string())?
-        .pretty_print(&map)
-        .map_err(|e| raw_to_parse_error(&map, *e).to_string())?)
+/// Compile CSS from a string, configured by a JavaScript object
+///
+/// The options object may have a `recursionLimit`, as
+/// `Options::recursion_limit`, and a `sourceMap` of `"none"` or `"inline"`, as
+/// `Options::source_map`. A `charset` of `"auto"`, `"always"` or `"never"`
+/// sets `Options::charset`, and a `lineEnding` of `"lf"`, `"crlf"` or `"auto"`
+/// sets `Options::line_ending`. The object may also be `undefined` or `null` to use the
+/// defaults.
+///
+/// There is no file system, so files loaded by `@import`, `@use` and
+/// `@forward` are requested from an `importer` function. It is called with
+/// the path of each file that may be loaded, relative to the input, and
+/// returns the contents of the file as a string, or `null` if there is no
+/// such file. It may be called more than once for the same path. Without an
+/// importer, loading any file fails. A `dedupImports` boolean sets
+/// `Options::dedup_imports`.
+///
+/// ```js
+/// grass_compile("a { color: red; }", { recursionLimit: 100, sourceMap: "inline" });
+/// grass_compile("@use \"colors\";", {
+///     importer: (path) => path === "colors.scss" ? "$primary: red;" : null,
+/// });
+/// ```
+#[cfg(feature = "wasm")]
+#[wasm_bindgen]
+pub fn grass_compile(scss: &str, options: JsValue) -> std::result::Result<JsString, JsValue> {
+    let options = Options::from_js(&options)?;
+    compile_string(scss.to_owned(), &options)
+        .map(|css| JsString::from(css.as_str()))
+        .map_err(|e| e.to_string().into())
 }
@@ -1,12 +1,18 @@
 use std::{
-    fs::File,
+    collections::{BTreeMap, HashMap},
+    fs::{self, File},
     io::{stdout, BufWriter, Write},
+    path::{Path, PathBuf},
+    sync::mpsc::channel,
+    time::{Duration, Instant},
 };
 
 use clap::{arg_enum, App, Arg};
 
 #[cfg(not(feature = "wasm"))]
-use grass::from_path;
+use grass::{compile_with_deps, from_path, Options, SourceMapOption, VERSION};
+#[cfg(not(feature = "wasm"))]
+use notify::{DebouncedEvent, PollWatcher, RecommendedWatcher, RecursiveMode, Watcher};
 
 arg_enum! {
     #[derive(PartialEq, Debug)]
@@ -31,7 +37,7 @@ fn main() {}
 #[cfg_attr(feature = "profiling", inline(never))]
 fn main() -> std::io::Result<()> {
     let matches = App::new("grass")
-        .version(env!("CARGO_PKG_VERSION"))
+        .version(VERSION)
         .about("SCSS Compiler in rust")
         .version_short("v")
         .arg(
@@ -154,6 +160,13 @@ fn main() -> std::io::Result<()> {
             Arg::with_name("OUTPUT")
                 .help("Output SCSS file")
         )
+        .arg(
+            Arg::with_name("OUTPUT_FLAG")
+                .long("output")
+                .help("Output file, or the directory to write to when watching a directory")
+                .takes_value(true)
+                .conflicts_with("OUTPUT"),
+        )
 
         // Hidden, legacy arguments
         .arg(
@@ -164,11 +177,29 @@ fn main() -> std::io::Result<()> {
         )
         .get_matches();
 
+    let mut options = Options::default();
+
+    if matches.is_present("EMBED_SOURCE_MAP") && !matches.is_present("NO_SOURCE_MAP") {
+        options = options.source_map(SourceMapOption::Inline);
+    }
+
+    let output = matches
+        .value_of("OUTPUT")
+        .or_else(|| matches.value_of("OUTPUT_FLAG"));
+
+    if matches.is_present("WATCH") {
+        let input = matches.value_of("INPUT").unwrap_or_else(|| {
+            eprintln!("--watch requires an input file or directory");
+            std::process::exit(1)
+        });
+        return watch(input, output, matches.is_present("POLL"), &options);
+    }
+
     if let Some(name) = matches.value_of("INPUT") {
-        if let Some(path) = matches.value_of("OUTPUT") {
+        if let Some(path) = output {
             let mut buf = BufWriter::new(File::open(path).unwrap_or(File::create(path)?));
             buf.write_all(
-                from_path(name)
+                from_path(name, &options)
                     .unwrap_or_else(|e| {
                         eprintln!("{}", e);
                         std::process::exit(1)
@@ -178,7 +209,7 @@ fn main() -> std::io::Result<()> {
         } else {
             let mut stdout = BufWriter::new(stdout());
             stdout.write_all(
-                from_path(name)
+                from_path(name, &options)
                     .unwrap_or_else(|e| {
                         eprintln!("{}", e);
                         std::process::exit(1)
@@ -189,3 +220,275 @@ fn main() -> std::io::Result<()> {
     }
     Ok(())
 }
+
+/// How long to wait for changes to settle before recompiling
+#[cfg(not(feature = "wasm"))]
+const WATCH_DELAY: Duration = Duration::from_millis(100);
+
+#[cfg(not(feature = "wasm"))]
+fn is_stylesheet(path: &Path) -> bool {
+    matches!(
+        path.extension().and_then(|ext| ext.to_str()),
+        Some("scss") | Some("sass")
+    )
+}
+
+/// Stylesheets beneath `dir` which should be compiled to their own CSS file,
+/// ignoring partials
+#[cfg(not(feature = "wasm"))]
+fn entry_points(dir: &Path, entries: &mut Vec<PathBuf>) -> std::io::Result<()> {
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            entry_points(&path, entries)?;
+        } else if is_stylesheet(&path)
+            && !path
+                .file_name()
+                .and_then(|name| name.to_str())
+                .map_or(false, |name| name.starts_with('_'))
+        {
+            entries.push(path);
+        }
+    }
+    Ok(())
+}
+
+#[cfg(not(feature = "wasm"))]
+fn canonicalize(path: &Path) -> PathBuf {
+    fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf())
+}
+
+/// A stylesheet compiled by `--watch`, along with every file it loaded
+#[cfg(not(feature = "wasm"))]
+struct WatchedEntry {
+    output: Option<PathBuf>,
+    dependencies: Vec<PathBuf>,
+}
+
+#[cfg(not(feature = "wasm"))]
+impl WatchedEntry {
+    fn new(output: Option<PathBuf>) -> Self {
+        WatchedEntry {
+            output,
+            dependencies: Vec::new(),
+        }
+    }
+
+    /// Compile `input`, printing errors rather than returning them so that the
+    /// watcher keeps running
+    fn compile(&mut self, input: &Path, options: &Options) {
+        let start = Instant::now();
+
+        let (css, dependencies) = match compile_with_deps(&input.to_string_lossy(), options) {
+            Ok(v) => v,
+            Err(e) => {
+                eprintln!("{}", e);
+                return;
+            }
+        };
+
+        self.dependencies = dependencies.iter().map(|dep| canonicalize(dep)).collect();
+
+        let output = match &self.output {
+            Some(output) => output,
+            None => {
+                print!("{}", css);
+                return;
+            }
+        };
+
+        let written = match output.parent() {
+            Some(parent) if !parent.as_os_str().is_empty() => fs::create_dir_all(parent),
+            _ => Ok(()),
+        }
+        .and_then(|()| fs::write(output, css));
+
+        match written {
+            Ok(()) => println!(
+                "Compiled {} to {} in {}ms",
+                input.display(),
+                output.display(),
+                start.elapsed().as_millis()
+            ),
+            Err(e) => eprintln!("Error writing {}: {}", output.display(), e),
+        }
+    }
+}
+
+/// Compile `input`, which may be a file or a directory of stylesheets, and then
+/// recompile every stylesheet affected by a change until interrupted
+#[cfg(not(feature = "wasm"))]
+fn watch(input: &str, output: Option<&str>, poll: bool, options: &Options) -> std::io::Result<()> {
+    let input = Path::new(input);
+    let mut entries: HashMap<PathBuf, WatchedEntry> = HashMap::new();
+
+    let output_for = |entry: &Path| -> Option<PathBuf> {
+        let output = Path::new(output?);
+        if input.is_dir() {
+            let relative = entry.strip_prefix(input).unwrap_or(entry);
+            Some(output.join(relative).with_extension("css"))
+        } else {
+            Some(output.to_path_buf())
+        }
+    };
+
+    if input.is_dir() {
+        if output.is_none() {
+            eprintln!("--output is required when watching a directory");
+            std::process::exit(1);
+        }
+        let mut paths = Vec::new();
+        entry_points(input, &mut paths)?;
+        for path in paths {
+            entries.insert(path.clone(), WatchedEntry::new(output_for(&path)));
+        }
+    } else {
+        entries.insert(input.to_path_buf(), WatchedEntry::new(output_for(input)));
+    }
+
+    for (path, entry) in &mut entries {
+        entry.compile(path, options);
+    }
+
+    let watched = watched_paths(input, &entries);
+
+    let on_change = |changed: PathBuf| -> std::io::Result<BTreeMap<PathBuf, RecursiveMode>> {
+        let changed = canonicalize(&changed);
+
+        // a new stylesheet in a watched directory is compiled on its own
+        if input.is_dir()
+            && changed.is_file()
+            && entries.keys().all(|path| canonicalize(path) != changed)
+        {
+            let mut paths = Vec::new();
+            entry_points(input, &mut paths)?;
+            for path in paths {
+                if !entries.contains_key(&path) && canonicalize(&path) == changed {
+                    entries.insert(path.clone(), WatchedEntry::new(output_for(&path)));
+                }
+            }
+        }
+
+        entries.retain(|path, _| path.exists());
+
+        for (path, entry) in &mut entries {
+            if entry.dependencies.is_empty() || entry.dependencies.contains(&changed) {
+                entry.compile(path, options);
+            }
+        }
+
+        Ok(watched_paths(input, &entries))
+    };
+
+    if poll {
+        watch_events::<PollWatcher>(watched, on_change)
+    } else {
+        watch_events::<RecommendedWatcher>(watched, on_change)
+    }
+}
+
+/// Every path which must be watched to see changes to `input` and the files
+/// its stylesheets loaded
+///
+/// A directory `input` is watched recursively. Otherwise, the directory
+/// containing `input` and the directory containing each dependency are
+/// watched, so that files which are replaced rather than modified in place,
+/// as many editors do, are still seen.
+#[cfg(not(feature = "wasm"))]
+fn watched_paths(
+    input: &Path,
+    entries: &HashMap<PathBuf, WatchedEntry>,
+) -> BTreeMap<PathBuf, RecursiveMode> {
+    let input = canonicalize(input);
+    let mut watched = BTreeMap::new();
+
+    if input.is_dir() {
+        watched.insert(input.clone(), RecursiveMode::Recursive);
+    } else if let Some(parent) = input.parent() {
+        watched.insert(parent.to_path_buf(), RecursiveMode::NonRecursive);
+    }
+
+    for dependency in entries.values().flat_map(|entry| &entry.dependencies) {
+        match dependency.parent() {
+            Some(parent) if !(input.is_dir() && parent.starts_with(&input)) => {
+                watched.insert(parent.to_path_buf(), RecursiveMode::NonRecursive);
+            }
+            _ => {}
+        }
+    }
+
+    watched
+}
+
+/// Watch each of `paths` using `W`, calling `on_change` with the path of every
+/// stylesheet that is created, modified or removed. `on_change` returns the
+/// paths to watch from then on
+#[cfg(not(feature = "wasm"))]
+fn watch_events<W: Watcher>(
+    paths: BTreeMap<PathBuf, RecursiveMode>,
+    mut on_change: impl FnMut(PathBuf) -> std::io::Result<BTreeMap<PathBuf, RecursiveMode>>,
+) -> std::io::Result<()> {
+    let (tx, rx) = channel();
+
+    let mut watcher = W::new(tx, WATCH_DELAY).unwrap_or_else(|e| {
+        eprintln!("{}", e);
+        std::process::exit(1)
+    });
+
+    let mut watched = BTreeMap::new();
+    update_watches(&mut watcher, &mut watched, paths);
+
+    if watched.is_empty() {
+        std::process::exit(1)
+    }
+
+    eprintln!("Sass is watching for changes. Press Ctrl-C to stop.");
+
+    for event in rx {
+        match event {
+            DebouncedEvent::Create(path)
+            | DebouncedEvent::Write(path)
+            | DebouncedEvent::Remove(path)
+            | DebouncedEvent::Rename(_, path)
+                if is_stylesheet(&path) =>
+            {
+                let paths = on_change(path)?;
+                update_watches(&mut watcher, &mut watched, paths);
+            }
+            DebouncedEvent::Error(e, _) => eprintln!("{}", e),
+            _ => {}
+        }
+    }
+
+    Ok(())
+}
+
+/// Start watching every path in `paths` which is not yet in `watched`, and
+/// stop watching those which are no longer needed
+#[cfg(not(feature = "wasm"))]
+fn update_watches<W: Watcher>(
+    watcher: &mut W,
+    watched: &mut BTreeMap<PathBuf, RecursiveMode>,
+    paths: BTreeMap<PathBuf, RecursiveMode>,
+) {
+    watched.retain(|path, mode| {
+        if paths.get(path) == Some(mode) {
+            return true;
+        }
+        // the path may already have been removed, which also removes its watch
+        let _ = watcher.unwatch(path);
+        false
+    });
+
+    for (path, mode) in paths {
+        if watched.contains_key(&path) {
+            continue;
+        }
+        match watcher.watch(&path, mode) {
+            Ok(()) => {
+                watched.insert(path, mode);
+            }
+            Err(e) => eprintln!("Unable to watch {}: {}", path.display(), e),
+        }
+    }
+}
@@ -0,0 +1,597 @@
+//! # Modules
+//!
+//! Every stylesheet loaded with `@use` or `@forward` is a module. A module is
+//! evaluated only once per compilation, no matter how many times it is loaded,
+//! and is kept here so that its members may later be accessed through a
+//! namespace. Mixins and functions remember the module they were declared in,
+//! and are always evaluated against that module's global scope.
+use std::{
+    collections::{BTreeSet, HashMap, HashSet},
+    mem,
+    ops::{Index, IndexMut},
+    path::{Path, PathBuf},
+};
+
+use codemap::{Span, Spanned};
+
+use crate::{
+    args::CallArgs,
+    atrule::{Function, Mixin},
+    builtin::{Builtin, BuiltinMixin},
+    common::Identifier,
+    error::SassResult,
+    parse::Parser,
+    scope::Scope,
+    value::{SassFunction, Value},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) struct ModuleId(usize);
+
+impl ModuleId {
+    /// The stylesheet being compiled
+    pub const ROOT: Self = ModuleId(0);
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct Module {
+    /// The module's global variables, mixins, and functions
+    pub scope: Scope,
+    /// The canonical path of the file this module was loaded from, if it
+    /// was loaded from a file
+    path: Option<PathBuf>,
+    /// Modules loaded with `@use`, by namespace
+    namespaces: HashMap<Identifier, ModuleId>,
+    /// Modules loaded with `@use ... as *`
+    global_modules: Vec<ModuleId>,
+    /// Modules whose members are made available to anything loading this
+    /// module, through `@forward`
+    forwarded: Vec<Forward>,
+    /// Values given by `@use ... with (...)` for this module's `!default`
+    /// variables, which are removed as they are used
+    pub configuration: Configuration,
+    /// Mixins implemented by `grass` itself, for built-in modules
+    builtin_mixins: HashMap<Identifier, BuiltinMixin>,
+    /// Functions implemented by `grass` itself, for built-in modules
+    builtin_functions: HashMap<Identifier, Builtin>,
+    /// Read-only variables of built-in modules, such as `math.$pi`
+    builtin_variables: HashMap<Identifier, Value>,
+}
+
+/// The variables configured by a `with` clause
+pub(crate) type Configuration = HashMap<Identifier, Spanned<Value>>;
+
+/// A variable in the `with` clause of an `@forward`
+#[derive(Debug)]
+pub(crate) struct ForwardedVariable {
+    pub name: Identifier,
+    pub value: Spanned<Value>,
+    /// Whether the variable is marked `!default`, in which case a value
+    /// configured for the forwarding module takes precedence
+    pub guarded: bool,
+}
+
+impl Module {
+    pub fn new(path: Option<PathBuf>, configuration: Configuration) -> Self {
+        Module {
+            path,
+            configuration,
+            ..Module::default()
+        }
+    }
+
+    pub fn insert_builtin_mixin(&mut self, name: &'static str, mixin: BuiltinMixin) {
+        self.builtin_mixins.insert(name.into(), mixin);
+    }
+
+    pub fn insert_builtin_fn(
+        &mut self,
+        name: &'static str,
+        function: fn(CallArgs, &mut Parser<'_>) -> SassResult<Value>,
+    ) {
+        self.builtin_functions
+            .insert(name.into(), Builtin::new(function));
+    }
+
+    pub fn insert_builtin_var(&mut self, name: &'static str, value: Value) {
+        self.builtin_variables.insert(name.into(), value);
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct Forward {
+    module: ModuleId,
+    visibility: Visibility,
+    /// Prepended to the name of every forwarded member, as with
+    /// `@forward ... as prefix-*`
+    prefix: Option<Identifier>,
+}
+
+impl Forward {
+    pub const fn new(module: ModuleId, visibility: Visibility, prefix: Option<Identifier>) -> Self {
+        Forward {
+            module,
+            visibility,
+            prefix,
+        }
+    }
+
+    /// The name of the member of the forwarded module exposed as `name`, or
+    /// `None` if `name` lacks this forward's prefix
+    fn unprefixed(&self, name: &Identifier) -> Option<Identifier> {
+        match &self.prefix {
+            Some(prefix) => name
+                .as_str()
+                .strip_prefix(prefix.as_str())
+                .map(Identifier::from),
+            None => Some(name.clone()),
+        }
+    }
+
+    /// The name under which the member `name` of the forwarded module is
+    /// exposed
+    fn prefixed(&self, name: Identifier) -> Identifier {
+        match &self.prefix {
+            Some(prefix) => Identifier::from(format!("{}{}", prefix, name)),
+            None => name,
+        }
+    }
+}
+
+/// The members of a module exposed by `@forward`
+#[derive(Debug)]
+pub(crate) enum Visibility {
+    All,
+    Show(Members),
+    Hide(Members),
+}
+
+impl Visibility {
+    fn allows(&self, member: Member<'_>) -> bool {
+        match self {
+            Visibility::All => true,
+            Visibility::Show(members) => members.contains(member),
+            Visibility::Hide(members) => !members.contains(member),
+        }
+    }
+}
+
+/// The members named in a `show` or `hide` clause
+#[derive(Debug, Default)]
+pub(crate) struct Members {
+    variables: HashSet<Identifier>,
+    /// Mixins and functions, which are named the same way
+    callables: HashSet<Identifier>,
+}
+
+impl Members {
+    pub fn insert_variable(&mut self, name: Identifier) {
+        self.variables.insert(name);
+    }
+
+    pub fn insert_callable(&mut self, name: Identifier) {
+        self.callables.insert(name);
+    }
+
+    fn contains(&self, member: Member<'_>) -> bool {
+        match member {
+            Member::Variable(name) => self.variables.contains(name),
+            Member::Mixin(name) | Member::Function(name) => self.callables.contains(name),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Member<'a> {
+    Variable(&'a Identifier),
+    Mixin(&'a Identifier),
+    Function(&'a Identifier),
+}
+
+impl<'a> Member<'a> {
+    fn name(&self) -> &'a Identifier {
+        match self {
+            Member::Variable(name) | Member::Mixin(name) | Member::Function(name) => name,
+        }
+    }
+
+    /// A member of the same kind named `name`
+    fn renamed<'b>(&self, name: &'b Identifier) -> Member<'b> {
+        match self {
+            Member::Variable(..) => Member::Variable(name),
+            Member::Mixin(..) => Member::Mixin(name),
+            Member::Function(..) => Member::Function(name),
+        }
+    }
+}
+
+/// Errors if `name` is private to the module declaring it
+fn assert_public(name: &Spanned<Identifier>) -> SassResult<()> {
+    if name.node.is_private() {
+        return Err((
+            "Private members can't be accessed from outside their modules.",
+            name.span,
+        )
+            .into());
+    }
+    Ok(())
+}
+
+#[derive(Debug)]
+pub(crate) struct Modules(Vec<Module>);
+
+impl Modules {
+    pub fn new() -> Self {
+        Modules(vec![Module::default()])
+    }
+
+    pub fn insert(&mut self, module: Module) -> ModuleId {
+        self.0.push(module);
+        ModuleId(self.0.len() - 1)
+    }
+
+    /// The id that will be given to the next module inserted
+    pub fn next_id(&self) -> ModuleId {
+        ModuleId(self.0.len())
+    }
+
+    /// Find a module that has already been loaded from `path`
+    pub fn find(&self, path: &Path) -> Option<ModuleId> {
+        self.0
+            .iter()
+            .position(|module| module.path.as_deref() == Some(path))
+            .map(ModuleId)
+    }
+
+    pub fn insert_namespace(
+        &mut self,
+        module: ModuleId,
+        namespace: Spanned<Identifier>,
+        used: ModuleId,
+    ) -> SassResult<()> {
+        let namespaces = &mut self[module].namespaces;
+        if namespaces.contains_key(&namespace.node) {
+            return Err((
+                format!(
+                    "There's already a module with namespace \"{}\".",
+                    namespace.node
+                ),
+                namespace.span,
+            )
+                .into());
+        }
+        namespaces.insert(namespace.node, used);
+        Ok(())
+    }
+
+    pub fn insert_global_module(&mut self, module: ModuleId, used: ModuleId) {
+        self[module].global_modules.push(used);
+    }
+
+    pub fn insert_forward(&mut self, module: ModuleId, forward: Forward) {
+        self[module].forwarded.push(forward);
+    }
+
+    /// Remove the configured values `module` has not yet used
+    pub fn take_configuration(&mut self, module: ModuleId) -> Configuration {
+        mem::take(&mut self[module].configuration)
+    }
+
+    pub fn has_namespace(&self, module: ModuleId, namespace: &str) -> bool {
+        self[module]
+            .namespaces
+            .contains_key(&Identifier::from(namespace))
+    }
+
+    /// Find the module loaded by `module` under `namespace`
+    pub fn get_namespace(
+        &self,
+        module: ModuleId,
+        namespace: &Spanned<Identifier>,
+    ) -> SassResult<ModuleId> {
+        match self[module].namespaces.get(&namespace.node) {
+            Some(id) => Ok(*id),
+            None => Err((
+                format!(
+                    "There is no module with the namespace \"{}\".",
+                    namespace.node
+                ),
+                namespace.span,
+            )
+                .into()),
+        }
+    }
+
+    /// Find the module that declares `member`, if it is visible from outside
+    /// of `module`, along with the member's name within that module, which
+    /// differs if it was forwarded with a prefix
+    fn declaring_module(
+        &self,
+        module: ModuleId,
+        member: Member<'_>,
+    ) -> Option<(ModuleId, Identifier)> {
+        if member.name().is_private() {
+            return None;
+        }
+
+        let Module {
+            scope,
+            forwarded,
+            builtin_functions,
+            builtin_variables,
+            ..
+        } = &self[module];
+
+        let declared = match member {
+            Member::Variable(name) => {
+                scope.var_exists_no_global(name) || builtin_variables.contains_key(name)
+            }
+            Member::Mixin(name) => scope.mixin_exists_no_global(name),
+            Member::Function(name) => {
+                scope.fn_exists_no_global(name) || builtin_functions.contains_key(name)
+            }
+        };
+
+        if declared {
+            return Some((module, member.name().clone()));
+        }
+
+        forwarded
+            .iter()
+            .filter(|forward| forward.visibility.allows(member))
+            .find_map(|forward| {
+                let name = forward.unprefixed(member.name())?;
+                self.declaring_module(forward.module, member.renamed(&name))
+            })
+    }
+
+    /// Collect the names of the variables, or otherwise of the functions,
+    /// that are visible from outside of `module`
+    fn member_names(&self, module: ModuleId, variables: bool, names: &mut BTreeSet<Identifier>) {
+        let Module {
+            scope,
+            forwarded,
+            builtin_functions,
+            builtin_variables,
+            ..
+        } = &self[module];
+
+        if variables {
+            names.extend(scope.var_names().cloned());
+            names.extend(builtin_variables.keys().cloned());
+        } else {
+            names.extend(scope.fn_names().cloned());
+            names.extend(builtin_functions.keys().cloned());
+        }
+
+        names.retain(|name| !name.is_private());
+
+        for forward in forwarded {
+            let mut forwarded_names = BTreeSet::new();
+            self.member_names(forward.module, variables, &mut forwarded_names);
+            names.extend(
+                forwarded_names
+                    .into_iter()
+                    .map(|name| forward.prefixed(name))
+                    .filter(|name| {
+                        forward.visibility.allows(if variables {
+                            Member::Variable(name)
+                        } else {
+                            Member::Function(name)
+                        })
+                    }),
+            );
+        }
+    }
+
+    /// The variables visible from outside of `module`, ordered by name
+    pub fn variables(&self, module: ModuleId, span: Span) -> Vec<(Identifier, Value)> {
+        let mut names = BTreeSet::new();
+        self.member_names(module, true, &mut names);
+        names
+            .into_iter()
+            .filter_map(|name| {
+                let value = self.get_var(
+                    module,
+                    Spanned {
+                        node: name.clone(),
+                        span,
+                    },
+                );
+                value.ok().map(|value| (name, value.node))
+            })
+            .collect()
+    }
+
+    /// The functions visible from outside of `module`, ordered by name
+    pub fn functions(&self, module: ModuleId, span: Span) -> Vec<(Identifier, SassFunction)> {
+        let mut names = BTreeSet::new();
+        self.member_names(module, false, &mut names);
+        names
+            .into_iter()
+            .filter_map(|name| {
+                if let Some(func) = self.get_builtin_fn(module, &name) {
+                    return Some((name.clone(), SassFunction::Builtin(func, name)));
+                }
+                let func = self.get_fn(
+                    module,
+                    Spanned {
+                        node: name.clone(),
+                        span,
+                    },
+                );
+                func.ok().map(|func| {
+                    (
+                        name.clone(),
+                        SassFunction::UserDefined(Box::new(func), name),
+                    )
+                })
+            })
+            .collect()
+    }
+
+    /// Find a member in the modules loaded by `module` with `@use ... as *`
+    fn declaring_global_module(
+        &self,
+        module: ModuleId,
+        member: Member<'_>,
+    ) -> Option<(ModuleId, Identifier)> {
+        self[module]
+            .global_modules
+            .iter()
+            .find_map(|used| self.declaring_module(*used, member))
+    }
+
+    pub fn get_var(
+        &self,
+        module: ModuleId,
+        name: Spanned<Identifier>,
+    ) -> SassResult<Spanned<Value>> {
+        assert_public(&name)?;
+        match self.declaring_module(module, Member::Variable(&name.node)) {
+            Some((id, declared)) => self.get_declared_var(
+                id,
+                &Spanned {
+                    node: declared,
+                    span: name.span,
+                },
+            ),
+            None => Err(("Undefined variable.", name.span).into()),
+        }
+    }
+
+    /// Check an assignment to a variable that is visible from outside of
+    /// `module`, such as with `lib.$var: value`
+    ///
+    /// The variables of other modules are read-only, so this always fails,
+    /// unless the variable doesn't exist, in which case that is the error
+    pub fn assign_var(&self, module: ModuleId, name: &Spanned<Identifier>) -> SassResult<()> {
+        assert_public(name)?;
+        if self
+            .declaring_module(module, Member::Variable(&name.node))
+            .is_none()
+        {
+            return Err(("Undefined variable.", name.span).into());
+        }
+
+        Err(("Cannot modify variable in other module.", name.span).into())
+    }
+
+    /// Find a variable declared directly in `module`, including the variables
+    /// of built-in modules
+    fn get_declared_var(
+        &self,
+        module: ModuleId,
+        name: &Spanned<Identifier>,
+    ) -> SassResult<Spanned<Value>> {
+        match self[module].builtin_variables.get(&name.node) {
+            Some(value) => Ok(Spanned {
+                node: value.clone(),
+                span: name.span,
+            }),
+            None => self[module].scope.get_var_no_global(name),
+        }
+    }
+
+    pub fn get_mixin(&self, module: ModuleId, name: Spanned<Identifier>) -> SassResult<Mixin> {
+        assert_public(&name)?;
+        match self.declaring_module(module, Member::Mixin(&name.node)) {
+            Some((id, declared)) => self[id].scope.get_mixin_no_global(&Spanned {
+                node: declared,
+                span: name.span,
+            }),
+            None => Err(("Undefined mixin.", name.span).into()),
+        }
+    }
+
+    pub fn get_fn(&self, module: ModuleId, name: Spanned<Identifier>) -> SassResult<Function> {
+        assert_public(&name)?;
+        match self.declaring_module(module, Member::Function(&name.node)) {
+            Some((id, declared)) => self[id].scope.get_fn_no_global(&Spanned {
+                node: declared,
+                span: name.span,
+            }),
+            None => Err(("Undefined function.", name.span).into()),
+        }
+    }
+
+    /// Find a mixin implemented by `grass` itself in the built-in `module`
+    pub fn get_builtin_mixin(&self, module: ModuleId, name: &Identifier) -> Option<BuiltinMixin> {
+        self[module].builtin_mixins.get(name).copied()
+    }
+
+    /// Find a function implemented by `grass` itself that is visible from
+    /// outside of `module`
+    pub fn get_builtin_fn(&self, module: ModuleId, name: &Identifier) -> Option<Builtin> {
+        self.declaring_module(module, Member::Function(name))
+            .and_then(|(id, declared)| self[id].builtin_functions.get(&declared).cloned())
+    }
+
+    /// Find a function implemented by `grass` itself made available to
+    /// `module` by `@use ... as *`
+    pub fn get_global_builtin_fn(&self, module: ModuleId, name: &Identifier) -> Option<Builtin> {
+        self.declaring_global_module(module, Member::Function(name))
+            .and_then(|(id, declared)| self[id].builtin_functions.get(&declared).cloned())
+    }
+
+    /// Find a variable made available to `module` by `@use ... as *`
+    pub fn get_global_var(
+        &self,
+        module: ModuleId,
+        name: &Spanned<Identifier>,
+    ) -> Option<Spanned<Value>> {
+        self.declaring_global_module(module, Member::Variable(&name.node))
+            .and_then(|(id, declared)| {
+                self.get_declared_var(
+                    id,
+                    &Spanned {
+                        node: declared,
+                        span: name.span,
+                    },
+                )
+                .ok()
+            })
+    }
+
+    /// Find a mixin made available to `module` by `@use ... as *`
+    pub fn get_global_mixin(&self, module: ModuleId, name: &Spanned<Identifier>) -> Option<Mixin> {
+        self.declaring_global_module(module, Member::Mixin(&name.node))
+            .and_then(|(id, declared)| {
+                self[id]
+                    .scope
+                    .get_mixin_no_global(&Spanned {
+                        node: declared,
+                        span: name.span,
+                    })
+                    .ok()
+            })
+    }
+
+    /// Find a function made available to `module` by `@use ... as *`
+    pub fn get_global_fn(&self, module: ModuleId, name: &Spanned<Identifier>) -> Option<Function> {
+        self.declaring_global_module(module, Member::Function(&name.node))
+            .and_then(|(id, declared)| {
+                self[id]
+                    .scope
+                    .get_fn_no_global(&Spanned {
+                        node: declared,
+                        span: name.span,
+                    })
+                    .ok()
+            })
+    }
+}
+
+impl Index<ModuleId> for Modules {
+    type Output = Module;
+
+    fn index(&self, id: ModuleId) -> &Module {
+        &self.0[id.0]
+    }
+}
+
+impl IndexMut<ModuleId> for Modules {
+    fn index_mut(&mut self, id: ModuleId) -> &mut Module {
+        &mut self.0[id.0]
+    }
+}
@@ -12,7 +12,9 @@ use crate::{
     error::SassResult,
     parse::Stmt,
     selector::Selector,
+    source_map::{SourceMap, SourceMapOption},
     style::Style,
+    CharsetBehavior, Options,
 };
 
 #[derive(Debug, Clone)]
@@ -29,7 +31,7 @@ enum Toplevel {
     UnknownAtRule(Box<ToplevelUnknownAtRule>),
     Keyframes(Box<Keyframes>),
     KeyframesRuleSet(Vec<KeyframesSelector>, Vec<BlockEntry>),
-    Media { query: String, body: Vec<Stmt> },
+    Media { query: String, body: Css },
     Supports { params: String, body: Vec<Stmt> },
     Newline,
     Style(Style),
@@ -59,6 +61,25 @@ impl Toplevel {
         Toplevel::KeyframesRuleSet(selector, Vec::new())
     }
 
+    /// Create a `@media` rule, unless its body produces no output
+    fn new_media(media: MediaRule) -> SassResult<Option<Self>> {
+        let MediaRule { query, body, .. } = media;
+        let body = Css::from_stmts(body, true)?;
+        if body.is_empty() {
+            return Ok(None);
+        }
+        Ok(Some(Toplevel::Media { query, body }))
+    }
+
+    fn is_empty_ruleset(&self) -> bool {
+        match self {
+            Toplevel::RuleSet(_, entries) | Toplevel::KeyframesRuleSet(_, entries) => {
+                entries.is_empty()
+            }
+            _ => false,
+        }
+    }
+
     fn push_style(&mut self, s: Style) {
         if s.value.is_null() {
             return;
@@ -93,6 +114,14 @@ impl Css {
         }
     }
 
+    /// Whether this would produce no output. Empty rulesets and `@media`
+    /// rules are never added, so only the newlines between blocks remain
+    fn is_empty(&self) -> bool {
+        self.blocks
+            .iter()
+            .all(|block| matches!(block, Toplevel::Newline))
+    }
+
     pub(crate) fn from_stmts(s: Vec<Stmt>, in_at_rule: bool) -> SassResult<Self> {
         Css::new(in_at_rule).parse_stylesheet(s)
     }
@@ -113,10 +142,7 @@ impl Css {
                         Stmt::RuleSet { .. } => vals.extend(self.parse_stmt(rule)?),
                         Stmt::Style(s) => vals.get_mut(0).unwrap().push_style(s),
                         Stmt::Comment(s) => vals.get_mut(0).unwrap().push_comment(s),
-                        Stmt::Media(m) => {
-                            let MediaRule { query, body, .. } = *m;
-                            vals.push(Toplevel::Media { query, body })
-                        }
+                        Stmt::Media(m) => vals.extend(Toplevel::new_media(*m)?),
                         Stmt::Supports(s) => {
                             let SupportsRule { params, body } = *s;
                             vals.push(Toplevel::Supports { params, body })
@@ -147,14 +173,14 @@ impl Css {
                         }
                     };
                 }
+                // a ruleset whose body produced no output, including one
+                // containing only empty rulesets, is omitted entirely
+                vals.retain(|v| !v.is_empty_ruleset());
                 vals
             }
             Stmt::Comment(s) => vec![Toplevel::MultilineComment(s)],
             Stmt::Style(s) => vec![Toplevel::Style(s)],
-            Stmt::Media(m) => {
-                let MediaRule { query, body, .. } = *m;
-                vec![Toplevel::Media { query, body }]
-            }
+            Stmt::Media(m) => Toplevel::new_media(*m)?.into_iter().collect(),
             Stmt::Supports(s) => {
                 let SupportsRule { params, body } = *s;
                 vec![Toplevel::Supports { params, body }]
@@ -185,6 +211,7 @@ impl Css {
                         _ => todo!(),
                     }
                 }
+                vals.retain(|v| !v.is_empty_ruleset());
                 vals
             }
         })
@@ -209,21 +236,42 @@ impl Css {
         Ok(self)
     }
 
-    pub fn pretty_print(self, map: &CodeMap) -> SassResult<String> {
+    pub fn pretty_print(self, map: &CodeMap, options: &Options) -> SassResult<String> {
         let mut string = Vec::new();
-        self._inner_pretty_print(&mut string, map, 0)?;
-        if string.iter().any(|s| !s.is_ascii()) {
-            return Ok(format!("@charset \"UTF-8\";\n{}", unsafe {
+        let mut source_map = match options.source_map {
+            SourceMapOption::None => None,
+            SourceMapOption::Inline => Some(SourceMap::new()),
+        };
+        self._inner_pretty_print(&mut string, map, &mut source_map, 0)?;
+        // this must be checked against the full output, as any rule may
+        // contain non-ascii characters
+        let emit_charset = match options.charset {
+            CharsetBehavior::Auto => string.iter().any(|s| !s.is_ascii()),
+            CharsetBehavior::Always => !string.is_empty(),
+            CharsetBehavior::Never => false,
+        };
+        let mut css = if emit_charset {
+            if let Some(source_map) = &mut source_map {
+                source_map.prepend_line();
+            }
+            format!("@charset \"UTF-8\";\n{}", unsafe {
                 String::from_utf8_unchecked(string)
-            }));
+            })
+        } else {
+            unsafe { String::from_utf8_unchecked(string) }
+        };
+        if let Some(source_map) = source_map {
+            css.push_str(&source_map.to_inline_comment());
+            css.push('\n');
         }
-        Ok(unsafe { String::from_utf8_unchecked(string) })
+        Ok(css)
     }
 
     fn _inner_pretty_print(
         self,
         buf: &mut Vec<u8>,
         map: &CodeMap,
+        source_map: &mut Option<SourceMap>,
         nesting: usize,
     ) -> SassResult<()> {
         let mut has_written = false;
@@ -240,8 +288,16 @@ impl Css {
                         should_emit_newline = false;
                         writeln!(buf)?;
                     }
+                    if let Some(source_map) = source_map {
+                        source_map.add(buf, padding.len(), selector.0.span, map);
+                    }
                     writeln!(buf, "{}{} {{", padding, selector)?;
                     for style in styles {
+                        if let (Some(source_map), BlockEntry::Style(style)) =
+                            (&mut *source_map, &style)
+                        {
+                            source_map.add(buf, padding.len() + 2, style.value.span, map);
+                        }
                         writeln!(buf, "{}  {}", padding, style.to_string()?)?;
                     }
                     writeln!(buf, "{}}}", padding)?;
@@ -291,7 +347,12 @@ impl Css {
                         writeln!(buf, " {{")?;
                     }
 
-                    Css::from_stmts(body, true)?._inner_pretty_print(buf, map, nesting + 1)?;
+                    Css::from_stmts(body, true)?._inner_pretty_print(
+                        buf,
+                        map,
+                        source_map,
+                        nesting + 1,
+                    )?;
                     writeln!(buf, "{}}}", padding)?;
                 }
                 Toplevel::Keyframes(k) => {
@@ -314,7 +375,12 @@ impl Css {
                         writeln!(buf, " {{")?;
                     }
 
-                    Css::from_stmts(body, true)?._inner_pretty_print(buf, map, nesting + 1)?;
+                    Css::from_stmts(body, true)?._inner_pretty_print(
+                        buf,
+                        map,
+                        source_map,
+                        nesting + 1,
+                    )?;
                     writeln!(buf, "{}}}", padding)?;
                 }
                 Toplevel::Supports { params, body } => {
@@ -336,19 +402,23 @@ impl Css {
                         writeln!(buf, " {{")?;
                     }
 
-                    Css::from_stmts(body, true)?._inner_pretty_print(buf, map, nesting + 1)?;
+                    Css::from_stmts(body, true)?._inner_pretty_print(
+                        buf,
+                        map,
+                        source_map,
+                        nesting + 1,
+                    )?;
                     writeln!(buf, "{}}}", padding)?;
                 }
                 Toplevel::Media { query, body } => {
-                    if body.is_empty() {
-                        continue;
-                    }
-
                     writeln!(buf, "{}@media {} {{", padding, query)?;
-                    Css::from_stmts(body, true)?._inner_pretty_print(buf, map, nesting + 1)?;
+                    body._inner_pretty_print(buf, map, source_map, nesting + 1)?;
                     writeln!(buf, "{}}}", padding)?;
                 }
                 Toplevel::Style(s) => {
+                    if let Some(source_map) = source_map {
+                        source_map.add(buf, padding.len(), s.value.span, map);
+                    }
                     writeln!(buf, "{}{}", padding, s.to_string()?)?;
                 }
                 Toplevel::Newline => {
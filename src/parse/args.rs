@@ -4,10 +4,11 @@ use codemap::{Span, Spanned};
 
 use crate::{
     args::{CallArg, CallArgs, FuncArg, FuncArgs},
+    common::QuoteKind,
     error::SassResult,
     scope::Scope,
     utils::{read_until_closing_paren, read_until_closing_quote, read_until_closing_square_brace},
-    value::Value,
+    value::{SassMap, Value},
     Token,
 };
 
@@ -178,7 +179,7 @@ impl<'a> Parser<'a> {
                             } else {
                                 CallArg::Named(name.into())
                             },
-                            self.parse_value_from_vec(val),
+                            self.parse_value_from_vec(val, false),
                         );
                         span = span.merge(tok.pos());
                         return Ok(CallArgs(args, span));
@@ -218,12 +219,16 @@ impl<'a> Parser<'a> {
             }
 
             if is_splat {
-                let val = self.parse_value_from_vec(mem::take(&mut val))?;
+                let val = self.parse_value_from_vec(mem::take(&mut val), false)?;
                 match val.node {
-                    Value::ArgList(v) => {
+                    Value::ArgList(v, keywords) => {
                         for arg in v {
                             args.insert(CallArg::Positional(args.len()), Ok(arg));
                         }
+                        for (name, arg) in keywords.entries() {
+                            let name = name.to_css_string(val.span)?.to_string();
+                            args.insert(CallArg::Named(name.into()), Ok(arg.span(val.span)));
+                        }
                     }
                     Value::List(v, ..) => {
                         for arg in v {
@@ -240,6 +245,13 @@ impl<'a> Parser<'a> {
                         args.insert(CallArg::Positional(args.len()), Ok(val));
                     }
                 }
+
+                // a list splat may be followed by a map splat, as in
+                // `foo($list..., $map...)`
+                self.whitespace_or_comment();
+                if let Some(Token { kind: ',', .. }) = self.toks.peek() {
+                    self.toks.next();
+                }
             } else {
                 args.insert(
                     if name.is_empty() {
@@ -247,7 +259,7 @@ impl<'a> Parser<'a> {
                     } else {
                         CallArg::Named(name.as_str().into())
                     },
-                    self.parse_value_from_vec(mem::take(&mut val)),
+                    self.parse_value_from_vec(mem::take(&mut val), false),
                 );
             }
 
@@ -335,6 +347,31 @@ impl<'a> Parser<'a> {
         Ok(vals)
     }
 
+    /// Collects the arguments passed to a variadic parameter, with any named
+    /// arguments becoming its keywords
+    fn arg_list(&self, args: CallArgs) -> SassResult<Value> {
+        let mut positional = Vec::new();
+        let mut named = Vec::new();
+        for (arg, val) in args.0 {
+            match arg {
+                CallArg::Positional(idx) => positional.push((idx, val?)),
+                CallArg::Named(name) => named.push((name.into_inner(), val?)),
+            }
+        }
+        positional.sort_by(|(a1, _), (a2, _)| a1.cmp(a2));
+        named.sort_by(|(a1, _), (a2, _)| a1.cmp(a2));
+
+        let mut keywords = SassMap::new();
+        for (name, val) in named {
+            keywords.insert(Value::String(name, QuoteKind::None), val.node);
+        }
+
+        Ok(Value::ArgList(
+            positional.into_iter().map(|(_, val)| val).collect(),
+            keywords,
+        ))
+    }
+
     pub(super) fn eval_args(
         &mut self,
         mut fn_args: FuncArgs,
@@ -346,7 +383,7 @@ impl<'a> Parser<'a> {
             if arg.is_variadic {
                 let span = args.span();
                 // todo: does this get the most recent scope?
-                let arg_list = Value::ArgList(self.variadic_args(args)?);
+                let arg_list = self.arg_list(args)?;
                 scope.insert_var(
                     arg.name.clone(),
                     Spanned {
@@ -359,7 +396,7 @@ impl<'a> Parser<'a> {
             let val = match args.get(idx, arg.name.clone()) {
                 Some(v) => v,
                 None => match arg.default.as_mut() {
-                    Some(v) => self.parse_value_from_vec(mem::take(v)),
+                    Some(v) => self.parse_value_from_vec(mem::take(v), false),
                     None => {
                         return Err(
                             (format!("Missing argument ${}.", &arg.name), args.span()).into()
@@ -109,6 +109,10 @@ impl ContextFlags {
     pub fn in_keyframes(self) -> bool {
         (self.0 & Self::IN_KEYFRAMES) != 0
     }
+
+    pub fn unset(&mut self, flag: ContextFlag) {
+        self.0 &= !flag.0;
+    }
 }
 
 impl BitAnd<ContextFlag> for u8 {
@@ -50,10 +50,17 @@ impl<'a> Parser<'a> {
         });
         self.whitespace();
 
-        let function = Function::new(self.scopes.last().clone(), args, body, span);
+        let function = Function::new(
+            self.scopes.last().clone(),
+            args,
+            body,
+            self.module,
+            self.path.to_path_buf(),
+            span,
+        );
 
         if self.at_root {
-            self.global_scope.insert_fn(name, function);
+            self.global_scope_mut().insert_fn(name, function);
         } else {
             self.scopes.last_mut().insert_fn(name, function);
         }
@@ -62,7 +69,7 @@ impl<'a> Parser<'a> {
 
     pub(super) fn parse_return(&mut self) -> SassResult<Box<Value>> {
         let toks = read_until_semicolon_or_closing_curly_brace(self.toks)?;
-        let v = self.parse_value_from_vec(toks)?;
+        let v = self.parse_value_from_vec(toks, false)?;
         if let Some(Token { kind: ';', .. }) = self.toks.peek() {
             self.toks.next();
         }
@@ -74,6 +81,8 @@ impl<'a> Parser<'a> {
             mut scope,
             body,
             args: fn_args,
+            module,
+            path,
             ..
         } = function;
 
@@ -82,16 +91,23 @@ impl<'a> Parser<'a> {
         let mut return_value = Parser {
             toks: &mut body.into_iter().peekmore(),
             map: self.map,
-            path: self.path,
+            path: &path,
             scopes: &mut NeverEmptyVec::new(scope),
-            global_scope: self.global_scope,
+            modules: self.modules,
+            module,
             super_selectors: self.super_selectors,
             span_before: self.span_before,
             content: self.content,
+            call_stack: self.call_stack,
+            options: self.options,
+            dependencies: self.dependencies,
+            import_stack: self.import_stack,
+            imported: self.imported,
             flags: self.flags | ContextFlags::IN_FUNCTION,
             at_root: false,
             at_root_has_selector: self.at_root_has_selector,
             extender: self.extender,
+            media_queries: self.media_queries,
         }
         .parse()?;
 
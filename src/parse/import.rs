@@ -1,8 +1,18 @@
-use std::{ffi::OsStr, fs, path::Path};
+use std::{
+    ffi::OsStr,
+    path::{Path, PathBuf},
+};
 
+use codemap::{Span, Spanned};
 use peekmore::PeekMore;
 
-use crate::{error::SassResult, Token};
+use crate::{
+    atrule::UnknownAtRule,
+    error::SassResult,
+    selector::Selector,
+    utils::{canonicalize, glob, is_file, read_file},
+    Cow, Options, Token,
+};
 
 use crate::lexer::Lexer;
 
@@ -12,29 +22,201 @@ impl<'a> Parser<'a> {
     pub(super) fn import(&mut self) -> SassResult<Vec<Stmt>> {
         self.whitespace();
         let mut file_name = String::new();
-        let next = match self.toks.next() {
-            Some(v) => v,
+        let next = match self.toks.peek() {
+            Some(v) => *v,
             None => return Err(("expected more input.", self.span_before).into()),
         };
-        match next.kind {
+        let mut url_span = next.pos();
+        let plain_css_url = match next.kind {
             q @ '"' | q @ '\'' => {
-                file_name.push_str(
-                    &self
-                        .parse_quoted_string(q)?
-                        .node
-                        .unquote()
-                        .to_css_string(self.span_before)?,
-                );
+                self.toks.next();
+                let url = self.parse_quoted_string(q)?;
+                url_span = url.span;
+                file_name.push_str(&url.node.clone().unquote().to_css_string(url.span)?);
+                if is_plain_css_import(&file_name) {
+                    Some(url.node.to_css_string(url.span)?.into_owned())
+                } else {
+                    None
+                }
             }
+            'u' | 'U' => Some(self.parse_import_url()?),
             _ => return Err(("Expected string.", next.pos()).into()),
+        };
+
+        self.whitespace();
+
+        let media = if self.has_import_media_query() {
+            Some(self.parse_media_query_list()?)
+        } else {
+            None
+        };
+
+        if let Some(Token { kind: ';', .. }) = self.toks.peek() {
+            self.toks.next();
         }
-        if let Some(t) = self.toks.peek() {
-            if t.kind == ';' {
-                self.toks.next();
+
+        self.whitespace();
+
+        if let Some(url) = plain_css_url {
+            return Ok(vec![Stmt::UnknownAtRule(Box::new(UnknownAtRule {
+                name: "import".to_owned(),
+                super_selector: Selector::new(self.span_before),
+                params: match media {
+                    Some(media) => format!("{} {}", url, media),
+                    None => url,
+                },
+                body: Vec::new(),
+            }))]);
+        }
+
+        let stmts = if self.options.allow_import_globs && is_glob(&file_name) {
+            let mut stmts = Vec::new();
+            for name in self.find_glob_imports(&file_name) {
+                stmts.extend(self.import_file(name, next.pos())?);
             }
+            stmts
+        } else if let Some(name) = self.find_import(&file_name) {
+            self.import_file(name, next.pos())?
+        } else {
+            return Err(("Can't find stylesheet to import.", url_span).into());
+        };
+
+        Ok(match media {
+            Some(query) => vec![self.media_rule(query, stmts)],
+            None => stmts,
+        })
+    }
+
+    /// Parse the Sass file at `name`, as imported by an `@import` at `span`
+    fn import_file(&mut self, name: PathBuf, span: Span) -> SassResult<Vec<Stmt>> {
+        if self.is_being_imported(&name) {
+            let cycle = self
+                .import_stack
+                .iter()
+                .chain(std::iter::once(&name))
+                .map(|path| path.to_string_lossy())
+                .collect::<Vec<_>>()
+                .join(" \u{2192} ");
+            return Err((format!("Circular @import: {}", cycle), self.span_before).into());
+        }
+
+        let canonical = canonicalize(&name, self.options).unwrap_or_else(|_| name.clone());
+        if !self.imported.insert(canonical) {
+            self.warn(&Spanned {
+                node: Cow::owned(format!(
+                    "{} has already been imported. Importing a file more than once is deprecated.",
+                    name.to_string_lossy()
+                )),
+                span,
+            });
+            if self.options.dedup_imports {
+                return Ok(Vec::new());
+            }
+        }
+
+        if !self.dependencies.contains(&name) {
+            self.dependencies.push(name.clone());
+        }
+
+        let file = self.map.add_file(
+            name.to_string_lossy().into(),
+            String::from_utf8(read_file(&name, self.options)?)?,
+        );
+
+        self.import_stack.push(name.clone());
+
+        let stmts = Parser {
+            toks: &mut Lexer::new(&file)
+                .collect::<Vec<Token>>()
+                .into_iter()
+                .peekmore(),
+            map: self.map,
+            path: name.as_ref(),
+            scopes: self.scopes,
+            modules: self.modules,
+            module: self.module,
+            super_selectors: self.super_selectors,
+            span_before: file.span.subspan(0, 0),
+            content: self.content,
+            call_stack: self.call_stack,
+            options: self.options,
+            dependencies: self.dependencies,
+            import_stack: self.import_stack,
+            imported: self.imported,
+            flags: self.flags,
+            at_root: self.at_root,
+            at_root_has_selector: self.at_root_has_selector,
+            extender: self.extender,
+            media_queries: self.media_queries,
+        }
+        .parse();
+
+        self.import_stack.pop();
+
+        stmts
+    }
+
+    /// Whether a media query follows the url of an import, before the end of
+    /// the statement
+    ///
+    /// The trailing semicolon may be omitted, in which case a following `{`
+    /// belongs to the next style rule rather than the import
+    fn has_import_media_query(&mut self) -> bool {
+        let mut has_media_query = false;
+        let mut interpolation_depth = 0_usize;
+        let mut prev = None;
+        while let Some(tok) = self.toks.peek() {
+            match tok.kind {
+                '{' if prev == Some('#') => interpolation_depth += 1,
+                '}' if interpolation_depth > 0 => interpolation_depth -= 1,
+                ';' | '{' | '}' if interpolation_depth == 0 => {
+                    has_media_query &= tok.kind != '{';
+                    break;
+                }
+                _ => {}
+            }
+            has_media_query = true;
+            prev = Some(tok.kind);
+            self.toks.advance_cursor();
+        }
+        self.toks.reset_cursor();
+        has_media_query
+    }
+
+    /// Parse a `url(...)` import, which is always passed through to the
+    /// output as plain CSS
+    fn parse_import_url(&mut self) -> SassResult<String> {
+        let name = self.parse_identifier()?;
+        if !name.node.eq_ignore_ascii_case("url") || !self.scan_char('(') {
+            return Err(("Expected string.", name.span).into());
         }
 
+        if let Some(url) = self.try_eat_url()? {
+            return Ok(url);
+        }
+
+        self.whitespace();
+        let url = match self.toks.next() {
+            Some(Token { kind: q @ '"', .. }) | Some(Token { kind: q @ '\'', .. }) => {
+                self.parse_quoted_string(q)?
+            }
+            Some(Token { pos, .. }) => return Err(("Expected string.", pos).into()),
+            None => return Err(("expected more input.", name.span).into()),
+        };
         self.whitespace();
+        self.expect_char(')')?;
+
+        Ok(format!("url({})", url.node.to_css_string(url.span)?))
+    }
+
+    /// Find the file that `file_name` refers to, relative to the file
+    /// currently being parsed
+    pub(super) fn find_import(&self, file_name: &str) -> Option<PathBuf> {
+        if self.options.node_modules_resolution {
+            if let Some(file_name) = file_name.strip_prefix('~') {
+                return self.find_node_module(file_name);
+            }
+        }
 
         let path: &Path = file_name.as_ref();
 
@@ -47,51 +229,97 @@ impl<'a> Parser<'a> {
                 .unwrap_or_else(|| Path::new(""))
                 .join(path)
         };
-        // todo: will panic if path ended in `..`
-        let name = path_buf.file_name().unwrap();
-        if path_buf.extension() == Some(OsStr::new(".css")) {
-            // || name.starts_with("http://") || name.starts_with("https://") {
-            todo!("css imports")
-        }
 
-        let paths = [
-            path_buf.with_file_name(name).with_extension("scss"),
-            path_buf
-                .with_file_name(format!("_{}", name.to_str().unwrap()))
-                .with_extension("scss"),
-            path_buf.clone(),
-            path_buf.join("index.scss"),
-            path_buf.join("_index.scss"),
-        ];
-
-        for name in &paths {
-            if name.is_file() {
-                let file = self.map.add_file(
-                    name.to_string_lossy().into(),
-                    String::from_utf8(fs::read(name)?)?,
-                );
-
-                return Parser {
-                    toks: &mut Lexer::new(&file)
-                        .collect::<Vec<Token>>()
-                        .into_iter()
-                        .peekmore(),
-                    map: self.map,
-                    path: name.as_ref(),
-                    scopes: self.scopes,
-                    global_scope: self.global_scope,
-                    super_selectors: self.super_selectors,
-                    span_before: file.span.subspan(0, 0),
-                    content: self.content,
-                    flags: self.flags,
-                    at_root: self.at_root,
-                    at_root_has_selector: self.at_root_has_selector,
-                    extender: self.extender,
-                }
-                .parse();
-            }
-        }
+        resolve_import(&path_buf, self.options)
+    }
 
-        Ok(Vec::new())
+    /// Find `file_name` in the closest `node_modules` directory containing
+    /// it, starting from the directory of the file currently being parsed
+    fn find_node_module(&self, file_name: &str) -> Option<PathBuf> {
+        let dir = self.path.parent().unwrap_or_else(|| Path::new(""));
+        let dir = if dir.as_os_str().is_empty() {
+            std::env::current_dir().ok()?
+        } else {
+            canonicalize(dir, self.options).ok()?
+        };
+
+        dir.ancestors()
+            .find_map(|dir| resolve_import(&dir.join("node_modules").join(file_name), self.options))
     }
+
+    /// Find the `.scss` files matched by the glob `pattern`, relative to the
+    /// current file, in alphabetical order
+    fn find_glob_imports(&self, pattern: &str) -> Vec<PathBuf> {
+        let dir = if Path::new(pattern).is_absolute() {
+            Path::new("/")
+        } else {
+            self.path.parent().unwrap_or_else(|| Path::new(""))
+        };
+
+        // a trailing `**` only matches directories, so match the files within
+        // them. Like other imports, a glob may leave off the `.scss` extension
+        let patterns = if pattern.ends_with("**") {
+            vec![format!("{}/*", pattern)]
+        } else {
+            vec![format!("{}.scss", pattern), pattern.to_owned()]
+        };
+
+        let mut paths: Vec<PathBuf> = patterns
+            .iter()
+            .flat_map(|pattern| glob(dir, pattern))
+            .filter(|path| path.is_file() && path.extension() == Some(OsStr::new("scss")))
+            .collect();
+        paths.sort();
+        paths.dedup();
+
+        paths
+    }
+
+    /// Whether `path` refers to a file that is already in the middle of
+    /// being imported
+    pub(super) fn is_being_imported(&self, path: &Path) -> bool {
+        let path = match canonicalize(path, self.options) {
+            Ok(path) => path,
+            Err(..) => return false,
+        };
+        self.import_stack
+            .iter()
+            .any(|p| canonicalize(p, self.options).map_or(false, |p| p == path))
+    }
+}
+
+/// Find the Sass file that an import of `path_buf` refers to, trying partials
+/// and index files
+fn resolve_import(path_buf: &Path, options: &Options) -> Option<PathBuf> {
+    // todo: will panic if path ended in `..`
+    let name = path_buf.file_name().unwrap();
+    if path_buf.extension() == Some(OsStr::new(".css")) {
+        // || name.starts_with("http://") || name.starts_with("https://") {
+        todo!("css imports")
+    }
+
+    let paths = [
+        path_buf.with_file_name(name).with_extension("scss"),
+        path_buf
+            .with_file_name(format!("_{}", name.to_str().unwrap()))
+            .with_extension("scss"),
+        path_buf.to_path_buf(),
+        path_buf.join("index.scss"),
+        path_buf.join("_index.scss"),
+    ];
+
+    paths.iter().find(|name| is_file(name, options)).cloned()
+}
+
+/// Whether an import of `url` is a glob pattern, e.g. `components/**`
+fn is_glob(url: &str) -> bool {
+    url.contains(|c| matches!(c, '*' | '?' | '['))
+}
+
+/// Whether an import of `url` refers to plain CSS, rather than a Sass file
+fn is_plain_css_import(url: &str) -> bool {
+    url.ends_with(".css")
+        || url.starts_with("http://")
+        || url.starts_with("https://")
+        || url.starts_with("//")
 }
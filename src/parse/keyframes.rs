@@ -1,5 +1,7 @@
 use std::fmt;
 
+use codemap::Span;
+
 use peekmore::PeekMore;
 
 use crate::{
@@ -54,11 +56,8 @@ impl<'a, 'b> KeyframesSelectorParser<'a, 'b> {
                         return Err(("Expected \"to\" or \"from\".", tok.pos).into());
                     }
                 }
-                '0'..='9' => {
-                    let num = eat_whole_number(self.parser.toks);
-                    if !matches!(self.parser.toks.next(), Some(Token { kind: '%', .. })) {
-                        return Err(("expected \"%\".", tok.pos).into());
-                    }
+                '+' | '.' | '0'..='9' => {
+                    let num = self.parse_percentage(tok.pos)?;
                     selectors.push(KeyframesSelector::Percent(num.into_boxed_str()));
                 }
                 '{' => break,
@@ -75,6 +74,47 @@ impl<'a, 'b> KeyframesSelectorParser<'a, 'b> {
         }
         Ok(selectors)
     }
+
+    /// Parse a percentage such as `50%`, `+12.5%` or `1e1%`, returning the
+    /// number as written
+    fn parse_percentage(&mut self, span: Span) -> SassResult<String> {
+        let mut num = String::new();
+        if let Some(Token { kind: '+', .. }) = self.parser.toks.peek() {
+            self.parser.toks.next();
+            num.push('+');
+        }
+
+        match self.parser.toks.peek() {
+            Some(Token { kind, .. }) if kind.is_ascii_digit() || *kind == '.' => {}
+            _ => return Err(("Expected number.", span).into()),
+        }
+
+        num.push_str(&eat_whole_number(self.parser.toks));
+
+        if let Some(Token { kind: '.', .. }) = self.parser.toks.peek() {
+            self.parser.toks.next();
+            num.push('.');
+            num.push_str(&eat_whole_number(self.parser.toks));
+        }
+
+        if let Some(Token { kind: 'e', .. }) | Some(Token { kind: 'E', .. }) =
+            self.parser.toks.peek()
+        {
+            num.push(self.parser.toks.next().unwrap().kind);
+            if let Some(Token { kind: '+', .. }) | Some(Token { kind: '-', .. }) =
+                self.parser.toks.peek()
+            {
+                num.push(self.parser.toks.next().unwrap().kind);
+            }
+            num.push_str(&eat_whole_number(self.parser.toks));
+        }
+
+        if !matches!(self.parser.toks.next(), Some(Token { kind: '%', .. })) {
+            return Err(("expected \"%\".", span).into());
+        }
+
+        Ok(num)
+    }
 }
 
 impl<'a> Parser<'a> {
@@ -156,14 +196,21 @@ impl<'a> Parser<'a> {
                         map: self.map,
                         path: self.path,
                         scopes: self.scopes,
-                        global_scope: self.global_scope,
+                        modules: self.modules,
+                        module: self.module,
                         super_selectors: self.super_selectors,
                         span_before: self.span_before,
                         content: self.content,
+                        call_stack: self.call_stack,
+                        options: self.options,
+                        dependencies: self.dependencies,
+                        import_stack: self.import_stack,
+                        imported: self.imported,
                         flags: self.flags,
                         at_root: self.at_root,
                         at_root_has_selector: self.at_root_has_selector,
                         extender: self.extender,
+                        media_queries: self.media_queries,
                     })
                     .parse_keyframes_selector()?;
 
@@ -185,14 +232,21 @@ impl<'a> Parser<'a> {
             map: self.map,
             path: self.path,
             scopes: self.scopes,
-            global_scope: self.global_scope,
+            modules: self.modules,
+            module: self.module,
             super_selectors: self.super_selectors,
             span_before: self.span_before,
             content: self.content,
+            call_stack: self.call_stack,
+            options: self.options,
+            dependencies: self.dependencies,
+            import_stack: self.import_stack,
+            imported: self.imported,
             flags: self.flags | ContextFlags::IN_KEYFRAMES,
             at_root: false,
             at_root_has_selector: self.at_root_has_selector,
             extender: self.extender,
+            media_queries: self.media_queries,
         }
         .parse_stmt()?;
 
@@ -5,7 +5,9 @@ use peekmore::PeekMore;
 use crate::{
     args::{CallArgs, FuncArgs},
     atrule::{Content, Mixin},
+    common::Identifier,
     error::SassResult,
+    stack,
     utils::read_until_closing_curly_brace,
     Token,
 };
@@ -16,6 +18,17 @@ impl<'a> Parser<'a> {
     pub(super) fn parse_mixin(&mut self) -> SassResult<()> {
         self.whitespace();
         let Spanned { node: name, span } = self.parse_identifier()?;
+
+        // this includes mixins declared within style rules inside a mixin
+        // body, which are evaluated with the same flags
+        if self.flags.in_mixin() {
+            return Err(("Mixins may not contain mixin declarations.", span).into());
+        }
+
+        if self.flags.in_function() {
+            return Err(("This at-rule is not allowed here.", self.span_before).into());
+        }
+
         self.whitespace();
         let args = match self.toks.next() {
             Some(Token { kind: '(', .. }) => self.parse_func_args()?,
@@ -39,10 +52,17 @@ impl<'a> Parser<'a> {
         // this is blocked on figuring out just how to check for this. presumably we could have a check
         // not when parsing initially, but rather when `@include`ing to see if an `@content` was found.
 
-        let mixin = Mixin::new(self.scopes.last().clone(), args, body, false);
+        let mixin = Mixin::new(
+            self.scopes.last().clone(),
+            args,
+            body,
+            false,
+            self.module,
+            self.path.to_path_buf(),
+        );
 
         if self.at_root {
-            self.global_scope.insert_mixin(name, mixin);
+            self.global_scope_mut().insert_mixin(name, mixin);
         } else {
             self.scopes.last_mut().insert_mixin(name, mixin);
         }
@@ -51,7 +71,16 @@ impl<'a> Parser<'a> {
 
     pub(super) fn parse_include(&mut self) -> SassResult<Vec<Stmt>> {
         self.whitespace_or_comment();
-        let name = self.parse_identifier()?;
+        let mut name = self.parse_identifier()?;
+
+        let namespace = if let Some(Token { kind: '.', .. }) = self.toks.peek() {
+            self.toks.next();
+            let namespace = name.map_node(Identifier::from);
+            name = self.parse_identifier()?;
+            Some(namespace)
+        } else {
+            None
+        };
 
         self.whitespace_or_comment();
 
@@ -105,38 +134,78 @@ impl<'a> Parser<'a> {
             self.toks.next();
         }
 
+        let frame = Spanned {
+            node: format!("{}()", name.node),
+            span: name.span,
+        };
+
         let Mixin {
             mut scope,
             body,
             args: fn_args,
+            module,
+            path,
             ..
-        } = self.scopes.last().get_mixin(name, self.global_scope)?;
+        } = match namespace {
+            Some(namespace) => {
+                let module = self.modules.get_namespace(self.module, &namespace)?;
+                let name = name.map_node(Identifier::from);
+                if let Some(mixin) = self.modules.get_builtin_mixin(module, &name.node) {
+                    if content.is_some() {
+                        return Err(("Mixin doesn't accept a content block.", name.span).into());
+                    }
+                    self.push_call_stack(frame)?;
+                    let stmts = mixin(args, self);
+                    self.call_stack.pop();
+                    return stmts;
+                }
+                self.modules.get_mixin(module, name)?
+            }
+            None => self.get_mixin(name.map_node(Identifier::from))?,
+        };
         self.eval_args(fn_args, args, &mut scope)?;
 
+        self.push_call_stack(frame)?;
+
         self.content.push(Content {
             content,
             content_args,
             scope: self.scopes.last().clone(),
+            module: self.module,
+            path: self.path.to_path_buf(),
+            flags: self.flags,
         });
 
-        let body = Parser {
-            toks: &mut body.into_iter().peekmore(),
-            map: self.map,
-            path: self.path,
-            scopes: &mut NeverEmptyVec::new(scope),
-            global_scope: self.global_scope,
-            super_selectors: self.super_selectors,
-            span_before: self.span_before,
-            flags: self.flags | ContextFlags::IN_MIXIN,
-            content: self.content,
-            at_root: false,
-            at_root_has_selector: self.at_root_has_selector,
-            extender: self.extender,
-        }
-        .parse()?;
+        let body = stack::grow(|| {
+            Parser {
+                toks: &mut body.into_iter().peekmore(),
+                map: self.map,
+                path: &path,
+                scopes: &mut NeverEmptyVec::new(scope),
+                modules: self.modules,
+                module,
+                super_selectors: self.super_selectors,
+                span_before: self.span_before,
+                flags: self.flags | ContextFlags::IN_MIXIN,
+                content: self.content,
+                call_stack: self.call_stack,
+                options: self.options,
+                dependencies: self.dependencies,
+                import_stack: self.import_stack,
+                imported: self.imported,
+                at_root: false,
+                at_root_has_selector: self.at_root_has_selector,
+                extender: self.extender,
+                media_queries: self.media_queries,
+            }
+            .parse()
+        });
 
+        self.call_stack.pop();
         self.content.pop();
 
+        let body = body?;
+
         Ok(body)
     }
 
@@ -154,7 +223,9 @@ impl<'a> Parser<'a> {
                 if let Some(Some(content_args)) =
                     self.content.last().map(|v| v.content_args.clone())
                 {
-                    args.max_args(content_args.len())?;
+                    if !content_args.0.last().map_or(false, |arg| arg.is_variadic) {
+                        args.max_args(content_args.len())?;
+                    }
 
                     self.eval_args(content_args, args, &mut scope)?;
                 } else {
@@ -163,20 +234,34 @@ impl<'a> Parser<'a> {
             }
 
             Ok(if let Some(content) = &self.content.pop() {
+                // the content block is only within a mixin if the `@include`
+                // it was passed to is
+                let mut flags = self.flags;
+                if !content.flags.in_mixin() {
+                    flags.unset(ContextFlags::IN_MIXIN);
+                }
+
                 let stmts = if let Some(body) = content.content.clone() {
                     Parser {
                         toks: &mut body.into_iter().peekmore(),
                         map: self.map,
-                        path: self.path,
+                        path: &content.path,
                         scopes: &mut NeverEmptyVec::new(scope),
-                        global_scope: self.global_scope,
+                        modules: self.modules,
+                        module: content.module,
                         super_selectors: self.super_selectors,
                         span_before: self.span_before,
-                        flags: self.flags,
+                        flags,
                         content: self.content,
+                        call_stack: self.call_stack,
+                        options: self.options,
+                        dependencies: self.dependencies,
+                        import_stack: self.import_stack,
+                        imported: self.imported,
                         at_root: self.at_root,
                         at_root_has_selector: self.at_root_has_selector,
                         extender: self.extender,
+                        media_queries: self.media_queries,
                     }
                     .parse()?
                 } else {
@@ -1,4 +1,9 @@
-use std::{convert::TryFrom, path::Path, vec::IntoIter};
+use std::{
+    collections::HashSet,
+    convert::TryFrom,
+    path::{Path, PathBuf},
+    vec::IntoIter,
+};
 
 use codemap::{CodeMap, Span, Spanned};
 use num_traits::cast::ToPrimitive;
@@ -10,11 +15,12 @@ use crate::{
         media::MediaRule,
         AtRuleKind, Content, SupportsRule, UnknownAtRule,
     },
-    common::{Brackets, ListSeparator},
     error::SassResult,
+    module::{ModuleId, Modules},
     scope::Scope,
     selector::{
-        ComplexSelectorComponent, ExtendRule, ExtendedSelector, Extender, Selector, SelectorParser,
+        ComplexSelectorComponent, CssMediaQuery, ExtendRule, ExtendedSelector, Extender, Selector,
+        SelectorParser,
     },
     style::Style,
     unit::Unit,
@@ -23,7 +29,7 @@ use crate::{
         read_until_semicolon_or_closing_curly_brace,
     },
     value::{Number, Value},
-    {Cow, Token},
+    {Cow, Options, Token},
 };
 
 use common::{Branch, ContextFlags, NeverEmptyVec, SelectorOrStyle};
@@ -38,6 +44,7 @@ mod import;
 mod keyframes;
 mod media;
 mod mixin;
+mod module;
 mod style;
 mod value;
 mod variable;
@@ -74,11 +81,27 @@ pub(crate) struct Parser<'a> {
     pub toks: &'a mut PeekMoreIterator<IntoIter<Token>>,
     pub map: &'a mut CodeMap,
     pub path: &'a Path,
-    pub global_scope: &'a mut Scope,
+    /// Every module loaded so far during compilation
+    pub modules: &'a mut Modules,
+    /// The module currently being evaluated, whose global scope is used
+    pub module: ModuleId,
     pub scopes: &'a mut NeverEmptyVec<Scope>,
     pub super_selectors: &'a mut NeverEmptyVec<Selector>,
     pub span_before: Span,
     pub content: &'a mut Vec<Content>,
+    /// The mixins and functions currently being evaluated, along with
+    /// the span at which each was invoked
+    ///
+    /// Used to print a stack trace for `@warn`
+    pub call_stack: &'a mut Vec<Spanned<String>>,
+    pub options: &'a Options,
+    /// Every file loaded so far during compilation
+    pub dependencies: &'a mut Vec<PathBuf>,
+    /// The chain of files currently being imported, used to detect cycles
+    pub import_stack: &'a mut Vec<PathBuf>,
+    /// Every file loaded with `@import` so far, used to detect duplicate
+    /// imports
+    pub imported: &'a mut HashSet<PathBuf>,
     pub flags: ContextFlags,
     /// Whether this parser is at the root of the document
     /// E.g. not inside a style, mixin, or function
@@ -87,6 +110,9 @@ pub(crate) struct Parser<'a> {
     /// not the `@at-rule` block has a super selector
     pub at_root_has_selector: bool,
     pub extender: &'a mut Extender,
+    /// The queries of the `@media` rules this parser is nested within,
+    /// outermost first
+    pub media_queries: &'a mut Vec<CssMediaQuery>,
 }
 
 impl<'a> Parser<'a> {
@@ -111,7 +137,7 @@ impl<'a> Parser<'a> {
             self.span_before = *pos;
             match kind {
                 '@' => {
-                    self.toks.next();
+                    let at_span = self.toks.next().unwrap().pos();
                     let kind_string = self.parse_identifier()?;
                     self.span_before = kind_string.span;
                     match AtRuleKind::try_from(&kind_string)? {
@@ -145,7 +171,7 @@ impl<'a> Parser<'a> {
                             let Spanned {
                                 node: message,
                                 span,
-                            } = self.parse_value_from_vec(toks)?;
+                            } = self.parse_value_from_vec(toks, false)?;
 
                             return Err((
                                 message.inspect(span)?.to_string(),
@@ -158,15 +184,18 @@ impl<'a> Parser<'a> {
                             let Spanned {
                                 node: message,
                                 span,
-                            } = self.parse_value_from_vec(toks)?;
+                            } = self.parse_value_from_vec(toks, false)?;
                             span.merge(kind_string.span);
                             if let Some(Token { kind: ';', pos }) = self.toks.peek() {
                                 kind_string.span.merge(*pos);
                                 self.toks.next();
                             }
                             self.warn(&Spanned {
-                                node: message.to_css_string(span)?,
-                                span,
+                                node: match message {
+                                    Value::String(s, ..) => Cow::owned(s),
+                                    v => v.inspect(span)?,
+                                },
+                                span: at_span.merge(kind_string.span),
                             })
                         }
                         AtRuleKind::Debug => {
@@ -174,15 +203,18 @@ impl<'a> Parser<'a> {
                             let Spanned {
                                 node: message,
                                 span,
-                            } = self.parse_value_from_vec(toks)?;
+                            } = self.parse_value_from_vec(toks, false)?;
                             span.merge(kind_string.span);
                             if let Some(Token { kind: ';', pos }) = self.toks.peek() {
                                 kind_string.span.merge(*pos);
                                 self.toks.next();
                             }
                             self.debug(&Spanned {
-                                node: message.inspect(span)?,
-                                span,
+                                node: match message {
+                                    Value::String(s, ..) => Cow::owned(s),
+                                    v => v.inspect(span)?,
+                                },
+                                span: at_span.merge(kind_string.span),
                             })
                         }
                         AtRuleKind::If => stmts.append(&mut self.parse_if()?),
@@ -200,8 +232,8 @@ impl<'a> Parser<'a> {
                         AtRuleKind::Unknown(_) => {
                             stmts.push(self.parse_unknown_at_rule(kind_string.node)?)
                         }
-                        AtRuleKind::Use => todo!("@use not yet implemented"),
-                        AtRuleKind::Forward => todo!("@forward not yet implemented"),
+                        AtRuleKind::Use => stmts.append(&mut self.parse_use()?),
+                        AtRuleKind::Forward => stmts.append(&mut self.parse_forward()?),
                         AtRuleKind::Extend => self.parse_extend()?,
                         AtRuleKind::Supports => stmts.push(self.parse_supports()?),
                         AtRuleKind::Keyframes => stmts.push(self.parse_keyframes()?),
@@ -231,6 +263,11 @@ impl<'a> Parser<'a> {
                 // dart-sass seems to special-case the error message here?
                 '!' | '{' => return Err(("expected \"}\".", *pos).into()),
                 _ => {
+                    let start = *pos;
+                    if self.next_is_namespaced_variable() {
+                        self.parse_namespaced_variable_declaration()?;
+                        continue;
+                    }
                     if self.flags.in_keyframes() {
                         match self.is_selector_or_style()? {
                             SelectorOrStyle::Style(property, value) => {
@@ -274,16 +311,21 @@ impl<'a> Parser<'a> {
                         SelectorOrStyle::Selector(init) => {
                             let at_root = self.at_root;
                             self.at_root = false;
-                            let selector = self
+                            let mut selector = self
                                 .parse_selector(!self.super_selectors.is_empty(), false, init)?
                                 .resolve_parent_selectors(
                                     self.super_selectors.last(),
                                     !at_root || self.at_root_has_selector,
                                 )?;
+                            // the selector's own span begins after any text
+                            // already consumed while looking for a style
+                            selector.0.span = start.merge(selector.0.span);
                             self.scopes.push(self.scopes.last().clone());
                             self.super_selectors.push(selector.clone());
 
-                            let extended_selector = self.extender.add_selector(selector.0, None);
+                            let extended_selector = self
+                                .extender
+                                .add_selector(selector.0, self.media_context())?;
 
                             let body = self.parse_stmt()?;
                             self.scopes.pop();
@@ -367,14 +409,21 @@ impl<'a> Parser<'a> {
                 map: self.map,
                 path: self.path,
                 scopes: self.scopes,
-                global_scope: self.global_scope,
+                modules: self.modules,
+                module: self.module,
                 super_selectors: self.super_selectors,
                 span_before: self.span_before,
                 content: self.content,
+                call_stack: self.call_stack,
+                options: self.options,
+                dependencies: self.dependencies,
+                import_stack: self.import_stack,
+                imported: self.imported,
                 flags: self.flags,
                 at_root: self.at_root,
                 at_root_has_selector: self.at_root_has_selector,
                 extender: self.extender,
+                media_queries: self.media_queries,
             },
             allows_parent,
             true,
@@ -433,7 +482,7 @@ impl<'a> Parser<'a> {
 
     pub fn parse_interpolation(&mut self) -> SassResult<Spanned<Value>> {
         let toks = read_until_closing_curly_brace(self.toks)?;
-        let val = self.parse_value_from_vec(toks)?;
+        let val = self.parse_value_from_vec(toks, false)?;
         match self.toks.next() {
             Some(Token { kind: '}', .. }) => {}
             Some(..) | None => return Err(("expected \"}\".", val.span).into()),
@@ -453,7 +502,7 @@ impl<'a> Parser<'a> {
         &mut self,
         toks: Vec<Token>,
     ) -> SassResult<Cow<'static, str>> {
-        let value = self.parse_value_from_vec(toks)?;
+        let value = self.parse_value_from_vec(toks, true)?;
         value.node.to_css_string(value.span)
     }
 
@@ -564,18 +613,25 @@ impl<'a> Parser<'a> {
                     {
                         self.toks.next();
                         let cond = read_until_open_curly_brace(self.toks)?;
-                        self.toks.next();
+                        let span_before = match self.toks.next() {
+                            Some(t) => t.pos,
+                            None => return Err(("Expected expression.", tok.pos()).into()),
+                        };
                         self.whitespace();
-                        branches.push(Branch::new(
-                            cond,
-                            read_until_closing_curly_brace(self.toks)?,
-                        ));
-                        self.toks.next();
+                        let mut toks = read_until_closing_curly_brace(self.toks)?;
+                        match self.toks.next() {
+                            Some(tok) => toks.push(tok),
+                            None => return Err(("expected \"}\".", span_before).into()),
+                        }
+                        branches.push(Branch::new(cond, toks));
                         self.whitespace();
                     }
                     '{' => {
                         else_ = read_until_closing_curly_brace(self.toks)?;
-                        self.toks.next();
+                        match self.toks.next() {
+                            Some(tok) => else_.push(tok),
+                            None => return Err(("expected \"}\".", tok.pos()).into()),
+                        }
                         break;
                     }
                     _ => {
@@ -590,20 +646,31 @@ impl<'a> Parser<'a> {
 
         for branch in branches {
             self.span_before = branch.cond.first().unwrap().pos;
-            if self.parse_value_from_vec(branch.cond)?.node.is_true() {
+            if self
+                .parse_value_from_vec(branch.cond, false)?
+                .node
+                .is_true()
+            {
                 return Parser {
                     toks: &mut branch.toks.into_iter().peekmore(),
                     map: self.map,
                     path: self.path,
                     scopes: self.scopes,
-                    global_scope: self.global_scope,
+                    modules: self.modules,
+                    module: self.module,
                     super_selectors: self.super_selectors,
                     span_before: self.span_before,
                     content: self.content,
+                    call_stack: self.call_stack,
+                    options: self.options,
+                    dependencies: self.dependencies,
+                    import_stack: self.import_stack,
+                    imported: self.imported,
                     flags: self.flags | ContextFlags::IN_CONTROL_FLOW,
                     at_root: self.at_root,
                     at_root_has_selector: self.at_root_has_selector,
                     extender: self.extender,
+                    media_queries: self.media_queries,
                 }
                 .parse();
             }
@@ -616,14 +683,21 @@ impl<'a> Parser<'a> {
             map: self.map,
             path: self.path,
             scopes: self.scopes,
-            global_scope: self.global_scope,
+            modules: self.modules,
+            module: self.module,
             super_selectors: self.super_selectors,
             span_before: self.span_before,
             content: self.content,
+            call_stack: self.call_stack,
+            options: self.options,
+            dependencies: self.dependencies,
+            import_stack: self.import_stack,
+            imported: self.imported,
             flags: self.flags | ContextFlags::IN_CONTROL_FLOW,
             at_root: self.at_root,
             at_root_has_selector: self.at_root_has_selector,
             extender: self.extender,
+            media_queries: self.media_queries,
         }
         .parse()
     }
@@ -692,11 +766,14 @@ impl<'a> Parser<'a> {
             }
         }
         self.whitespace();
-        let from_val = self.parse_value_from_vec(from_toks)?;
+        let from_val = self.parse_value_from_vec(from_toks, false)?;
         let from = match from_val.node {
+            Value::Dimension(n, _) if n.is_decimal() => {
+                return Err((format!("{} is not an int.", n), from_val.span).into())
+            }
             Value::Dimension(n, _) => match n.to_integer().to_isize() {
                 Some(v) => v,
-                None => return Err((format!("{} is not a int.", n), from_val.span).into()),
+                None => return Err((format!("{} is not an int.", n), from_val.span).into()),
             },
             v => {
                 return Err((
@@ -709,11 +786,14 @@ impl<'a> Parser<'a> {
 
         let to_toks = read_until_open_curly_brace(self.toks)?;
         self.toks.next();
-        let to_val = self.parse_value_from_vec(to_toks)?;
+        let to_val = self.parse_value_from_vec(to_toks, false)?;
         let to = match to_val.node {
+            Value::Dimension(n, _) if n.is_decimal() => {
+                return Err((format!("{} is not an int.", n), to_val.span).into())
+            }
             Value::Dimension(n, _) => match n.to_integer().to_isize() {
                 Some(v) => v,
-                None => return Err((format!("{} is not a int.", n), to_val.span).into()),
+                None => return Err((format!("{} is not an int.", n), to_val.span).into()),
             },
             v => {
                 return Err((
@@ -757,14 +837,21 @@ impl<'a> Parser<'a> {
                     map: self.map,
                     path: self.path,
                     scopes: self.scopes,
-                    global_scope: self.global_scope,
+                    modules: self.modules,
+                    module: self.module,
                     super_selectors: self.super_selectors,
                     span_before: self.span_before,
                     content: self.content,
+                    call_stack: self.call_stack,
+                    options: self.options,
+                    dependencies: self.dependencies,
+                    import_stack: self.import_stack,
+                    imported: self.imported,
                     flags: self.flags | ContextFlags::IN_CONTROL_FLOW,
                     at_root: self.at_root,
                     at_root_has_selector: self.at_root_has_selector,
                     extender: self.extender,
+                    media_queries: self.media_queries,
                 }
                 .parse()?;
                 if !these_stmts.is_empty() {
@@ -777,14 +864,21 @@ impl<'a> Parser<'a> {
                         map: self.map,
                         path: self.path,
                         scopes: self.scopes,
-                        global_scope: self.global_scope,
+                        modules: self.modules,
+                        module: self.module,
                         super_selectors: self.super_selectors,
                         span_before: self.span_before,
                         content: self.content,
+                        call_stack: self.call_stack,
+                        options: self.options,
+                        dependencies: self.dependencies,
+                        import_stack: self.import_stack,
+                        imported: self.imported,
                         flags: self.flags | ContextFlags::IN_CONTROL_FLOW,
                         at_root: self.at_root,
                         at_root_has_selector: self.at_root_has_selector,
                         extender: self.extender,
+                        media_queries: self.media_queries,
                     }
                     .parse()?,
                 );
@@ -816,7 +910,7 @@ impl<'a> Parser<'a> {
         self.whitespace();
 
         let mut stmts = Vec::new();
-        let mut val = self.parse_value_from_vec(cond.clone())?;
+        let mut val = self.parse_value_from_vec(cond.clone(), false)?;
         self.scopes.push(self.scopes.last().clone());
         while val.node.is_true() {
             if self.flags.in_function() {
@@ -825,14 +919,21 @@ impl<'a> Parser<'a> {
                     map: self.map,
                     path: self.path,
                     scopes: self.scopes,
-                    global_scope: self.global_scope,
+                    modules: self.modules,
+                    module: self.module,
                     super_selectors: self.super_selectors,
                     span_before: self.span_before,
                     content: self.content,
+                    call_stack: self.call_stack,
+                    options: self.options,
+                    dependencies: self.dependencies,
+                    import_stack: self.import_stack,
+                    imported: self.imported,
                     flags: self.flags | ContextFlags::IN_CONTROL_FLOW,
                     at_root: self.at_root,
                     at_root_has_selector: self.at_root_has_selector,
                     extender: self.extender,
+                    media_queries: self.media_queries,
                 }
                 .parse()?;
                 if !these_stmts.is_empty() {
@@ -845,19 +946,26 @@ impl<'a> Parser<'a> {
                         map: self.map,
                         path: self.path,
                         scopes: self.scopes,
-                        global_scope: self.global_scope,
+                        modules: self.modules,
+                        module: self.module,
                         super_selectors: self.super_selectors,
                         span_before: self.span_before,
                         content: self.content,
+                        call_stack: self.call_stack,
+                        options: self.options,
+                        dependencies: self.dependencies,
+                        import_stack: self.import_stack,
+                        imported: self.imported,
                         flags: self.flags | ContextFlags::IN_CONTROL_FLOW,
                         at_root: self.at_root,
                         at_root_has_selector: self.at_root_has_selector,
                         extender: self.extender,
+                        media_queries: self.media_queries,
                     }
                     .parse()?,
                 );
             }
-            val = self.parse_value_from_vec(cond.clone())?;
+            val = self.parse_value_from_vec(cond.clone(), false)?;
         }
         self.scopes.pop();
 
@@ -898,7 +1006,10 @@ impl<'a> Parser<'a> {
         }
         self.whitespace();
         let iter_val_toks = read_until_open_curly_brace(self.toks)?;
-        let iter = self.parse_value_from_vec(iter_val_toks)?.node.as_list();
+        let iter = self
+            .parse_value_from_vec(iter_val_toks, false)?
+            .node
+            .as_list();
         self.toks.next();
         self.whitespace();
         let mut body = read_until_closing_curly_brace(self.toks)?;
@@ -910,30 +1021,25 @@ impl<'a> Parser<'a> {
 
         let mut stmts = Vec::new();
 
-        for row in iter {
-            let this_iterator = row.as_list();
+        // rather than cloning the scope, the loop variables shadow any of the
+        // same name until the loop ends, when those are restored
+        let shadowed: Vec<Option<Spanned<Value>>> = vars
+            .iter()
+            .map(|var| self.scopes.last_mut().remove_var(&var.node))
+            .collect();
 
+        for row in iter {
             if vars.len() == 1 {
-                if this_iterator.len() == 1 {
-                    self.scopes.last_mut().insert_var(
-                        &vars[0].node,
-                        Spanned {
-                            node: this_iterator[0].clone(),
-                            span: vars[0].span,
-                        },
-                    );
-                } else {
-                    self.scopes.last_mut().insert_var(
-                        &vars[0].node,
-                        Spanned {
-                            node: Value::List(this_iterator, ListSeparator::Space, Brackets::None),
-                            span: vars[0].span,
-                        },
-                    );
-                }
+                self.scopes.last_mut().insert_var(
+                    &vars[0].node,
+                    Spanned {
+                        node: row,
+                        span: vars[0].span,
+                    },
+                );
             } else {
-                for (var, val) in vars.clone().into_iter().zip(
-                    this_iterator
+                for (var, val) in vars.iter().zip(
+                    row.as_list()
                         .into_iter()
                         .chain(std::iter::once(Value::Null).cycle()),
                 ) {
@@ -953,17 +1059,25 @@ impl<'a> Parser<'a> {
                     map: self.map,
                     path: self.path,
                     scopes: self.scopes,
-                    global_scope: self.global_scope,
+                    modules: self.modules,
+                    module: self.module,
                     super_selectors: self.super_selectors,
                     span_before: self.span_before,
                     content: self.content,
+                    call_stack: self.call_stack,
+                    options: self.options,
+                    dependencies: self.dependencies,
+                    import_stack: self.import_stack,
+                    imported: self.imported,
                     flags: self.flags | ContextFlags::IN_CONTROL_FLOW,
                     at_root: self.at_root,
                     at_root_has_selector: self.at_root_has_selector,
                     extender: self.extender,
+                    media_queries: self.media_queries,
                 }
                 .parse()?;
                 if !these_stmts.is_empty() {
+                    self.restore_shadowed_vars(&vars, shadowed);
                     return Ok(these_stmts);
                 }
             } else {
@@ -973,23 +1087,51 @@ impl<'a> Parser<'a> {
                         map: self.map,
                         path: self.path,
                         scopes: self.scopes,
-                        global_scope: self.global_scope,
+                        modules: self.modules,
+                        module: self.module,
                         super_selectors: self.super_selectors,
                         span_before: self.span_before,
                         content: self.content,
+                        call_stack: self.call_stack,
+                        options: self.options,
+                        dependencies: self.dependencies,
+                        import_stack: self.import_stack,
+                        imported: self.imported,
                         flags: self.flags | ContextFlags::IN_CONTROL_FLOW,
                         at_root: self.at_root,
                         at_root_has_selector: self.at_root_has_selector,
                         extender: self.extender,
+                        media_queries: self.media_queries,
                     }
                     .parse()?,
                 );
             }
         }
 
+        self.restore_shadowed_vars(&vars, shadowed);
+
         Ok(stmts)
     }
 
+    /// Undo the shadowing of variables by the loop variables of `@each`
+    fn restore_shadowed_vars(
+        &mut self,
+        vars: &[Spanned<String>],
+        shadowed: Vec<Option<Spanned<Value>>>,
+    ) {
+        let scope = self.scopes.last_mut();
+        for (var, value) in vars.iter().zip(shadowed) {
+            match value {
+                Some(value) => {
+                    scope.insert_var(&var.node, value);
+                }
+                None => {
+                    scope.remove_var(&var.node);
+                }
+            }
+        }
+    }
+
     fn parse_unknown_at_rule(&mut self, name: String) -> SassResult<Stmt> {
         let mut params = String::new();
         self.whitespace();
@@ -1062,22 +1204,49 @@ impl<'a> Parser<'a> {
             return Err(("expected \"{\".", self.span_before).into());
         }
 
+        self.media_queries.push(CssMediaQuery::new(query.clone()));
+
         let raw_body = Parser {
             toks: self.toks,
             map: self.map,
             path: self.path,
             scopes: self.scopes,
-            global_scope: self.global_scope,
+            modules: self.modules,
+            module: self.module,
             super_selectors: self.super_selectors,
             span_before: self.span_before,
             content: self.content,
+            call_stack: self.call_stack,
+            options: self.options,
+            dependencies: self.dependencies,
+            import_stack: self.import_stack,
+            imported: self.imported,
             flags: self.flags,
             at_root: false,
             at_root_has_selector: self.at_root_has_selector,
             extender: self.extender,
+            media_queries: self.media_queries,
         }
-        .parse_stmt()?;
+        .parse_stmt();
+
+        self.media_queries.pop();
 
+        Ok(self.media_rule(query, raw_body?))
+    }
+
+    /// The media query context of a selector or `@extend` at this point, or
+    /// `None` outside of any `@media` rule
+    fn media_context(&self) -> Option<Vec<CssMediaQuery>> {
+        if self.media_queries.is_empty() {
+            None
+        } else {
+            Some(self.media_queries.clone())
+        }
+    }
+
+    /// Wrap `raw_body` in an `@media` rule, nesting its declarations within
+    /// the current selector
+    fn media_rule(&self, query: String, raw_body: Vec<Stmt>) -> Stmt {
         let mut rules = Vec::with_capacity(raw_body.len());
         let mut body = Vec::new();
 
@@ -1097,11 +1266,11 @@ impl<'a> Parser<'a> {
 
         body.append(&mut rules);
 
-        Ok(Stmt::Media(Box::new(MediaRule {
+        Stmt::Media(Box::new(MediaRule {
             super_selector: Selector::new(self.span_before),
             query,
             body,
-        })))
+        }))
     }
 
     fn parse_at_root(&mut self) -> SassResult<Vec<Stmt>> {
@@ -1133,14 +1302,21 @@ impl<'a> Parser<'a> {
             map: self.map,
             path: self.path,
             scopes: self.scopes,
-            global_scope: self.global_scope,
+            modules: self.modules,
+            module: self.module,
             super_selectors: &mut NeverEmptyVec::new(at_rule_selector.clone()),
             span_before: self.span_before,
             content: self.content,
+            call_stack: self.call_stack,
+            options: self.options,
+            dependencies: self.dependencies,
+            import_stack: self.import_stack,
+            imported: self.imported,
             flags: self.flags,
             at_root: true,
             at_root_has_selector,
             extender: self.extender,
+            media_queries: self.media_queries,
         }
         .parse()?
         .into_iter()
@@ -1165,35 +1341,46 @@ impl<'a> Parser<'a> {
         // if !self.in_style_rule && !self.in_mixin && !self.in_content_block {
         //     return Err(("@extend may only be used within style rules.", self.span_before).into());
         // }
+        let mut toks = read_until_semicolon_or_closing_curly_brace(self.toks)?;
+
+        let is_optional = match toks.iter().rposition(|t| t.kind == '!') {
+            Some(idx)
+                if toks[idx + 1..]
+                    .iter()
+                    .map(|t| t.kind)
+                    .collect::<String>()
+                    .trim()
+                    == "optional" =>
+            {
+                toks.truncate(idx);
+                true
+            }
+            Some(..) | None => false,
+        };
+
         let value = Parser {
-            toks: &mut read_until_semicolon_or_closing_curly_brace(self.toks)?
-                .into_iter()
-                .peekmore(),
+            toks: &mut toks.into_iter().peekmore(),
             map: self.map,
             path: self.path,
             scopes: self.scopes,
-            global_scope: self.global_scope,
+            modules: self.modules,
+            module: self.module,
             super_selectors: self.super_selectors,
             span_before: self.span_before,
             content: self.content,
+            call_stack: self.call_stack,
+            options: self.options,
+            dependencies: self.dependencies,
+            import_stack: self.import_stack,
+            imported: self.imported,
             flags: self.flags,
             at_root: self.at_root,
             at_root_has_selector: self.at_root_has_selector,
             extender: self.extender,
+            media_queries: self.media_queries,
         }
         .parse_selector(false, true, String::new())?;
 
-        let is_optional = if let Some(Token { kind: '!', .. }) = self.toks.peek() {
-            self.toks.next();
-            assert_eq!(
-                self.parse_identifier_no_interpolation(false)?.node,
-                "optional"
-            );
-            true
-        } else {
-            false
-        };
-
         self.whitespace();
 
         if let Some(Token { kind: ';', .. }) = self.toks.peek() {
@@ -1202,6 +1389,8 @@ impl<'a> Parser<'a> {
 
         let extend_rule = ExtendRule::new(value.clone(), is_optional, self.span_before);
 
+        let media_context = self.media_context();
+
         let super_selector = self.super_selectors.last();
 
         for complex in value.0.components {
@@ -1228,9 +1417,9 @@ impl<'a> Parser<'a> {
                 super_selector.clone().0,
                 compound.components.first().unwrap(),
                 &extend_rule,
-                &None,
+                &media_context,
                 Some(self.span_before),
-            )
+            )?;
         }
 
         Ok(())
@@ -1248,14 +1437,21 @@ impl<'a> Parser<'a> {
             map: self.map,
             path: self.path,
             scopes: self.scopes,
-            global_scope: self.global_scope,
+            modules: self.modules,
+            module: self.module,
             super_selectors: self.super_selectors,
             span_before: self.span_before,
             content: self.content,
+            call_stack: self.call_stack,
+            options: self.options,
+            dependencies: self.dependencies,
+            import_stack: self.import_stack,
+            imported: self.imported,
             flags: self.flags,
             at_root: false,
             at_root_has_selector: self.at_root_has_selector,
             extender: self.extender,
+            media_queries: self.media_queries,
         }
         .parse()?;
 
@@ -1319,21 +1515,63 @@ impl<'a> Parser<'a> {
     fn debug(&self, message: &Spanned<Cow<'a, str>>) {
         let loc = self.map.look_up_span(message.span);
         eprintln!(
-            "{}:{} Debug: {}",
+            "{}:{}:{} Debug: {}",
             loc.file.name(),
             loc.begin.line + 1,
+            loc.begin.column + 1,
             message.node
         );
     }
 
     fn warn(&self, message: &Spanned<Cow<'a, str>>) {
-        let loc = self.map.look_up_span(message.span);
-        eprintln!(
-            "Warning: {}\n    {} {}:{}  root stylesheet",
-            message.node,
+        eprintln!("WARNING: {}", message.node);
+
+        let mut frames = Vec::new();
+        let mut span = message.span;
+        for frame in self.call_stack.iter().rev() {
+            frames.push((self.frame_location(span), frame.node.as_str()));
+            span = frame.span;
+        }
+        frames.push((self.frame_location(span), "root stylesheet"));
+
+        // as in dart-sass, members line up after the longest location
+        let width = frames
+            .iter()
+            .map(|(location, ..)| location.chars().count())
+            .max()
+            .unwrap_or(0);
+        for (location, member) in frames {
+            eprintln!("    {:width$}  {}", location, member, width = width);
+        }
+    }
+
+    /// The global scope of the module currently being evaluated
+    pub fn global_scope(&self) -> &Scope {
+        &self.modules[self.module].scope
+    }
+
+    pub fn global_scope_mut(&mut self) -> &mut Scope {
+        &mut self.modules[self.module].scope
+    }
+
+    /// Enter a mixin or function call, failing if this would exceed
+    /// the configured recursion limit
+    pub fn push_call_stack(&mut self, frame: Spanned<String>) -> SassResult<()> {
+        if self.call_stack.len() >= self.options.recursion_limit {
+            return Err(("stack overflow", frame.span).into());
+        }
+        self.call_stack.push(frame);
+        Ok(())
+    }
+
+    /// Where `span` begins, as shown in a stack trace, e.g. `input.scss 1:2`
+    fn frame_location(&self, span: Span) -> String {
+        let loc = self.map.look_up_span(span);
+        format!(
+            "{} {}:{}",
             loc.file.name(),
             loc.begin.line + 1,
             loc.begin.column + 1
-        );
+        )
     }
 }
@@ -0,0 +1,503 @@
+use std::{
+    collections::{HashMap, HashSet},
+    path::{Path, PathBuf},
+};
+
+use codemap::Spanned;
+
+use peekmore::PeekMore;
+
+use crate::{
+    args::CallArg,
+    atrule::{Function, Mixin},
+    builtin::builtin_module,
+    common::Identifier,
+    error::SassResult,
+    lexer::Lexer,
+    module::{Configuration, Forward, ForwardedVariable, Members, Module, ModuleId, Visibility},
+    scope::Scope,
+    selector::Selector,
+    utils::{
+        canonicalize, read_file, read_until_closing_curly_brace, read_until_closing_paren,
+        read_until_closing_quote, read_until_closing_square_brace,
+    },
+    value::Value,
+    Token,
+};
+
+use super::{common::ContextFlags, NeverEmptyVec, Parser, Stmt};
+
+impl<'a> Parser<'a> {
+    pub(super) fn parse_use(&mut self) -> SassResult<Vec<Stmt>> {
+        self.whitespace_or_comment();
+        let url = self.parse_module_url()?;
+        self.whitespace_or_comment();
+
+        let namespace = if self.scan_identifier("as")? {
+            self.whitespace_or_comment();
+            if self.scan_char('*') {
+                None
+            } else {
+                Some(
+                    self.parse_identifier_no_interpolation(false)?
+                        .map_node(Identifier::from),
+                )
+            }
+        } else {
+            let path = url.node.strip_prefix("sass:").unwrap_or(&url.node);
+            let basename = Path::new(path)
+                .file_name()
+                .and_then(|name| name.to_str())
+                .map(|name| name.strip_prefix('_').unwrap_or(name))
+                .and_then(|name| name.split('.').next())
+                .unwrap_or_default();
+            Some(Spanned {
+                node: Identifier::from(basename),
+                span: url.span,
+            })
+        };
+
+        self.whitespace_or_comment();
+
+        let configuration = if self.scan_identifier("with")? {
+            self.whitespace_or_comment();
+            self.expect_char('(')?;
+            self.parse_configuration()?
+        } else {
+            Configuration::new()
+        };
+
+        self.expect_module_rule_end()?;
+
+        let (id, stmts) = self.load_module(url, configuration, true)?;
+        assert_configuration_used(&self.modules.take_configuration(id))?;
+
+        match namespace {
+            Some(namespace) => self.modules.insert_namespace(self.module, namespace, id)?,
+            None => self.modules.insert_global_module(self.module, id),
+        }
+
+        Ok(stmts)
+    }
+
+    pub(super) fn parse_forward(&mut self) -> SassResult<Vec<Stmt>> {
+        self.whitespace_or_comment();
+        let url = self.parse_module_url()?;
+        self.whitespace_or_comment();
+
+        let prefix = if self.scan_identifier("as")? {
+            self.whitespace_or_comment();
+            let prefix = self.parse_identifier_no_interpolation(false)?;
+            self.expect_char('*')?;
+            self.whitespace_or_comment();
+            Some(Identifier::from(prefix.node))
+        } else {
+            None
+        };
+
+        let visibility = if self.scan_identifier("show")? {
+            Visibility::Show(self.parse_forward_members()?)
+        } else if self.scan_identifier("hide")? {
+            Visibility::Hide(self.parse_forward_members()?)
+        } else {
+            Visibility::All
+        };
+
+        self.whitespace_or_comment();
+
+        let with = if self.scan_identifier("with")? {
+            self.whitespace_or_comment();
+            self.expect_char('(')?;
+            self.parse_forward_configuration()?
+        } else {
+            Vec::new()
+        };
+
+        self.expect_module_rule_end()?;
+
+        // values configured for this module are passed on to the forwarded
+        // module, unless overridden by the `with` clause. Through a prefixed
+        // forward, only those with the prefix are, under their unprefixed
+        // names
+        let mut passed_on: HashMap<Identifier, Identifier> = HashMap::new();
+        let mut configuration = Configuration::new();
+        for (name, value) in &self.modules[self.module].configuration {
+            let unprefixed = match &prefix {
+                Some(prefix) => name
+                    .as_str()
+                    .strip_prefix(prefix.as_str())
+                    .map(Identifier::from),
+                None => Some(name.clone()),
+            };
+            if let Some(unprefixed) = unprefixed {
+                passed_on.insert(unprefixed.clone(), name.clone());
+                configuration.insert(unprefixed, value.clone());
+            }
+        }
+        let mut configured = HashSet::new();
+        for ForwardedVariable {
+            name,
+            value,
+            guarded,
+        } in with
+        {
+            configured.insert(name.clone());
+            if guarded
+                && configuration
+                    .get(&name)
+                    .map_or(false, |v| !v.node.is_null())
+            {
+                continue;
+            }
+            passed_on.remove(&name);
+            configuration.insert(name, value);
+        }
+
+        let next_id = self.modules.next_id();
+        let (id, stmts) = self.load_module(url, configuration, !configured.is_empty())?;
+
+        let unused = self.modules.take_configuration(id);
+
+        // values this module was configured with are used up once the
+        // forwarded module has used them. A module that was already loaded
+        // used none of them
+        if id >= next_id {
+            for (unprefixed, name) in passed_on {
+                if !unused.contains_key(&unprefixed) {
+                    self.modules[self.module].configuration.remove(&name);
+                }
+            }
+        }
+
+        assert_configuration_used(
+            &unused
+                .into_iter()
+                .filter(|(name, ..)| configured.contains(name))
+                .collect(),
+        )?;
+
+        self.modules
+            .insert_forward(self.module, Forward::new(id, visibility, prefix));
+
+        Ok(stmts)
+    }
+
+    /// Find a variable visible from the current scope, including those made
+    /// available by `@use ... as *`
+    pub fn get_var(&self, name: Spanned<Identifier>) -> SassResult<Spanned<Value>> {
+        match self
+            .scopes
+            .last()
+            .get_var(name.clone(), self.global_scope())
+        {
+            Ok(v) => Ok(v),
+            Err(e) => self.modules.get_global_var(self.module, &name).ok_or(e),
+        }
+    }
+
+    /// Find a mixin visible from the current scope, including those made
+    /// available by `@use ... as *`
+    pub fn get_mixin(&self, name: Spanned<Identifier>) -> SassResult<Mixin> {
+        match self
+            .scopes
+            .last()
+            .get_mixin(name.clone(), self.global_scope())
+        {
+            Ok(v) => Ok(v),
+            Err(e) => self.modules.get_global_mixin(self.module, &name).ok_or(e),
+        }
+    }
+
+    /// Find a function visible from the current scope, including those made
+    /// available by `@use ... as *`
+    pub fn get_fn(&self, name: Spanned<Identifier>) -> SassResult<Function> {
+        match self.scopes.last().get_fn(name.clone(), self.global_scope()) {
+            Ok(v) => Ok(v),
+            Err(e) => self.modules.get_global_fn(self.module, &name).ok_or(e),
+        }
+    }
+
+    /// Parse the comma separated list of names following `show` or `hide`
+    fn parse_forward_members(&mut self) -> SassResult<Members> {
+        let mut members = Members::default();
+        loop {
+            self.whitespace_or_comment();
+            if self.scan_char('$') {
+                let name = self.parse_identifier_no_interpolation(false)?;
+                members.insert_variable(name.node.into());
+            } else {
+                let name = self.parse_identifier_no_interpolation(false)?;
+                members.insert_callable(name.node.into());
+            }
+            self.whitespace_or_comment();
+            if !self.scan_char(',') {
+                break;
+            }
+        }
+        Ok(members)
+    }
+
+    /// Parse the variables given in the `with` clause of an `@forward`, after
+    /// the opening parenthesis
+    fn parse_forward_configuration(&mut self) -> SassResult<Vec<ForwardedVariable>> {
+        let mut variables = Vec::new();
+        loop {
+            self.whitespace_or_comment();
+            if self.scan_char(')') {
+                break;
+            }
+
+            self.expect_char('$')?;
+            let name = self.parse_identifier_no_interpolation(false)?;
+            self.whitespace_or_comment();
+            self.expect_char(':')?;
+            self.whitespace_or_comment();
+
+            let mut toks = Vec::new();
+            while let Some(tok) = self.toks.peek().copied() {
+                match tok.kind {
+                    ',' | ')' => break,
+                    '(' => {
+                        toks.push(self.toks.next().unwrap());
+                        toks.extend(read_until_closing_paren(self.toks)?);
+                    }
+                    '[' => {
+                        toks.push(self.toks.next().unwrap());
+                        toks.extend(read_until_closing_square_brace(self.toks)?);
+                    }
+                    '{' => {
+                        toks.push(self.toks.next().unwrap());
+                        toks.extend(read_until_closing_curly_brace(self.toks)?);
+                        toks.extend(self.toks.next());
+                    }
+                    q @ '"' | q @ '\'' => {
+                        toks.push(self.toks.next().unwrap());
+                        toks.extend(read_until_closing_quote(self.toks, q)?);
+                    }
+                    _ => toks.push(self.toks.next().unwrap()),
+                }
+            }
+
+            let text: String = toks.iter().map(|tok| tok.kind).collect();
+            let text = text.trim_end();
+            let guarded = text.ends_with("!default");
+            if guarded {
+                toks.truncate(text.chars().count() - "!default".len());
+            }
+
+            if toks.iter().all(|tok| tok.kind.is_whitespace()) {
+                return Err(("Expected expression.", name.span).into());
+            }
+
+            variables.push(ForwardedVariable {
+                name: name.node.into(),
+                value: self.parse_value_from_vec(toks, false)?,
+                guarded,
+            });
+
+            if !self.scan_char(',') {
+                self.expect_char(')')?;
+                break;
+            }
+        }
+        Ok(variables)
+    }
+
+    /// Parse the variables given in a `with` clause, after the opening
+    /// parenthesis
+    fn parse_configuration(&mut self) -> SassResult<Configuration> {
+        let args = self.parse_call_args()?;
+        let span = args.span();
+        let mut configuration = Configuration::new();
+        for (arg, value) in args.0 {
+            match arg {
+                CallArg::Named(name) => {
+                    configuration.insert(name, value?);
+                }
+                CallArg::Positional(..) => return Err(("expected \"$\".", span).into()),
+            }
+        }
+        Ok(configuration)
+    }
+
+    fn parse_module_url(&mut self) -> SassResult<Spanned<String>> {
+        match self.toks.next() {
+            Some(Token { kind: q @ '"', pos })
+            | Some(Token {
+                kind: q @ '\'',
+                pos,
+            }) => {
+                let Spanned { node: url, span } = self.parse_quoted_string(q)?;
+                let span = pos.merge(span);
+                Ok(Spanned {
+                    node: url.unquote().to_css_string(span)?.into_owned(),
+                    span,
+                })
+            }
+            Some(Token { pos, .. }) => Err(("Expected string.", pos).into()),
+            None => Err(("expected more input.", self.span_before).into()),
+        }
+    }
+
+    fn expect_module_rule_end(&mut self) -> SassResult<()> {
+        self.whitespace_or_comment();
+        match self.toks.peek() {
+            Some(Token { kind: ';', .. }) => {
+                self.toks.next();
+                Ok(())
+            }
+            Some(Token { kind: '}', .. }) | None => Ok(()),
+            Some(Token { pos, .. }) => Err(("expected \";\".", *pos).into()),
+        }
+    }
+
+    /// Load and evaluate the module at `url`, unless it has already been
+    /// loaded, returning the CSS it emits
+    ///
+    /// A module's CSS is only ever emitted the first time it is loaded, and
+    /// a module may only be configured the first time it is loaded. If the
+    /// configuration is not `explicit`, as when it is only passed on by
+    /// `@forward`, it is ignored by modules that can't be configured
+    fn load_module(
+        &mut self,
+        url: Spanned<String>,
+        configuration: Configuration,
+        explicit: bool,
+    ) -> SassResult<(ModuleId, Vec<Stmt>)> {
+        if let Some(name) = url.node.strip_prefix("sass:") {
+            if explicit && !configuration.is_empty() {
+                return Err(("Built-in modules can't be configured.", url.span).into());
+            }
+            return match builtin_module(name) {
+                Some(module) => Ok((self.modules.insert(module), Vec::new())),
+                None => Err(("Can't find stylesheet to import.", url.span).into()),
+            };
+        }
+
+        let path = self.find_module(&url)?;
+
+        let canonical = canonicalize(&path, self.options)?;
+        if let Some(id) = self.modules.find(&canonical) {
+            if explicit && !configuration.is_empty() {
+                return Err((
+                    "This module was already loaded, so it can't be configured using \"with\".",
+                    url.span,
+                )
+                    .into());
+            }
+            return Ok((id, Vec::new()));
+        }
+
+        let id = self
+            .modules
+            .insert(Module::new(Some(canonical), configuration));
+        let stmts = self.evaluate_module(id, &path, false)?;
+
+        Ok((id, stmts))
+    }
+
+    /// Evaluate the file at `url` for `meta.load-css`, returning its CSS
+    /// nested within the current style rule
+    ///
+    /// Unlike `@use`, the file's CSS is emitted each time it is loaded
+    pub(crate) fn load_css(
+        &mut self,
+        url: Spanned<String>,
+        configuration: Configuration,
+    ) -> SassResult<Vec<Stmt>> {
+        let path = self.find_module(&url)?;
+        let id = self.modules.insert(Module::new(None, configuration));
+        let stmts = self.evaluate_module(id, &path, true)?;
+        assert_configuration_used(&self.modules.take_configuration(id))?;
+        Ok(stmts)
+    }
+
+    fn find_module(&self, url: &Spanned<String>) -> SassResult<PathBuf> {
+        let path = match self.find_import(&url.node) {
+            Some(path) => path,
+            None => return Err(("Can't find stylesheet to import.", url.span).into()),
+        };
+
+        if self.is_being_imported(&path) {
+            return Err((
+                "Module loop: this module is already being loaded.",
+                url.span,
+            )
+                .into());
+        }
+
+        Ok(path)
+    }
+
+    /// Evaluate the file at `path` as the body of the module `id`
+    ///
+    /// If `nested` is true, the CSS emitted is nested within the current
+    /// style rule rather than placed at the root of the document
+    fn evaluate_module(
+        &mut self,
+        id: ModuleId,
+        path: &Path,
+        nested: bool,
+    ) -> SassResult<Vec<Stmt>> {
+        if !self.dependencies.iter().any(|dep| dep == path) {
+            self.dependencies.push(path.to_path_buf());
+        }
+
+        let file = self.map.add_file(
+            path.to_string_lossy().into(),
+            String::from_utf8(read_file(path, self.options)?)?,
+        );
+        let empty_span = file.span.subspan(0, 0);
+
+        let super_selector = if nested {
+            self.super_selectors.last().clone()
+        } else {
+            Selector::new(empty_span)
+        };
+
+        self.import_stack.push(path.to_path_buf());
+
+        let stmts = Parser {
+            toks: &mut Lexer::new(&file)
+                .collect::<Vec<Token>>()
+                .into_iter()
+                .peekmore(),
+            map: self.map,
+            path,
+            scopes: &mut NeverEmptyVec::new(Scope::new()),
+            modules: self.modules,
+            module: id,
+            super_selectors: &mut NeverEmptyVec::new(super_selector),
+            span_before: empty_span,
+            content: &mut Vec::new(),
+            call_stack: self.call_stack,
+            options: self.options,
+            dependencies: self.dependencies,
+            import_stack: self.import_stack,
+            imported: self.imported,
+            flags: ContextFlags::empty(),
+            at_root: true,
+            at_root_has_selector: nested && !self.super_selectors.is_empty(),
+            extender: self.extender,
+            media_queries: self.media_queries,
+        }
+        .parse();
+
+        self.import_stack.pop();
+
+        stmts
+    }
+}
+
+/// Any variables left over once a module has been evaluated were not
+/// declared with `!default`
+fn assert_configuration_used(unused: &Configuration) -> SassResult<()> {
+    match unused.values().next() {
+        Some(value) => Err((
+            "This variable was not declared with !default in the @used module.",
+            value.span,
+        )
+            .into()),
+        None => Ok(()),
+    }
+}
@@ -108,7 +108,7 @@ impl<'a> Parser<'a> {
         }
 
         let mut property = self.parse_identifier()?.node;
-        let whitespace_after_property = self.whitespace();
+        let whitespace_after_property = self.whitespace_or_comment();
 
         if let Some(Token { kind: ':', .. }) = self.toks.peek() {
             self.toks.next();
@@ -124,7 +124,7 @@ impl<'a> Parser<'a> {
                     c if is_name(*c) => {
                         if let Some(toks) = self.parse_style_value_when_no_space_after_semicolon() {
                             let len = toks.len();
-                            if let Ok(val) = self.parse_value_from_vec(toks) {
+                            if let Ok(val) = self.parse_value_from_vec(toks, true) {
                                 self.toks.take(len).for_each(drop);
                                 return Ok(SelectorOrStyle::Style(property, Some(Box::new(val))));
                             }
@@ -169,7 +169,7 @@ impl<'a> Parser<'a> {
     }
 
     fn parse_style_value(&mut self) -> SassResult<Spanned<Value>> {
-        self.parse_value()
+        self.parse_value(true)
     }
 
     pub(super) fn parse_style_group(&mut self, super_property: String) -> SassResult<Vec<Style>> {
@@ -35,6 +35,22 @@ impl<'a> Parser<'a> {
                         buf.push('#');
                     }
                 }
+                q @ '"' | q @ '\'' => {
+                    // operators and parentheses within strings are not special
+                    buf.push(q);
+                    while let Some(tok) = self.toks.next() {
+                        buf.push(tok.kind);
+                        match tok.kind {
+                            '\\' => {
+                                if let Some(tok) = self.toks.next() {
+                                    buf.push(tok.kind);
+                                }
+                            }
+                            c if c == q => break,
+                            _ => {}
+                        }
+                    }
+                }
                 '(' => {
                     nesting += 1;
                     buf.push('(');
@@ -73,7 +89,7 @@ impl<'a> Parser<'a> {
         Ok(string)
     }
 
-    pub(super) fn try_eat_url(&mut self) -> SassResult<Option<String>> {
+    pub(crate) fn try_eat_url(&mut self) -> SassResult<Option<String>> {
         let mut buf = String::from("url(");
         peek_whitespace(self.toks);
         while let Some(tok) = self.toks.peek() {
@@ -102,7 +118,6 @@ impl<'a> Parser<'a> {
             } else if kind == ')' {
                 buf.push(')');
                 self.toks.truncate_iterator_to_cursor();
-                self.toks.next();
                 return Ok(Some(buf));
             } else if kind.is_whitespace() {
                 peek_whitespace(self.toks);
@@ -197,16 +212,20 @@ impl<'a> Parser<'a> {
                     match self.toks.peek() {
                         Some(Token { kind: 'i', .. }) | Some(Token { kind: 'I', .. }) => {
                             self.toks.advance_cursor();
-                            if !matches!(self.toks.peek(), Some(Token { kind: 'n', .. }) | Some(Token { kind: 'N', .. }))
-                            {
+                            if !matches!(
+                                self.toks.peek(),
+                                Some(Token { kind: 'n', .. }) | Some(Token { kind: 'N', .. })
+                            ) {
                                 return Ok(None);
                             }
                             buf.push_str("min(")
                         }
                         Some(Token { kind: 'a', .. }) | Some(Token { kind: 'A', .. }) => {
                             self.toks.advance_cursor();
-                            if !matches!(self.toks.peek(), Some(Token { kind: 'x', .. }) | Some(Token { kind: 'X', .. }))
-                            {
+                            if !matches!(
+                                self.toks.peek(),
+                                Some(Token { kind: 'x', .. }) | Some(Token { kind: 'X', .. })
+                            ) {
                                 return Ok(None);
                             }
                             buf.push_str("max(")
@@ -265,7 +284,8 @@ impl<'a> Parser<'a> {
         Ok(Some(buf))
     }
 
-    #[allow(dead_code, unused_mut, unused_variables, unused_assignments)]
+    /// Peek a `calc()`, `env()` or `var()` call within `min()` or `max()`,
+    /// expanding interpolation but otherwise passing its arguments through
     fn try_parse_min_max_function(&mut self, fn_name: &'static str) -> SassResult<Option<String>> {
         let mut ident = peek_ident_no_interpolation(self.toks, false, self.span_before)?.node;
         ident.make_ascii_lowercase();
@@ -277,7 +297,60 @@ impl<'a> Parser<'a> {
         }
         self.toks.advance_cursor();
         ident.push('(');
-        todo!("special functions inside `min()` or `max()`")
+
+        let mut nesting = 0;
+        while let Some(tok) = self.toks.peek() {
+            let kind = tok.kind;
+            self.toks.advance_cursor();
+            match kind {
+                ' ' | '\t' | '\n' => {
+                    peek_whitespace(self.toks);
+                    ident.push(' ');
+                }
+                '#' => {
+                    if let Some(Token { kind: '{', .. }) = self.toks.peek() {
+                        self.toks.advance_cursor();
+                        let interpolation = self.peek_interpolation()?;
+                        match interpolation.node {
+                            Value::String(ref s, ..) => ident.push_str(s),
+                            v => ident.push_str(v.to_css_string(interpolation.span)?.borrow()),
+                        };
+                    } else {
+                        ident.push('#');
+                    }
+                }
+                q @ '"' | q @ '\'' => {
+                    ident.push(q);
+                    while let Some(tok) = self.toks.peek() {
+                        let kind = tok.kind;
+                        self.toks.advance_cursor();
+                        ident.push(kind);
+                        if kind == '\\' {
+                            if let Some(tok) = self.toks.peek() {
+                                ident.push(tok.kind);
+                                self.toks.advance_cursor();
+                            }
+                        } else if kind == q {
+                            break;
+                        }
+                    }
+                }
+                '(' => {
+                    nesting += 1;
+                    ident.push('(');
+                }
+                ')' => {
+                    ident.push(')');
+                    if nesting == 0 {
+                        return Ok(Some(ident));
+                    }
+                    nesting -= 1;
+                }
+                c => ident.push(c),
+            }
+        }
+
+        Ok(None)
     }
 }
 
@@ -339,7 +412,7 @@ impl<'a> Parser<'a> {
     fn peek_interpolation(&mut self) -> SassResult<Spanned<Value>> {
         let vec = peek_until_closing_curly_brace(self.toks)?;
         self.toks.advance_cursor();
-        let val = self.parse_value_from_vec(vec)?;
+        let val = self.parse_value_from_vec(vec, false)?;
         Ok(Spanned {
             node: val.node.unquote(),
             span: val.span,
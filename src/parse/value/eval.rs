@@ -6,10 +6,10 @@ use codemap::{Span, Spanned};
 
 use crate::{
     args::CallArgs,
-    common::{Op, QuoteKind},
+    common::{Brackets, Op, QuoteKind},
     error::SassResult,
     unit::{Unit, UNIT_CONVERSION_TABLE},
-    value::{SassFunction, Value},
+    value::{Number, SassFunction, Value},
 };
 
 use super::super::Parser;
@@ -17,6 +17,12 @@ use super::super::Parser;
 #[derive(Clone, Debug)]
 pub(crate) enum HigherIntermediateValue {
     Literal(Value),
+    /// A number as it was written, e.g. `16px`, rather than one from a
+    /// variable or function call
+    Number(Number, Unit),
+    /// Numbers separated by `/`, e.g. `16px/1.5` or `1/2/3`, which are only
+    /// divided when they're the operand of another operation
+    Slash(Box<Self>, Box<Self>),
     /// A function that hasn't yet been evaluated
     Function(SassFunction, CallArgs),
     BinaryOp(Box<Self>, Op, Box<Self>),
@@ -28,6 +34,18 @@ impl HigherIntermediateValue {
     pub const fn span(self, span: Span) -> Spanned<Self> {
         Spanned { node: self, span }
     }
+
+    /// Whether this may be separated from another value by `/` without
+    /// being divided
+    pub fn is_slash_operand(&self) -> bool {
+        match self {
+            Self::Number(..) | Self::Slash(..) => true,
+            Self::UnaryOp(Op::Minus, val) | Self::UnaryOp(Op::Plus, val) => {
+                matches!(**val, Self::Number(..))
+            }
+            _ => false,
+        }
+    }
 }
 
 impl<'a> Parser<'a> {
@@ -49,6 +67,8 @@ impl<'a, 'b: 'a> ValueVisitor<'a, 'b> {
     pub fn eval(&mut self, value: HigherIntermediateValue) -> SassResult<Value> {
         match value {
             HigherIntermediateValue::Literal(v) => Ok(v),
+            HigherIntermediateValue::Number(n, unit) => Ok(Value::Dimension(n, unit)),
+            HigherIntermediateValue::Slash(v1, v2) => self.bin_op(*v1, Op::Div, *v2),
             HigherIntermediateValue::BinaryOp(v1, op, v2) => self.bin_op(*v1, op, *v2),
             HigherIntermediateValue::UnaryOp(op, val) => self.unary_op(op, *val),
             HigherIntermediateValue::Paren(val) => self.eval(*val),
@@ -58,6 +78,26 @@ impl<'a, 'b: 'a> ValueVisitor<'a, 'b> {
         }
     }
 
+    /// Evaluate a value which isn't the operand of any operation, emitting
+    /// numbers separated by `/` as-is if `allow_slash` is true
+    pub fn eval_top_level(
+        &mut self,
+        value: HigherIntermediateValue,
+        allow_slash: bool,
+    ) -> SassResult<Value> {
+        match value {
+            HigherIntermediateValue::Slash(v1, v2) if allow_slash => Ok(Value::String(
+                format!(
+                    "{}/{}",
+                    self.eval_top_level(*v1, true)?.to_css_string(self.span)?,
+                    self.eval(*v2)?.to_css_string(self.span)?
+                ),
+                QuoteKind::None,
+            )),
+            v => self.eval(v),
+        }
+    }
+
     fn bin_op(
         &mut self,
         val1: HigherIntermediateValue,
@@ -142,6 +182,12 @@ impl<'a, 'b: 'a> ValueVisitor<'a, 'b> {
             HigherIntermediateValue::Function(function, args) => {
                 HigherIntermediateValue::Literal(self.parser.call_function(function, args)?)
             }
+            HigherIntermediateValue::Number(n, unit) => {
+                HigherIntermediateValue::Literal(Value::Dimension(n, unit))
+            }
+            HigherIntermediateValue::Slash(v1, v2) => {
+                HigherIntermediateValue::Literal(self.bin_op(*v1, Op::Div, *v2)?)
+            }
             val => val,
         })
     }
@@ -218,7 +264,7 @@ impl<'a, 'b: 'a> ValueVisitor<'a, 'b> {
                 }
                 Value::String(s, q) => Value::String(format!("{}{}{}", num, unit, s), q),
                 Value::Null => Value::String(format!("{}{}", num, unit), QuoteKind::None),
-                Value::True | Value::False | Value::List(..) => Value::String(
+                Value::True | Value::False | Value::List(..) | Value::Calc(..) => Value::String(
                     format!("{}{}{}", num, unit, right.to_css_string(self.span)?),
                     QuoteKind::None,
                 ),
@@ -265,6 +311,10 @@ impl<'a, 'b: 'a> ValueVisitor<'a, 'b> {
                 Value::String(text2, ..) => Value::String(text + &text2, quotes),
                 _ => Value::String(text + &right.to_css_string(self.span)?, quotes),
             },
+            Value::Calc(text) => match right {
+                Value::String(text2, ..) => Value::String(text + &text2, QuoteKind::None),
+                _ => Value::String(text + &right.to_css_string(self.span)?, QuoteKind::None),
+            },
             Value::List(..) | Value::ArgList(..) => match right {
                 Value::String(s, q) => {
                     Value::String(format!("{}{}", left.to_css_string(self.span)?, s), q)
@@ -326,6 +376,7 @@ impl<'a, 'b: 'a> ValueVisitor<'a, 'b> {
                 }
                 Value::List(..)
                 | Value::String(..)
+                | Value::Calc(..)
                 | Value::Important
                 | Value::True
                 | Value::False
@@ -512,6 +563,7 @@ impl<'a, 'b: 'a> ValueVisitor<'a, 'b> {
                 | Value::False
                 | Value::Important
                 | Value::Color(..)
+                | Value::Calc(..)
                 | Value::ArgList(..) => Value::String(
                     format!("{}{}/{}", num, unit, right.to_css_string(self.span)?),
                     QuoteKind::None,
@@ -557,6 +609,7 @@ impl<'a, 'b: 'a> ValueVisitor<'a, 'b> {
                 | Value::Dimension(..)
                 | Value::Color(..)
                 | Value::List(..)
+                | Value::Calc(..)
                 | Value::ArgList(..) => Value::String(
                     format!("{}{}{}/{}", q1, s1, q1, right.to_css_string(self.span)?),
                     QuoteKind::None,
@@ -707,6 +760,12 @@ impl<'a, 'b: 'a> ValueVisitor<'a, 'b> {
                 }
                 _ => false,
             },
+            // an empty list is equal to an empty map
+            Value::List(list1, _, Brackets::None) if list1.is_empty() => match right {
+                Value::List(list2, _, Brackets::None) => list2.is_empty(),
+                Value::Map(map) => map.is_empty(),
+                _ => false,
+            },
             Value::List(list1, sep1, brackets1) => match right {
                 Value::List(list2, sep2, brackets2) => {
                     if sep1 != sep2 || brackets1 != brackets2 || list1.len() != list2.len() {
@@ -730,72 +789,56 @@ impl<'a, 'b: 'a> ValueVisitor<'a, 'b> {
                 }
                 _ => false,
             },
-            s => s == right,
-        }))
-    }
-
-    fn not_equal(
-        &self,
-        left: HigherIntermediateValue,
-        right: HigherIntermediateValue,
-    ) -> SassResult<Value> {
-        let left = match left {
-            HigherIntermediateValue::Literal(v) => v,
-            v => panic!("{:?}", v),
-        };
-        let right = match right {
-            HigherIntermediateValue::Literal(v) => v,
-            v => panic!("{:?}", v),
-        };
-        Ok(Value::bool(match left {
-            Value::String(s1, ..) => match right {
-                Value::String(s2, ..) => s1 != s2,
-                _ => true,
-            },
-            Value::Dimension(n, unit) => match right {
-                Value::Dimension(n2, unit2) => {
-                    if !unit.comparable(&unit2) {
-                        true
-                    } else if unit == unit2 {
-                        n != n2
-                    } else if unit == Unit::None || unit2 == Unit::None {
-                        true
-                    } else {
-                        n != (n2
-                            * UNIT_CONVERSION_TABLE[unit.to_string().as_str()]
-                                [unit2.to_string().as_str()]
-                            .clone())
-                    }
-                }
-                _ => true,
-            },
-            Value::List(list1, sep1, brackets1) => match right {
-                Value::List(list2, sep2, brackets2) => {
-                    if sep1 != sep2 || brackets1 != brackets2 || list1.len() != list2.len() {
-                        true
+            // maps are equal if they have equal keys with equal values,
+            // regardless of order
+            Value::Map(map1) => match right {
+                Value::Map(map2) => {
+                    if map1.len() != map2.len() {
+                        false
                     } else {
-                        let mut equals = false;
-                        for (a, b) in list1.into_iter().zip(list2) {
-                            if self
-                                .not_equal(
-                                    HigherIntermediateValue::Literal(a),
-                                    HigherIntermediateValue::Literal(b),
-                                )?
-                                .is_true()
-                            {
-                                equals = true;
+                        let mut equals = true;
+                        for (key1, value1) in map1.iter() {
+                            let mut found = false;
+                            for (key2, value2) in map2.iter() {
+                                if self
+                                    .equal(
+                                        HigherIntermediateValue::Literal(key1.clone()),
+                                        HigherIntermediateValue::Literal(key2.clone()),
+                                    )?
+                                    .is_true()
+                                {
+                                    found = self
+                                        .equal(
+                                            HigherIntermediateValue::Literal(value1.clone()),
+                                            HigherIntermediateValue::Literal(value2.clone()),
+                                        )?
+                                        .is_true();
+                                    break;
+                                }
+                            }
+                            if !found {
+                                equals = false;
                                 break;
                             }
                         }
                         equals
                     }
                 }
-                _ => true,
+                Value::List(list, _, Brackets::None) => map1.is_empty() && list.is_empty(),
+                _ => false,
             },
-            s => s != right,
+            s => s == right,
         }))
     }
 
+    fn not_equal(
+        &self,
+        left: HigherIntermediateValue,
+        right: HigherIntermediateValue,
+    ) -> SassResult<Value> {
+        Ok(Value::bool(!self.equal(left, right)?.is_true()))
+    }
+
     fn cmp(
         &self,
         left: HigherIntermediateValue,
@@ -820,15 +863,29 @@ impl<'a, 'b: 'a> ValueVisitor<'a, 'b> {
                         )
                             .into());
                     }
-                    if &unit == unit2 || unit == Unit::None || unit2 == &Unit::None {
-                        num.cmp(num2)
+                    let num2 = if &unit == unit2 || unit == Unit::None || unit2 == &Unit::None {
+                        num2.clone()
                     } else {
-                        num.cmp(
-                            &(num2.clone()
-                                * UNIT_CONVERSION_TABLE[unit.to_string().as_str()]
-                                    [unit2.to_string().as_str()]
-                                .clone()),
-                        )
+                        num2.clone()
+                            * UNIT_CONVERSION_TABLE[unit.to_string().as_str()]
+                                [unit2.to_string().as_str()]
+                            .clone()
+                    };
+                    match num.partial_cmp(&num2) {
+                        Some(ordering) => ordering,
+                        None => {
+                            return Err((
+                                format!(
+                                    "Undefined operation \"{}{} {} {}\".",
+                                    num,
+                                    unit,
+                                    op,
+                                    right.inspect(self.span)?
+                                ),
+                                self.span,
+                            )
+                                .into())
+                        }
                     }
                 }
                 v => {
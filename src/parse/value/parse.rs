@@ -11,7 +11,7 @@ use peekmore::PeekMore;
 use crate::{
     builtin::GLOBAL_FUNCTIONS,
     color::{Color, NAMED_COLORS},
-    common::{Brackets, Identifier, ListSeparator, Op, QuoteKind},
+    common::{unvendor, Brackets, Identifier, ListSeparator, Op, QuoteKind},
     error::SassResult,
     unit::Unit,
     utils::{
@@ -52,7 +52,12 @@ impl IsWhitespace for IntermediateValue {
 }
 
 impl<'a> Parser<'a> {
-    pub(crate) fn parse_value(&mut self) -> SassResult<Spanned<Value>> {
+    /// Parse a value, evaluating it
+    ///
+    /// If `allow_slash` is true, numbers separated by `/` which aren't the
+    /// operand of any other operation are emitted as-is rather than divided,
+    /// e.g. the `16px/1.5` of `font: 16px/1.5 serif`
+    pub(crate) fn parse_value(&mut self, allow_slash: bool) -> SassResult<Spanned<Value>> {
         self.whitespace();
         let span = match self.toks.peek() {
             Some(Token { pos, .. }) => *pos,
@@ -101,7 +106,10 @@ impl<'a> Parser<'a> {
                                         span = span.merge(a.span);
                                         a.node
                                     })
-                                    .map(|a| ValueVisitor::new(iter.parser, span).eval(a))
+                                    .map(|a| {
+                                        ValueVisitor::new(iter.parser, span)
+                                            .eval_top_level(a, allow_slash)
+                                    })
                                     .collect::<SassResult<Vec<Value>>>()?,
                                 ListSeparator::Space,
                                 Brackets::None,
@@ -114,7 +122,7 @@ impl<'a> Parser<'a> {
                     last_was_whitespace = false;
                     space_separated.push(
                         HigherIntermediateValue::Literal(
-                            match iter.parser.parse_value_from_vec(t)?.node {
+                            match iter.parser.parse_value_from_vec(t, allow_slash)?.node {
                                 Value::List(v, sep, Brackets::None) => {
                                     Value::List(v, sep, Brackets::Bracketed)
                                 }
@@ -144,7 +152,9 @@ impl<'a> Parser<'a> {
                     HigherIntermediateValue::Literal(Value::List(
                         space_separated
                             .into_iter()
-                            .map(|a| ValueVisitor::new(self, span).eval(a.node))
+                            .map(|a| {
+                                ValueVisitor::new(self, span).eval_top_level(a.node, allow_slash)
+                            })
                             .collect::<SassResult<Vec<Value>>>()?,
                         ListSeparator::Space,
                         Brackets::None,
@@ -155,7 +165,7 @@ impl<'a> Parser<'a> {
             Value::List(
                 comma_separated
                     .into_iter()
-                    .map(|a| ValueVisitor::new(self, span).eval(a.node))
+                    .map(|a| ValueVisitor::new(self, span).eval_top_level(a.node, allow_slash))
                     .collect::<SassResult<Vec<Value>>>()?,
                 ListSeparator::Comma,
                 Brackets::None,
@@ -163,13 +173,13 @@ impl<'a> Parser<'a> {
             .span(span)
         } else if space_separated.len() == 1 {
             ValueVisitor::new(self, span)
-                .eval(space_separated.pop().unwrap().node)?
+                .eval_top_level(space_separated.pop().unwrap().node, allow_slash)?
                 .span(span)
         } else {
             Value::List(
                 space_separated
                     .into_iter()
-                    .map(|a| ValueVisitor::new(self, span).eval(a.node))
+                    .map(|a| ValueVisitor::new(self, span).eval_top_level(a.node, allow_slash))
                     .collect::<SassResult<Vec<Value>>>()?,
                 ListSeparator::Space,
                 Brackets::None,
@@ -178,22 +188,94 @@ impl<'a> Parser<'a> {
         })
     }
 
-    pub(crate) fn parse_value_from_vec(&mut self, toks: Vec<Token>) -> SassResult<Spanned<Value>> {
+    pub(crate) fn parse_value_from_vec(
+        &mut self,
+        toks: Vec<Token>,
+        allow_slash: bool,
+    ) -> SassResult<Spanned<Value>> {
         Parser {
             toks: &mut toks.into_iter().peekmore(),
             map: self.map,
             path: self.path,
             scopes: self.scopes,
-            global_scope: self.global_scope,
+            modules: self.modules,
+            module: self.module,
             super_selectors: self.super_selectors,
             span_before: self.span_before,
             content: self.content,
+            call_stack: self.call_stack,
+            options: self.options,
+            dependencies: self.dependencies,
+            import_stack: self.import_stack,
+            imported: self.imported,
             flags: self.flags,
             at_root: self.at_root,
             at_root_has_selector: self.at_root_has_selector,
             extender: self.extender,
+            media_queries: self.media_queries,
         }
-        .parse_value()
+        .parse_value(allow_slash)
+    }
+
+    /// Parse a member of the module loaded under `namespace`, either a
+    /// variable like `ns.$var` or a function call like `ns.fn()`
+    fn parse_module_member(
+        &mut self,
+        namespace: Spanned<Identifier>,
+    ) -> SassResult<Spanned<IntermediateValue>> {
+        let module = self.modules.get_namespace(self.module, &namespace)?;
+
+        if self.scan_char('$') {
+            let name = self.parse_identifier_no_interpolation(false)?;
+            let span = namespace.span.merge(name.span);
+            let value = self
+                .modules
+                .get_var(module, name.map_node(Identifier::from))?;
+            return Ok(
+                IntermediateValue::Value(HigherIntermediateValue::Literal(value.node)).span(span),
+            );
+        }
+
+        let name = self
+            .parse_identifier_no_interpolation(false)?
+            .map_node(Identifier::from);
+        let span = namespace.span.merge(name.span);
+        self.expect_char('(')?;
+
+        if let Some(func) = self.modules.get_builtin_fn(module, &name.node) {
+            return Ok(IntermediateValue::Value(HigherIntermediateValue::Function(
+                SassFunction::Builtin(func, name.node),
+                self.parse_call_args()?,
+            ))
+            .span(span));
+        }
+
+        let func = self.modules.get_fn(module, name.clone())?;
+
+        Ok(IntermediateValue::Value(HigherIntermediateValue::Function(
+            SassFunction::UserDefined(Box::new(func), name.node),
+            self.parse_call_args()?,
+        ))
+        .span(span))
+    }
+
+    /// Whether the tokens following the current `.` are a function name
+    /// followed by `(`, e.g. the `.fn()` of `ns.fn()`, in which case the
+    /// identifier before the `.` must be a namespace even if no module was
+    /// loaded under it
+    fn next_is_module_function_call(&mut self) -> bool {
+        self.toks.reset_cursor();
+        self.toks.advance_cursor();
+        let mut has_name = false;
+        while let Some(tok) = self.toks.peek_forward(0) {
+            match tok.kind {
+                c if c.is_alphanumeric() || c == '-' || c == '_' => has_name = true,
+                '(' => return has_name,
+                _ => return false,
+            }
+            self.toks.advance_cursor();
+        }
+        false
     }
 
     fn parse_ident_value(&mut self) -> SassResult<Spanned<IntermediateValue>> {
@@ -218,6 +300,21 @@ impl<'a> Parser<'a> {
             });
         }
 
+        if let Some(Token { kind: '.', .. }) = self.toks.peek() {
+            if matches!(self.toks.peek_forward(1), Some(Token { kind: '$', .. }))
+                || self.modules.has_namespace(self.module, &s)
+                || self.next_is_module_function_call()
+            {
+                self.toks.reset_cursor();
+                self.toks.next();
+                return self.parse_module_member(Spanned {
+                    node: Identifier::from(s),
+                    span,
+                });
+            }
+            self.toks.reset_cursor();
+        }
+
         if let Some(Token { kind: '(', .. }) = self.toks.peek() {
             self.toks.next();
 
@@ -225,9 +322,8 @@ impl<'a> Parser<'a> {
                 match self.try_parse_min_max("min", true)? {
                     Some(val) => {
                         self.toks.truncate_iterator_to_cursor();
-                        self.toks.next();
                         return Ok(IntermediateValue::Value(HigherIntermediateValue::Literal(
-                            Value::String(val, QuoteKind::None),
+                            Value::Calc(val),
                         ))
                         .span(span));
                     }
@@ -239,9 +335,8 @@ impl<'a> Parser<'a> {
                 match self.try_parse_min_max("max", true)? {
                     Some(val) => {
                         self.toks.truncate_iterator_to_cursor();
-                        self.toks.next();
                         return Ok(IntermediateValue::Value(HigherIntermediateValue::Literal(
-                            Value::String(val, QuoteKind::None),
+                            Value::Calc(val),
                         ))
                         .span(span));
                     }
@@ -253,24 +348,33 @@ impl<'a> Parser<'a> {
 
             let as_ident = Identifier::from(&s);
             let ident_as_string = as_ident.clone().into_inner();
-            let func = match self.scopes.last().get_fn(
-                Spanned {
-                    node: as_ident.clone(),
-                    span,
-                },
-                self.global_scope,
-            ) {
+            let func = match self.get_fn(Spanned {
+                node: as_ident.clone(),
+                span,
+            }) {
                 Ok(f) => f,
                 Err(_) => {
-                    if let Some(f) = GLOBAL_FUNCTIONS.get(ident_as_string.as_str()) {
+                    if let Some(f) = self
+                        .modules
+                        .get_global_builtin_fn(self.module, &as_ident)
+                        .or_else(|| GLOBAL_FUNCTIONS.get(ident_as_string.as_str()).cloned())
+                    {
                         return Ok(IntermediateValue::Value(HigherIntermediateValue::Function(
-                            SassFunction::Builtin(f.clone(), as_ident),
+                            SassFunction::Builtin(f, as_ident),
                             self.parse_call_args()?,
                         ))
                         .span(span));
                     } else {
                         // check for special cased CSS functions
-                        match lower.as_str() {
+                        match unvendor(&lower) {
+                            "calc" if lower == "calc" => {
+                                s = lower;
+                                self.eat_calc_args(&mut s)?;
+                                return Ok(IntermediateValue::Value(
+                                    HigherIntermediateValue::Literal(Value::Calc(s)),
+                                )
+                                .span(span));
+                            }
                             "calc" | "element" | "expression" => {
                                 s = lower;
                                 self.eat_calc_args(&mut s)?;
@@ -386,10 +490,7 @@ impl<'a> Parser<'a> {
                     if val.num.len() <= 18 && val.times_ten.is_empty() {
                         let n = Rational64::new_raw(parse_i64(&val.num), 1);
                         return Some(Ok(IntermediateValue::Value(
-                            HigherIntermediateValue::Literal(Value::Dimension(
-                                Number::new_small(n),
-                                unit,
-                            )),
+                            HigherIntermediateValue::Number(Number::new_small(n), unit),
                         )
                         .span(span)));
                     }
@@ -398,10 +499,7 @@ impl<'a> Parser<'a> {
                     if val.num.len() <= 18 && val.times_ten.is_empty() {
                         let n = Rational64::new(parse_i64(&val.num), pow(10, val.dec_len));
                         return Some(Ok(IntermediateValue::Value(
-                            HigherIntermediateValue::Literal(Value::Dimension(
-                                Number::new_small(n),
-                                unit,
-                            )),
+                            HigherIntermediateValue::Number(Number::new_small(n), unit),
                         )
                         .span(span)));
                     }
@@ -410,10 +508,7 @@ impl<'a> Parser<'a> {
 
                 if val.times_ten.is_empty() {
                     return Some(Ok(IntermediateValue::Value(
-                        HigherIntermediateValue::Literal(Value::Dimension(
-                            Number::new_big(n),
-                            unit,
-                        )),
+                        HigherIntermediateValue::Number(Number::new_big(n), unit),
                     )
                     .span(span)));
                 }
@@ -438,10 +533,10 @@ impl<'a> Parser<'a> {
                     BigRational::new(BigInt::one(), times_ten)
                 };
 
-                IntermediateValue::Value(HigherIntermediateValue::Literal(Value::Dimension(
+                IntermediateValue::Value(HigherIntermediateValue::Number(
                     Number::new_big(n * times_ten),
                     unit,
-                )))
+                ))
                 .span(span)
             }
             '(' => {
@@ -526,7 +621,7 @@ impl<'a> Parser<'a> {
                 };
                 let span = val.span;
                 IntermediateValue::Value(HigherIntermediateValue::Literal(
-                    match self.scopes.last().get_var(val, self.global_scope) {
+                    match self.get_var(val.map_node(Identifier::from)) {
                         Ok(v) => v,
                         Err(e) => return Some(Err(e)),
                     }
@@ -761,14 +856,17 @@ impl<'a, 'b: 'a> IntermediateValueIterator<'a, 'b> {
                 self.whitespace();
                 let right = self.single_value()?;
                 if let Some(left) = space_separated.pop() {
-                    space_separated.push(Spanned {
-                        node: HigherIntermediateValue::BinaryOp(
-                            Box::new(left.node),
-                            op.node,
-                            Box::new(right.node),
-                        ),
-                        span: left.span.merge(right.span),
-                    });
+                    if left.node.is_slash_operand() && right.node.is_slash_operand() {
+                        space_separated.push(
+                            HigherIntermediateValue::Slash(
+                                Box::new(left.node),
+                                Box::new(right.node),
+                            )
+                            .span(left.span.merge(right.span)),
+                        );
+                    } else {
+                        space_separated.push(Self::binary_op(left, op.node, right));
+                    }
                 } else {
                     self.whitespace();
                     space_separated.push(Spanned {
@@ -789,14 +887,7 @@ impl<'a, 'b: 'a> IntermediateValueIterator<'a, 'b> {
                 if let Some(left) = space_separated.pop() {
                     self.whitespace();
                     let right = self.single_value()?;
-                    space_separated.push(Spanned {
-                        node: HigherIntermediateValue::BinaryOp(
-                            Box::new(left.node),
-                            op.node,
-                            Box::new(right.node),
-                        ),
-                        span: left.span.merge(right.span),
-                    });
+                    space_separated.push(Self::binary_op(left, op.node, right));
                 } else {
                     self.whitespace();
                     let right = self.single_value()?;
@@ -810,14 +901,7 @@ impl<'a, 'b: 'a> IntermediateValueIterator<'a, 'b> {
                 if self.whitespace() || !last_was_whitespace {
                     let right = self.single_value()?;
                     if let Some(left) = space_separated.pop() {
-                        space_separated.push(Spanned {
-                            node: HigherIntermediateValue::BinaryOp(
-                                Box::new(left.node),
-                                op.node,
-                                Box::new(right.node),
-                            ),
-                            span: left.span.merge(right.span),
-                        });
+                        space_separated.push(Self::binary_op(left, op.node, right));
                     } else {
                         space_separated.push(
                             right.map_node(|n| {
@@ -860,10 +944,17 @@ impl<'a, 'b: 'a> IntermediateValueIterator<'a, 'b> {
                         );
                     } else {
                         // we explicitly ignore errors here as a workaround for short circuiting
+                        //
+                        // `or` has a lower precedence than `and`, so we stop skipping once
+                        // we reach one, e.g. `false and a or b` should evaluate to `b`
                         while let Some(value) = self.peek() {
                             if let Ok(Spanned {
                                 node: IntermediateValue::Comma,
                                 ..
+                            })
+                            | Ok(Spanned {
+                                node: IntermediateValue::Op(Op::Or),
+                                ..
                             }) = value
                             {
                                 break;
@@ -930,14 +1021,7 @@ impl<'a, 'b: 'a> IntermediateValueIterator<'a, 'b> {
                 if let Some(left) = space_separated.pop() {
                     self.whitespace();
                     let right = self.single_value()?;
-                    space_separated.push(
-                        HigherIntermediateValue::BinaryOp(
-                            Box::new(left.node),
-                            op.node,
-                            Box::new(right.node),
-                        )
-                        .span(left.span.merge(right.span)),
-                    );
+                    space_separated.push(Self::binary_op(left, op.node, right));
                 } else {
                     return Err(("Expected expression.", op.span).into());
                 }
@@ -946,6 +1030,27 @@ impl<'a, 'b: 'a> IntermediateValueIterator<'a, 'b> {
         Ok(())
     }
 
+    /// Combine `left` and `right` with a binary operator other than `and`
+    /// or `or`
+    ///
+    /// `not` binds more loosely than every other operator besides `and` and
+    /// `or`, so `not 1 > 2` is parsed as `not (1 > 2)`
+    fn binary_op(
+        left: Spanned<HigherIntermediateValue>,
+        op: Op,
+        right: Spanned<HigherIntermediateValue>,
+    ) -> Spanned<HigherIntermediateValue> {
+        let span = left.span.merge(right.span);
+        match left.node {
+            HigherIntermediateValue::UnaryOp(Op::Not, operand) => {
+                let operand = Self::binary_op(operand.span(left.span), op, right);
+                HigherIntermediateValue::UnaryOp(Op::Not, Box::new(operand.node)).span(span)
+            }
+            left => HigherIntermediateValue::BinaryOp(Box::new(left), op, Box::new(right.node))
+                .span(span),
+        }
+    }
+
     fn single_value(&mut self) -> SassResult<Spanned<HigherIntermediateValue>> {
         let next = self
             .next()
@@ -1012,7 +1117,7 @@ impl<'a, 'b: 'a> IntermediateValueIterator<'a, 'b> {
                 return Err(("Expected expression.", self.parser.span_before).into())
             }
             IntermediateValue::Bracketed(t) => {
-                let v = self.parser.parse_value_from_vec(t)?;
+                let v = self.parser.parse_value_from_vec(t, false)?;
                 HigherIntermediateValue::Literal(match v.node {
                     Value::List(v, sep, Brackets::None) => Value::List(v, sep, Brackets::Bracketed),
                     v => Value::List(vec![v], ListSeparator::Space, Brackets::Bracketed),
@@ -1050,7 +1155,7 @@ impl<'a, 'b: 'a> IntermediateValueIterator<'a, 'b> {
         let mut map = SassMap::new();
         let key = self
             .parser
-            .parse_value_from_vec(read_until_char(paren_toks, ':')?)?;
+            .parse_value_from_vec(read_until_char(paren_toks, ':')?, false)?;
 
         if paren_toks.peek().is_none() {
             return Ok(Spanned {
@@ -1063,7 +1168,7 @@ impl<'a, 'b: 'a> IntermediateValueIterator<'a, 'b> {
 
         let val = self
             .parser
-            .parse_value_from_vec(read_until_char(paren_toks, ',')?)?;
+            .parse_value_from_vec(read_until_char(paren_toks, ',')?, false)?;
 
         map.insert(key.node, val.node);
 
@@ -1081,11 +1186,11 @@ impl<'a, 'b: 'a> IntermediateValueIterator<'a, 'b> {
         loop {
             let key = self
                 .parser
-                .parse_value_from_vec(read_until_char(paren_toks, ':')?)?;
+                .parse_value_from_vec(read_until_char(paren_toks, ':')?, false)?;
             devour_whitespace(paren_toks);
             let val = self
                 .parser
-                .parse_value_from_vec(read_until_char(paren_toks, ',')?)?;
+                .parse_value_from_vec(read_until_char(paren_toks, ',')?, false)?;
             span = span.merge(val.span);
             devour_whitespace(paren_toks);
             if map.insert(key.node, val.node) {
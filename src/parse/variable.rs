@@ -41,18 +41,26 @@ impl<'a> Parser<'a> {
         let value = self.parse_variable_value()?;
 
         if value.global && !value.default {
-            self.global_scope
+            self.global_scope_mut()
                 .insert_var(ident.clone(), value.value.clone());
         }
 
         if value.default {
             if self.at_root && !self.flags.in_control_flow() {
-                if !self.global_scope.var_exists_no_global(&ident) {
-                    self.global_scope.insert_var(ident, value.value);
+                let module = self.module;
+                match self.modules[module].configuration.remove(&ident) {
+                    Some(configured) if !configured.node.is_null() => {
+                        self.global_scope_mut().insert_var(ident, configured);
+                    }
+                    _ => {
+                        if !self.global_scope().var_exists_no_global(&ident) {
+                            self.global_scope_mut().insert_var(ident, value.value);
+                        }
+                    }
                 }
             } else {
-                if value.global && !self.global_scope.var_exists_no_global(&ident) {
-                    self.global_scope
+                if value.global && !self.global_scope().var_exists_no_global(&ident) {
+                    self.global_scope_mut()
                         .insert_var(ident.clone(), value.value.clone());
                 }
                 if !self.scopes.last().var_exists_no_global(&ident) {
@@ -61,13 +69,13 @@ impl<'a> Parser<'a> {
             }
         } else if self.at_root {
             if self.flags.in_control_flow() {
-                if self.global_scope.var_exists_no_global(&ident) {
-                    self.global_scope.insert_var(ident, value.value);
+                if self.global_scope().var_exists_no_global(&ident) {
+                    self.global_scope_mut().insert_var(ident, value.value);
                 } else {
                     self.scopes.last_mut().insert_var(ident, value.value);
                 }
             } else {
-                self.global_scope.insert_var(ident, value.value);
+                self.global_scope_mut().insert_var(ident, value.value);
             }
         } else {
             let len = self.scopes.len();
@@ -91,6 +99,55 @@ impl<'a> Parser<'a> {
         Ok(())
     }
 
+    /// Whether the next tokens begin an assignment to a variable of another
+    /// module, e.g. `lib.$var: value`
+    pub(super) fn next_is_namespaced_variable(&mut self) -> bool {
+        let mut is_namespaced = false;
+        let mut has_namespace = false;
+        while let Some(tok) = self.toks.peek_forward(0) {
+            match tok.kind {
+                c if c.is_alphanumeric() || c == '-' || c == '_' => has_namespace = true,
+                '.' if has_namespace => {
+                    self.toks.advance_cursor();
+                    is_namespaced =
+                        matches!(self.toks.peek_forward(0), Some(Token { kind: '$', .. }));
+                    break;
+                }
+                _ => break,
+            }
+            self.toks.advance_cursor();
+        }
+        self.toks.reset_cursor();
+        is_namespaced
+    }
+
+    /// Parse an assignment to a variable of another module, e.g.
+    /// `lib.$var: value`
+    pub(super) fn parse_namespaced_variable_declaration(&mut self) -> SassResult<()> {
+        let namespace = self
+            .parse_identifier_no_interpolation(false)?
+            .map_node(Identifier::from);
+        self.expect_char('.')?;
+        self.expect_char('$')?;
+        let name = self
+            .parse_identifier_no_interpolation(false)?
+            .map_node(Identifier::from);
+        self.whitespace();
+        self.expect_char(':')?;
+        let value = self.parse_variable_value()?;
+
+        if value.global {
+            return Err((
+                "!global isn't allowed for variables in other modules.",
+                value.value.span,
+            )
+                .into());
+        }
+
+        let module = self.modules.get_namespace(self.module, &namespace)?;
+        self.modules.assign_var(module, &name)
+    }
+
     fn parse_variable_value(&mut self) -> SassResult<VariableValue> {
         let mut default = false;
         let mut global = false;
@@ -189,7 +246,7 @@ impl<'a> Parser<'a> {
                 _ => val_toks.push(self.toks.next().unwrap()),
             }
         }
-        let val = self.parse_value_from_vec(val_toks)?;
+        let val = self.parse_value_from_vec(val_toks, false)?;
         Ok(VariableValue::new(val, global, default))
     }
 }
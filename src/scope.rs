@@ -28,7 +28,7 @@ impl Scope {
         }
     }
 
-    fn get_var_no_global(&self, name: &Spanned<Identifier>) -> SassResult<Spanned<Value>> {
+    pub fn get_var_no_global(&self, name: &Spanned<Identifier>) -> SassResult<Spanned<Value>> {
         match self.vars.get(&name.node) {
             Some(v) => Ok(v.clone()),
             None => Err(("Undefined variable.", name.span).into()),
@@ -55,6 +55,14 @@ impl Scope {
         self.vars.insert(s.into(), v)
     }
 
+    pub fn remove_var<T: Into<Identifier>>(&mut self, s: T) -> Option<Spanned<Value>> {
+        self.vars.remove(&s.into())
+    }
+
+    pub fn var_names(&self) -> impl Iterator<Item = &Identifier> {
+        self.vars.keys()
+    }
+
     pub fn var_exists_no_global(&self, name: &Identifier) -> bool {
         self.vars.contains_key(name)
     }
@@ -64,7 +72,7 @@ impl Scope {
         self.vars.contains_key(name) || global_scope.var_exists_no_global(name)
     }
 
-    fn get_mixin_no_global(&self, name: &Spanned<Identifier>) -> SassResult<Mixin> {
+    pub fn get_mixin_no_global(&self, name: &Spanned<Identifier>) -> SassResult<Mixin> {
         match self.mixins.get(&name.node) {
             Some(v) => Ok(v.clone()),
             None => Err(("Undefined mixin.", name.span).into()),
@@ -87,7 +95,7 @@ impl Scope {
         self.mixins.insert(s.into(), v)
     }
 
-    fn mixin_exists_no_global(&self, name: &Identifier) -> bool {
+    pub fn mixin_exists_no_global(&self, name: &Identifier) -> bool {
         self.mixins.contains_key(name)
     }
 
@@ -96,7 +104,7 @@ impl Scope {
         self.mixins.contains_key(&name) || global_scope.mixin_exists_no_global(&name)
     }
 
-    fn get_fn_no_global(&self, name: &Spanned<Identifier>) -> SassResult<Function> {
+    pub fn get_fn_no_global(&self, name: &Spanned<Identifier>) -> SassResult<Function> {
         match self.functions.get(&name.node) {
             Some(v) => Ok(v.clone()),
             None => Err(("Undefined function.", name.span).into()),
@@ -119,7 +127,11 @@ impl Scope {
         self.functions.insert(s.into(), v)
     }
 
-    fn fn_exists_no_global(&self, name: &Identifier) -> bool {
+    pub fn fn_names(&self) -> impl Iterator<Item = &Identifier> {
+        self.functions.keys()
+    }
+
+    pub fn fn_exists_no_global(&self, name: &Identifier) -> bool {
         self.functions.contains_key(name)
     }
 
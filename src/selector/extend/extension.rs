@@ -1,5 +1,7 @@
 use codemap::Span;
 
+use crate::error::SassResult;
+
 use super::{ComplexSelector, CssMediaQuery, SimpleSelector};
 
 #[derive(Clone, Debug)]
@@ -50,17 +52,32 @@ impl Extension {
         }
     }
 
+    /// Whether this extension may extend a selector defined within
+    /// `media_context`
+    pub fn is_compatible_media_context(&self, media_context: &Option<Vec<CssMediaQuery>>) -> bool {
+        self.media_context.is_none() || &self.media_context == media_context
+    }
+
     /// Asserts that the `media_context` for a selector is compatible with the
     /// query context for this extender.
-    // todo: this should return a `Result`. it currently does not because the cascade effect
-    // from this returning a `Result` will make some code returning `Option`s much uglier (we can't
-    // use `?` to return both `Option` and `Result` from the same function)
-    pub fn assert_compatible_media_context(&self, media_context: &Option<Vec<CssMediaQuery>>) {
-        if &self.media_context == media_context {
-            return;
+    ///
+    /// Optional extensions are never an error, and instead just don't apply
+    pub fn assert_compatible_media_context(
+        &self,
+        media_context: &Option<Vec<CssMediaQuery>>,
+        span: Span,
+    ) -> SassResult<()> {
+        if self.is_optional || self.is_compatible_media_context(media_context) {
+            return Ok(());
         }
 
-        // Err(("You may not @extend selectors across media queries.", self.span.unwrap()).into())
+        let message = if media_context.is_none() {
+            "You may not @extend an outer selector from within @media."
+        } else {
+            "You may not @extend selectors across media queries."
+        };
+
+        Err((message, self.span.unwrap_or(span)).into())
     }
 
     #[allow(clippy::missing_const_for_fn)]
@@ -28,8 +28,15 @@ mod functions;
 mod merged;
 mod rule;
 
+/// The query of an `@media` rule, e.g. `screen and (min-width: 100px)`
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
-pub(crate) struct CssMediaQuery;
+pub(crate) struct CssMediaQuery(String);
+
+impl CssMediaQuery {
+    pub const fn new(query: String) -> Self {
+        Self(query)
+    }
+}
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
 /// Different modes in which extension can run.
@@ -422,10 +429,7 @@ impl Extender {
                     .first()?
                     .clone()
                     .into_iter()
-                    .map(|state| {
-                        state.assert_compatible_media_context(media_query_context);
-                        state.extender
-                    })
+                    .map(|state| state.extender)
                     .collect(),
             );
         }
@@ -506,7 +510,6 @@ impl Extender {
                 let mut line_break = false;
 
                 for state in path {
-                    state.assert_compatible_media_context(media_query_context);
                     line_break = line_break || state.extender.line_break;
                 }
 
@@ -555,6 +558,7 @@ impl Extender {
                             self.without_pseudo(
                                 SimpleSelector::Pseudo(pseudo.clone()),
                                 extensions,
+                                media_query_context,
                                 targets_used,
                                 self.mode,
                             )
@@ -567,8 +571,14 @@ impl Extender {
             }
         }
 
-        self.without_pseudo(simple, extensions, targets_used, self.mode)
-            .map(|v| vec![v])
+        self.without_pseudo(
+            simple,
+            extensions,
+            media_query_context,
+            targets_used,
+            self.mode,
+        )
+        .map(|v| vec![v])
     }
 
     /// Extends `pseudo` using `extensions`, and returns a list of resulting
@@ -710,23 +720,36 @@ impl Extender {
 
     /// Extends `simple` without extending the contents of any selector pseudos
     /// it contains.
+    ///
+    /// Extensions which may not apply within `media_query_context` are ignored.
     fn without_pseudo(
         &self,
         simple: SimpleSelector,
         extensions: Option<&HashMap<SimpleSelector, IndexMap<ComplexSelector, Extension>>>,
+        media_query_context: &Option<Vec<CssMediaQuery>>,
         targets_used: &mut HashSet<SimpleSelector>,
         mode: ExtendMode,
     ) -> Option<Vec<Extension>> {
-        let extenders = extensions.unwrap_or(&self.extensions).get(&simple)?;
+        let extenders: Vec<Extension> = extensions
+            .unwrap_or(&self.extensions)
+            .get(&simple)?
+            .values()
+            .filter(|extension| extension.is_compatible_media_context(media_query_context))
+            .cloned()
+            .collect();
+
+        if extenders.is_empty() {
+            return None;
+        }
 
         targets_used.insert(simple.clone());
 
         if mode == ExtendMode::Replace {
-            return Some(extenders.values().cloned().collect());
+            return Some(extenders);
         }
 
         let mut tmp = vec![self.extension_for_simple(simple)];
-        tmp.extend(extenders.values().cloned());
+        tmp.extend(extenders);
 
         Some(tmp)
     }
@@ -872,7 +895,9 @@ impl Extender {
         mut selector: SelectorList,
         // span: Span,
         media_query_context: Option<Vec<CssMediaQuery>>,
-    ) -> ExtendedSelector {
+    ) -> SassResult<ExtendedSelector> {
+        self.assert_compatible_extensions(&selector, &media_query_context)?;
+
         if !selector.is_invisible() {
             for complex in selector.components.clone() {
                 self.originals.insert(complex);
@@ -891,14 +916,39 @@ impl Extender {
             }
               */
         }
-        if let Some(mut media_query_context) = media_query_context {
+        if let Some(media_query_context) = media_query_context {
             self.media_contexts
-                .get_mut(&selector)
-                .replace(&mut media_query_context);
+                .insert(selector.clone(), media_query_context);
         }
         let extended_selector = ExtendedSelector::new(selector.clone());
         self.register_selector(selector, &extended_selector);
-        extended_selector
+        Ok(extended_selector)
+    }
+
+    /// Errors if an extension of any simple selector in `selector` may not
+    /// extend a selector defined within `media_query_context`
+    fn assert_compatible_extensions(
+        &self,
+        selector: &SelectorList,
+        media_query_context: &Option<Vec<CssMediaQuery>>,
+    ) -> SassResult<()> {
+        for complex in &selector.components {
+            for component in &complex.components {
+                if let ComplexSelectorComponent::Compound(compound) = component {
+                    for simple in &compound.components {
+                        if let Some(extensions) = self.extensions.get(simple) {
+                            for extension in extensions.values() {
+                                extension.assert_compatible_media_context(
+                                    media_query_context,
+                                    self.span,
+                                )?;
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        Ok(())
     }
 
     /// Registers the `SimpleSelector`s in `list` to point to `selector` in
@@ -949,7 +999,7 @@ impl Extender {
         extend: &ExtendRule,
         media_context: &Option<Vec<CssMediaQuery>>,
         span: Option<Span>,
-    ) {
+    ) -> SassResult<()> {
         let selectors = self.selectors.get(target).cloned();
         let existing_extensions = self.extensions_by_extender.get(target).cloned();
 
@@ -968,6 +1018,16 @@ impl Extender {
                 right: None,
             };
 
+            if let Some(selectors) = &selectors {
+                for selector in selectors.clone().into_iter() {
+                    let selector = selector.into_selector().0;
+                    state.assert_compatible_media_context(
+                        &self.media_contexts.get(&selector).cloned(),
+                        self.span,
+                    )?;
+                }
+            }
+
             let sources = self
                 .extensions
                 .entry(target.clone())
@@ -1010,7 +1070,7 @@ impl Extender {
         let new_extensions = if let Some(new) = new_extensions {
             new
         } else {
-            return;
+            return Ok(());
         };
 
         let mut new_extensions_by_target = HashMap::new();
@@ -1027,6 +1087,41 @@ impl Extender {
         if let Some(selectors) = selectors {
             self.extend_existing_selectors(selectors, &new_extensions_by_target);
         }
+
+        Ok(())
+    }
+
+    /// Throws an error if any (non-optional) extensions failed to match any
+    /// selectors.
+    pub fn check_unsatisfied_extensions(&self) -> SassResult<()> {
+        let mut already_reported = HashSet::new();
+        for sources in self.extensions.values() {
+            for extension in sources.values() {
+                if extension.is_optional {
+                    continue;
+                }
+
+                let target = match &extension.target {
+                    Some(target) => target,
+                    None => continue,
+                };
+
+                if !already_reported.insert(target) || self.selectors.contains_key(target) {
+                    continue;
+                }
+
+                return Err((
+                    format!(
+                        "The target selector was not found.\nUse \"@extend {} !optional\" to avoid this error.",
+                        target
+                    ),
+                    extension.span.unwrap_or(self.span),
+                )
+                    .into());
+            }
+        }
+
+        Ok(())
     }
 
     /// Extend `extensions` using `new_extensions`.
@@ -1140,10 +1235,11 @@ impl Extender {
     ) {
         for mut selector in selectors.into_iter() {
             let old_value = selector.clone().into_selector().0;
+            let media_query_context = self.media_contexts.get(&old_value).cloned();
             selector.set_inner(self.extend_list(
                 old_value.clone(),
                 Some(new_extensions),
-                &self.media_contexts.get(&old_value).cloned(),
+                &media_query_context,
             ));
             /*
             todo: error handling
@@ -1162,6 +1258,10 @@ impl Extender {
             if old_value == selector_as_selector {
                 continue;
             }
+            if let Some(media_query_context) = media_query_context {
+                self.media_contexts
+                    .insert(selector_as_selector.clone(), media_query_context);
+            }
             self.register_selector(selector_as_selector, &selector);
         }
     }
@@ -4,7 +4,7 @@ use crate::{
     common::unvendor,
     error::SassResult,
     parse::Parser,
-    utils::{is_name, is_name_start, read_until_closing_paren},
+    utils::{is_name, is_name_start, peek_whitespace, read_until_closing_paren},
     Token,
 };
 
@@ -478,7 +478,7 @@ impl<'a, 'b> SelectorParser<'a, 'b> {
                     buf.push(t.kind);
                     self.parser.toks.next();
                 }
-                self.parser.whitespace();
+                self.whitespace_before(|c| c == 'n' || c == 'N');
                 if let Some(t) = self.parser.toks.peek() {
                     if t.kind != 'n' && t.kind != 'N' {
                         return Ok(buf);
@@ -498,7 +498,7 @@ impl<'a, 'b> SelectorParser<'a, 'b> {
 
         buf.push('n');
 
-        self.parser.whitespace();
+        self.whitespace_before(|c| c == '+' || c == '-');
 
         if let Some(t @ Token { kind: '+', .. }) | Some(t @ Token { kind: '-', .. }) =
             self.parser.toks.peek()
@@ -525,6 +525,22 @@ impl<'a, 'b> SelectorParser<'a, 'b> {
         Ok(buf)
     }
 
+    /// Consume whitespace only if it is followed by a token matching `pred`,
+    /// so that callers may still see whitespace that ends a construct
+    fn whitespace_before(&mut self, pred: impl Fn(char) -> bool) -> bool {
+        peek_whitespace(self.parser.toks);
+        match self.parser.toks.peek() {
+            Some(t) if pred(t.kind) => {
+                self.parser.toks.truncate_iterator_to_cursor();
+                true
+            }
+            _ => {
+                self.parser.toks.reset_cursor();
+                false
+            }
+        }
+    }
+
     fn declaration_value(&mut self) -> SassResult<String> {
         // todo: this consumes the closing paren
         let mut tmp = read_until_closing_paren(self.parser.toks)?;
@@ -0,0 +1,215 @@
+//! # Source maps
+//!
+//! Maps positions in the generated CSS back to the Sass they were compiled
+//! from, following version 3 of the source map format. Selectors and
+//! declarations are mapped; other output is not.
+use codemap::{CodeMap, Span};
+
+const BASE64_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// How a source map should be emitted, if at all
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SourceMapOption {
+    /// Do not generate a source map
+    None,
+    /// Embed the source map in the CSS as a base64 encoded data URL, in a
+    /// trailing `/*# sourceMappingURL=... */` comment
+    Inline,
+}
+
+impl Default for SourceMapOption {
+    #[inline]
+    fn default() -> Self {
+        SourceMapOption::None
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Mapping {
+    generated_line: usize,
+    generated_column: usize,
+    source: usize,
+    source_line: usize,
+    source_column: usize,
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct SourceMap {
+    sources: Vec<String>,
+    mappings: Vec<Mapping>,
+    /// The number of bytes of output already counted into `line` and `column`
+    scanned: usize,
+    line: usize,
+    column: usize,
+}
+
+impl SourceMap {
+    pub fn new() -> Self {
+        SourceMap::default()
+    }
+
+    /// Record that the output about to be written `indent` columns after the
+    /// end of `buf` was compiled from `span`
+    pub fn add(&mut self, buf: &[u8], indent: usize, span: Span, map: &CodeMap) {
+        for &byte in &buf[self.scanned..] {
+            if byte == b'\n' {
+                self.line += 1;
+                self.column = 0;
+            } else if byte & 0xC0 != 0x80 {
+                // only count the first byte of each character
+                self.column += 1;
+            }
+        }
+        self.scanned = buf.len();
+
+        let loc = map.look_up_span(span);
+        let name = loc.file.name();
+        let source = match self.sources.iter().position(|s| s == name) {
+            Some(idx) => idx,
+            None => {
+                self.sources.push(name.to_owned());
+                self.sources.len() - 1
+            }
+        };
+
+        self.mappings.push(Mapping {
+            generated_line: self.line,
+            generated_column: self.column + indent,
+            source,
+            source_line: loc.begin.line,
+            source_column: loc.begin.column,
+        });
+    }
+
+    /// Account for a line inserted before the rest of the output, such as
+    /// `@charset`
+    pub fn prepend_line(&mut self) {
+        for mapping in &mut self.mappings {
+            mapping.generated_line += 1;
+        }
+    }
+
+    fn encode_mappings(&self) -> String {
+        let mut out = String::new();
+        let mut line = 0;
+        let mut prev_column = 0;
+        let mut prev_source = 0;
+        let mut prev_source_line = 0;
+        let mut prev_source_column = 0;
+
+        for (idx, mapping) in self.mappings.iter().enumerate() {
+            if mapping.generated_line != line {
+                while line < mapping.generated_line {
+                    out.push(';');
+                    line += 1;
+                }
+                prev_column = 0;
+            } else if idx != 0 {
+                out.push(',');
+            }
+
+            encode_vlq(
+                &mut out,
+                mapping.generated_column as i64 - prev_column as i64,
+            );
+            encode_vlq(&mut out, mapping.source as i64 - prev_source as i64);
+            encode_vlq(
+                &mut out,
+                mapping.source_line as i64 - prev_source_line as i64,
+            );
+            encode_vlq(
+                &mut out,
+                mapping.source_column as i64 - prev_source_column as i64,
+            );
+
+            prev_column = mapping.generated_column;
+            prev_source = mapping.source;
+            prev_source_line = mapping.source_line;
+            prev_source_column = mapping.source_column;
+        }
+
+        out
+    }
+
+    pub fn to_json(&self) -> String {
+        let sources = self
+            .sources
+            .iter()
+            .map(|s| json_string(s))
+            .collect::<Vec<String>>()
+            .join(",");
+        format!(
+            "{{\"version\":3,\"sources\":[{}],\"names\":[],\"mappings\":\"{}\"}}",
+            sources,
+            self.encode_mappings()
+        )
+    }
+
+    /// The comment linking CSS to this source map, embedded as a data URL
+    pub fn to_inline_comment(&self) -> String {
+        format!(
+            "/*# sourceMappingURL=data:application/json;base64,{} */",
+            base64(self.to_json().as_bytes())
+        )
+    }
+}
+
+/// Append `value` to `out` as a base64 VLQ
+fn encode_vlq(out: &mut String, value: i64) {
+    let mut vlq = if value < 0 {
+        ((-value as u64) << 1) | 1
+    } else {
+        (value as u64) << 1
+    };
+
+    loop {
+        let mut digit = (vlq & 0b1_1111) as usize;
+        vlq >>= 5;
+        if vlq > 0 {
+            digit |= 0b10_0000;
+        }
+        out.push(BASE64_ALPHABET[digit] as char);
+        if vlq == 0 {
+            break;
+        }
+    }
+}
+
+fn base64(bytes: &[u8]) -> String {
+    let mut out = String::with_capacity((bytes.len() + 2) / 3 * 4);
+    for chunk in bytes.chunks(3) {
+        let b = [
+            chunk[0],
+            chunk.get(1).copied().unwrap_or(0),
+            chunk.get(2).copied().unwrap_or(0),
+        ];
+        let n = (u32::from(b[0]) << 16) | (u32::from(b[1]) << 8) | u32::from(b[2]);
+        for i in 0..4 {
+            if i <= chunk.len() {
+                out.push(BASE64_ALPHABET[(n >> (18 - 6 * i) & 0b11_1111) as usize] as char);
+            } else {
+                out.push('=');
+            }
+        }
+    }
+    out
+}
+
+fn json_string(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
@@ -0,0 +1,33 @@
+//! # Stack size
+//!
+//! Each level of `@function` or `@mixin` recursion can use tens of kilobytes
+//! of stack, so at the default recursion limit compilation needs far more
+//! than most threads provide. Rather than run every compilation on a thread
+//! with a stack sized for `Options::recursion_limit`, the stack is grown as
+//! calls nest, so that reaching the limit is reported as an error rather than
+//! aborting the process. Compilations which don't recurse deeply only ever
+//! use the stack of the calling thread.
+//!
+//! When compiled to web assembly, the stack can't be grown, so calls are made
+//! directly.
+
+/// If less stack than this remains when a call begins, the call is made on a
+/// newly allocated stack. This must be more than a single level of recursion
+/// uses in debug builds
+#[cfg(not(target_arch = "wasm32"))]
+const RED_ZONE: usize = 512 * 1024;
+
+/// The size of each stack allocated when the current one runs low
+#[cfg(not(target_arch = "wasm32"))]
+const STACK_SIZE: usize = 8 * 1024 * 1024;
+
+/// Make a mixin or function call, first growing the stack if little remains
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) fn grow<T>(call: impl FnOnce() -> T) -> T {
+    stacker::maybe_grow(RED_ZONE, STACK_SIZE, call)
+}
+
+#[cfg(target_arch = "wasm32")]
+pub(crate) fn grow<T>(call: impl FnOnce() -> T) -> T {
+    call()
+}
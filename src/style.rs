@@ -1,6 +1,10 @@
 use codemap::Spanned;
 
-use crate::{error::SassResult, value::Value};
+use crate::{
+    common::{Brackets, ListSeparator, QuoteKind},
+    error::SassResult,
+    value::Value,
+};
 
 /// A style: `color: red`
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -11,10 +15,32 @@ pub(crate) struct Style {
 
 impl Style {
     pub fn to_string(&self) -> SassResult<String> {
-        Ok(format!(
-            "{}: {};",
-            self.property,
-            self.value.node.to_css_string(self.value.span)?
-        ))
+        let value = match &self.value.node {
+            // `#{$val} !important`, where `$val` already ends with `!important`
+            Value::List(elems, ListSeparator::Space, Brackets::None)
+                if elems.len() > 1
+                    && elems.last() == Some(&Value::Important)
+                    && ends_with_important(&elems[elems.len() - 2]) =>
+            {
+                let mut elems = elems.clone();
+                elems.pop();
+                Value::List(elems, ListSeparator::Space, Brackets::None)
+                    .to_css_string(self.value.span)?
+            }
+            v => v.to_css_string(self.value.span)?,
+        };
+
+        Ok(format!("{}: {};", self.property, value))
+    }
+}
+
+fn ends_with_important(value: &Value) -> bool {
+    match value {
+        Value::Important => true,
+        Value::String(s, QuoteKind::None) => s.to_ascii_lowercase().ends_with("!important"),
+        Value::List(elems, ListSeparator::Space, Brackets::None) => {
+            elems.last().map_or(false, ends_with_important)
+        }
+        _ => false,
     }
 }
@@ -0,0 +1,109 @@
+//! Access to the files loaded by `@import`, `@use` and `@forward`
+//!
+//! These are read from the file system, unless the WASM build was given an
+//! importer, in which case each file is requested from JavaScript instead.
+
+use std::{
+    fs, io,
+    path::{Component, Path, PathBuf},
+};
+
+#[cfg(feature = "wasm")]
+use wasm_bindgen::JsValue;
+
+use crate::Options;
+
+/// Whether `path` refers to a file which can be loaded
+#[cfg_attr(not(feature = "wasm"), allow(unused_variables))]
+pub(crate) fn is_file(path: &Path, options: &Options) -> bool {
+    #[cfg(feature = "wasm")]
+    {
+        if let Some(importer) = &options.importer {
+            return matches!(import(importer, path), Ok(Some(..)));
+        }
+    }
+    path.is_file()
+}
+
+/// The contents of the file at `path`
+#[cfg_attr(not(feature = "wasm"), allow(unused_variables))]
+pub(crate) fn read_file(path: &Path, options: &Options) -> io::Result<Vec<u8>> {
+    #[cfg(feature = "wasm")]
+    {
+        if let Some(importer) = &options.importer {
+            return match import(importer, path)? {
+                Some(contents) => Ok(contents.into_bytes()),
+                None => Err(io::Error::new(
+                    io::ErrorKind::NotFound,
+                    format!("{} was not found by the importer", path.display()),
+                )),
+            };
+        }
+    }
+    fs::read(path)
+}
+
+/// The canonical form of `path`, which is the same for every path referring
+/// to the same file
+///
+/// Files loaded by an importer aren't on the file system, so their paths are
+/// only normalized
+#[cfg_attr(not(feature = "wasm"), allow(unused_variables))]
+pub(crate) fn canonicalize(path: &Path, options: &Options) -> io::Result<PathBuf> {
+    #[cfg(feature = "wasm")]
+    {
+        if options.importer.is_some() {
+            return Ok(normalize(path));
+        }
+    }
+    fs::canonicalize(path)
+}
+
+/// Remove the `.` components of `path`, along with each `..` component and
+/// the component before it
+#[cfg_attr(not(feature = "wasm"), allow(dead_code))]
+fn normalize(path: &Path) -> PathBuf {
+    let mut normal = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir
+                if matches!(normal.components().next_back(), Some(Component::Normal(..))) =>
+            {
+                normal.pop();
+            }
+            component => normal.push(component),
+        }
+    }
+    normal
+}
+
+/// Ask `importer` for the contents of the file at `path`, which it returns as
+/// a string, or as `null` or `undefined` if there is no such file
+#[cfg(feature = "wasm")]
+fn import(importer: &js_sys::Function, path: &Path) -> io::Result<Option<String>> {
+    let contents = importer
+        .call1(&JsValue::NULL, &JsValue::from_str(&path.to_string_lossy()))
+        .map_err(|e| {
+            io::Error::new(
+                io::ErrorKind::Other,
+                format!(
+                    "importer failed to load {}: {}",
+                    path.display(),
+                    e.as_string().unwrap_or_else(|| format!("{:?}", e))
+                ),
+            )
+        })?;
+
+    if contents.is_undefined() || contents.is_null() {
+        return Ok(None);
+    }
+
+    match contents.as_string() {
+        Some(contents) => Ok(Some(contents)),
+        None => Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "importer must return a string, null or undefined",
+        )),
+    }
+}
@@ -0,0 +1,104 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// Every path beneath `dir` which matches `pattern`, whose components are
+/// separated by `/`
+///
+/// Within a component, `*` matches any number of characters, `?` matches any
+/// one character, and `[...]` matches any one of the characters it contains,
+/// which may include ranges such as `a-z`, or any other character if it begins
+/// with `!`. A component of `**` matches any number of nested directories.
+/// Directories which can't be read are skipped.
+pub(crate) fn glob(dir: &Path, pattern: &str) -> Vec<PathBuf> {
+    let components: Vec<&str> = pattern.split('/').filter(|c| !c.is_empty()).collect();
+    let mut paths = Vec::new();
+    walk(dir, &components, &mut paths);
+    paths
+}
+
+fn walk(path: &Path, components: &[&str], paths: &mut Vec<PathBuf>) {
+    let (component, rest) = match components.split_first() {
+        Some(v) => v,
+        None => {
+            paths.push(path.to_path_buf());
+            return;
+        }
+    };
+
+    if *component == "**" {
+        walk(path, rest, paths);
+        // symlinks are not followed, as they may form a cycle
+        for child in read_dir(path) {
+            if fs::symlink_metadata(&child).map_or(false, |meta| meta.is_dir()) {
+                walk(&child, components, paths);
+            }
+        }
+    } else if component.contains(|c| matches!(c, '*' | '?' | '[')) {
+        let pattern: Vec<char> = component.chars().collect();
+        for child in read_dir(path) {
+            let name: Option<Vec<char>> = child
+                .file_name()
+                .and_then(|name| name.to_str())
+                .map(|name| name.chars().collect());
+            if name.map_or(false, |name| matches(&pattern, &name)) {
+                walk(&child, rest, paths);
+            }
+        }
+    } else {
+        let child = path.join(component);
+        if child.exists() {
+            walk(&child, rest, paths);
+        }
+    }
+}
+
+/// The paths of the entries of `dir`, where an empty path refers to the
+/// current directory
+fn read_dir(dir: &Path) -> impl Iterator<Item = PathBuf> + '_ {
+    let entries = if dir.as_os_str().is_empty() {
+        fs::read_dir(".")
+    } else {
+        fs::read_dir(dir)
+    };
+
+    entries
+        .into_iter()
+        .flatten()
+        .filter_map(Result::ok)
+        .map(move |entry| dir.join(entry.file_name()))
+}
+
+/// Whether `name` matches `pattern`, a single path component. An unclosed `[`
+/// matches itself
+fn matches(pattern: &[char], name: &[char]) -> bool {
+    match (pattern, name) {
+        ([], name) => name.is_empty(),
+        (['*', rest @ ..], name) => (0..=name.len()).any(|idx| matches(rest, &name[idx..])),
+        ([_, ..], []) => false,
+        (['?', rest @ ..], [_, name @ ..]) => matches(rest, name),
+        (['[', class @ ..], [c, name @ ..]) => {
+            let (negated, class) = match class {
+                ['!', class @ ..] => (true, class),
+                class => (false, class),
+            };
+            // a `]` immediately after the `[` is part of the class, e.g. `[]a]`
+            match class.iter().skip(1).position(|&c| c == ']') {
+                Some(end) => {
+                    in_class(&class[..=end], *c) != negated && matches(&class[end + 2..], name)
+                }
+                None => *c == '[' && matches(&pattern[1..], name),
+            }
+        }
+        ([p, rest @ ..], [c, name @ ..]) => p == c && matches(rest, name),
+    }
+}
+
+fn in_class(class: &[char], c: char) -> bool {
+    match class {
+        [] => false,
+        [start, '-', end, rest @ ..] => (*start..=*end).contains(&c) || in_class(rest, c),
+        [first, rest @ ..] => *first == c || in_class(rest, c),
+    }
+}
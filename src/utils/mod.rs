@@ -1,5 +1,7 @@
 pub(crate) use chars::*;
 pub(crate) use comment_whitespace::*;
+pub(crate) use fs::*;
+pub(crate) use glob::*;
 pub(crate) use number::*;
 pub(crate) use peek_until::*;
 pub(crate) use read_until::*;
@@ -7,6 +9,8 @@ pub(crate) use strings::*;
 
 mod chars;
 mod comment_whitespace;
+mod fs;
+mod glob;
 mod number;
 mod peek_until;
 mod read_until;
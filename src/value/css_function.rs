@@ -5,3 +5,82 @@ pub(crate) fn is_special_function(s: &str) -> bool {
         || s.starts_with("min(")
         || s.starts_with("max(")
 }
+
+/// Whether `s` is a `calc()`, `min()`, `max()` or `clamp()` expression
+/// whose first opening parenthesis is closed by its last character
+pub(crate) fn is_calculation(s: &str) -> bool {
+    if !["calc(", "min(", "max(", "clamp("]
+        .iter()
+        .any(|name| s.starts_with(name))
+    {
+        return false;
+    }
+    let inner = &s[s.find('(').unwrap() + 1..];
+    match inner.strip_suffix(')') {
+        Some(inner) => split_args(inner).is_some(),
+        None => false,
+    }
+}
+
+/// Whether `s` is a single number, optionally followed by a unit,
+/// e.g. `-1.5px`
+pub(crate) fn is_number(s: &str) -> bool {
+    let s = s.strip_prefix(|c| c == '-' || c == '+').unwrap_or(s);
+    let unit_start = s
+        .find(|c: char| !c.is_ascii_digit() && c != '.')
+        .unwrap_or_else(|| s.len());
+    let (num, unit) = s.split_at(unit_start);
+    num.chars().any(|c| c.is_ascii_digit())
+        && num.matches('.').count() <= 1
+        && !num.ends_with('.')
+        && (unit == "%" || unit.chars().all(|c| c.is_ascii_alphabetic()))
+}
+
+/// The arguments of a `calc()`, `min()`, `max()` or `clamp()` expression
+pub(crate) fn calc_args(calc: &str) -> Vec<&str> {
+    let inner = match calc.find('(') {
+        Some(idx) => &calc[idx + 1..],
+        None => return Vec::new(),
+    };
+    let inner = inner.strip_suffix(')').unwrap_or(inner);
+    split_args(inner).unwrap_or_else(|| vec![inner.trim()])
+}
+
+/// Split `s` at each comma outside of parentheses and strings, returning
+/// `None` if a closing parenthesis has no matching opening parenthesis
+fn split_args(s: &str) -> Option<Vec<&str>> {
+    let mut args = Vec::new();
+    let mut nesting = 0_usize;
+    let mut quote = None;
+    let mut escaped = false;
+    let mut start = 0;
+
+    for (idx, c) in s.char_indices() {
+        if let Some(q) = quote {
+            if escaped {
+                escaped = false;
+            } else if c == '\\' {
+                escaped = true;
+            } else if c == q {
+                quote = None;
+            }
+            continue;
+        }
+        match c {
+            '"' | '\'' => quote = Some(c),
+            '(' => nesting += 1,
+            ')' => nesting = nesting.checked_sub(1)?,
+            ',' if nesting == 0 => {
+                args.push(s[start..idx].trim());
+                start = idx + 1;
+            }
+            _ => {}
+        }
+    }
+
+    let last = s[start..].trim();
+    if !last.is_empty() || !args.is_empty() {
+        args.push(last);
+    }
+    Some(args)
+}
@@ -17,40 +17,97 @@ impl SassMap {
         SassMap(Vec::new())
     }
 
-    pub fn get(
-        self,
+    /// The index of the entry whose key is equal to `key`, using Sass
+    /// equality rather than structural equality, so e.g. `1in` and `96px`
+    /// refer to the same entry
+    fn index_of(
+        &self,
         key: &Value,
         span: Span,
         parser: &mut Parser<'_>,
-    ) -> SassResult<Option<Value>> {
-        for (k, v) in self.0 {
+    ) -> SassResult<Option<usize>> {
+        for (idx, (k, ..)) in self.0.iter().enumerate() {
             if ValueVisitor::new(parser, span)
                 .equal(
-                    HigherIntermediateValue::Literal(k),
+                    HigherIntermediateValue::Literal(k.clone()),
                     HigherIntermediateValue::Literal(key.clone()),
                 )?
                 .is_true()
             {
-                return Ok(Some(v));
+                return Ok(Some(idx));
             }
         }
         Ok(None)
     }
 
-    pub fn remove(&mut self, key: &Value) {
-        self.0.retain(|(ref k, ..)| k != key);
+    pub fn get(
+        &self,
+        key: &Value,
+        span: Span,
+        parser: &mut Parser<'_>,
+    ) -> SassResult<Option<Value>> {
+        Ok(self
+            .index_of(key, span, parser)?
+            .map(|idx| self.0[idx].1.clone()))
+    }
+
+    pub fn get_mut(
+        &mut self,
+        key: &Value,
+        span: Span,
+        parser: &mut Parser<'_>,
+    ) -> SassResult<Option<&mut Value>> {
+        Ok(match self.index_of(key, span, parser)? {
+            Some(idx) => Some(&mut self.0[idx].1),
+            None => None,
+        })
+    }
+
+    pub fn contains_key(
+        &self,
+        key: &Value,
+        span: Span,
+        parser: &mut Parser<'_>,
+    ) -> SassResult<bool> {
+        Ok(self.index_of(key, span, parser)?.is_some())
+    }
+
+    /// Remove the entry for `key`, returning its value if it existed
+    pub fn remove(
+        &mut self,
+        key: &Value,
+        span: Span,
+        parser: &mut Parser<'_>,
+    ) -> SassResult<Option<Value>> {
+        Ok(self
+            .index_of(key, span, parser)?
+            .map(|idx| self.0.remove(idx).1))
     }
 
-    pub fn merge(&mut self, other: SassMap) {
+    /// Insert every entry of `other`, replacing the values of keys which
+    /// already exist while keeping their original position and key
+    pub fn merge(&mut self, other: SassMap, span: Span, parser: &mut Parser<'_>) -> SassResult<()> {
         for (key, value) in other {
-            self.insert(key, value);
+            match self.get_mut(&key, span, parser)? {
+                Some(v) => *v = value,
+                None => self.0.push((key, value)),
+            }
         }
+        Ok(())
     }
 
     pub fn iter(&self) -> Iter<(Value, Value)> {
         self.0.iter()
     }
 
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
     pub fn keys(self) -> Vec<Value> {
         self.0.into_iter().map(|(k, ..)| k).collect()
     }
@@ -1,3 +1,5 @@
+use std::fmt;
+
 use peekmore::PeekMore;
 
 use codemap::{Span, Spanned};
@@ -23,7 +25,7 @@ mod map;
 mod number;
 mod sass_function;
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Clone, PartialEq, Eq)]
 pub(crate) enum Value {
     Important,
     True,
@@ -33,12 +35,53 @@ pub(crate) enum Value {
     List(Vec<Value>, ListSeparator, Brackets),
     Color(Box<Color>),
     String(String, QuoteKind),
+    /// A `calc()`, `min()` or `max()` expression which is emitted as-is,
+    /// e.g. `calc(1px + 2%)`
+    Calc(String),
     Map(SassMap),
-    ArgList(Vec<Spanned<Value>>),
+    /// Positional arguments, and the keyword arguments accessible through
+    /// `keywords()`
+    ArgList(Vec<Spanned<Value>>, SassMap),
     /// Returned by `get-function()`
     FunctionRef(SassFunction),
 }
 
+/// `{:?}` is what `#[derive(Debug)]` would give, while `{:#?}` is the tree
+/// given by `Value::pretty_debug`
+impl fmt::Debug for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            return f.write_str(&self.pretty_debug(0));
+        }
+
+        match self {
+            Value::Important => f.write_str("Important"),
+            Value::True => f.write_str("True"),
+            Value::False => f.write_str("False"),
+            Value::Null => f.write_str("Null"),
+            Value::Dimension(num, unit) => {
+                f.debug_tuple("Dimension").field(num).field(unit).finish()
+            }
+            Value::List(elems, sep, brackets) => f
+                .debug_tuple("List")
+                .field(elems)
+                .field(sep)
+                .field(brackets)
+                .finish(),
+            Value::Color(color) => f.debug_tuple("Color").field(color).finish(),
+            Value::String(s, quotes) => f.debug_tuple("String").field(s).field(quotes).finish(),
+            Value::Calc(calc) => f.debug_tuple("Calc").field(calc).finish(),
+            Value::Map(map) => f.debug_tuple("Map").field(map).finish(),
+            Value::ArgList(args, keywords) => f
+                .debug_tuple("ArgList")
+                .field(args)
+                .field(keywords)
+                .finish(),
+            Value::FunctionRef(func) => f.debug_tuple("FunctionRef").field(func).finish(),
+        }
+    }
+}
+
 fn visit_quoted_string(buf: &mut String, force_double_quote: bool, string: &str) {
     let mut has_single_quote = false;
     let mut has_double_quote = false;
@@ -155,6 +198,7 @@ impl Value {
                 )),
             },
             Value::Color(c) => Cow::owned(c.to_string()),
+            Value::Calc(calc) => Cow::owned(calc.clone()),
             Value::String(string, QuoteKind::None) => {
                 let mut after_newline = false;
                 let mut buf = String::with_capacity(string.len());
@@ -185,7 +229,7 @@ impl Value {
             Value::True => Cow::const_str("true"),
             Value::False => Cow::const_str("false"),
             Value::Null => Cow::const_str(""),
-            Value::ArgList(args) => Cow::owned(
+            Value::ArgList(args, ..) => Cow::owned(
                 args.iter()
                     .filter(|x| !x.is_null())
                     .map(|a| Ok(a.node.to_css_string(span)?))
@@ -221,6 +265,7 @@ impl Value {
             Value::Color(..) => "color",
             Value::String(..) | Value::Important => "string",
             Value::Dimension(..) => "number",
+            Value::Calc(..) => "calculation",
             Value::List(..) => "list",
             Value::FunctionRef(..) => "function",
             Value::ArgList(..) => "arglist",
@@ -233,6 +278,7 @@ impl Value {
     pub fn is_special_function(&self) -> bool {
         match self {
             Value::String(s, QuoteKind::None) => is_special_function(s),
+            Value::Calc(..) => true,
             _ => false,
         }
     }
@@ -257,10 +303,12 @@ impl Value {
                 Brackets::None => match sep {
                     ListSeparator::Space => v[0].inspect(span)?,
                     ListSeparator::Comma => Cow::owned(format!("({},)", v[0].inspect(span)?)),
+                    ListSeparator::Slash => Cow::owned(format!("({} /)", v[0].inspect(span)?)),
                 },
                 Brackets::Bracketed => match sep {
                     ListSeparator::Space => Cow::owned(format!("[{}]", v[0].inspect(span)?)),
                     ListSeparator::Comma => Cow::owned(format!("[{},]", v[0].inspect(span)?)),
+                    ListSeparator::Slash => Cow::owned(format!("[{} /]", v[0].inspect(span)?)),
                 },
             },
             Value::List(vals, sep, brackets) => Cow::owned(match brackets {
@@ -287,7 +335,7 @@ impl Value {
                     .join(", ")
             )),
             Value::Dimension(num, unit) => Cow::owned(format!("{}{}", num, unit)),
-            Value::ArgList(args) => Cow::owned(
+            Value::ArgList(args, ..) => Cow::owned(
                 args.iter()
                     .filter(|x| !x.is_null())
                     .map(|a| Ok(a.node.inspect(span)?))
@@ -298,15 +346,83 @@ impl Value {
             | Value::True
             | Value::False
             | Value::Color(..)
+            | Value::Calc(..)
             | Value::String(..) => self.to_css_string(span)?,
         })
     }
 
+    /// A tree-structured alternative to the derived `Debug` output, with each
+    /// element of a list, map or argument list on its own line
+    ///
+    /// `indent` is the depth at which this value is nested. This is what
+    /// `{:#?}` prints, and is only intended to be used while debugging
+    pub fn pretty_debug(&self, indent: usize) -> String {
+        let (name, children) = match self {
+            Value::Important => return "Important".to_owned(),
+            Value::True => return "True".to_owned(),
+            Value::False => return "False".to_owned(),
+            Value::Null => return "Null".to_owned(),
+            Value::Dimension(num, Unit::None) => return format!("Dimension({})", num),
+            Value::Dimension(num, unit) => return format!("Dimension({}, {})", num, unit),
+            Value::Color(color) => return format!("Color({})", color),
+            Value::String(s, QuoteKind::Quoted) => return format!("String({:?})", s),
+            Value::String(s, QuoteKind::None) => return format!("String({})", s),
+            Value::Calc(calc) => return format!("Calc({})", calc),
+            Value::FunctionRef(f) => return format!("FunctionRef({})", f.name()),
+            Value::List(elems, sep, brackets) => (
+                format!("List({:?}, {:?}", sep, brackets),
+                elems
+                    .iter()
+                    .map(|elem| elem.pretty_debug(indent + 1))
+                    .collect(),
+            ),
+            Value::Map(map) => (
+                "Map(".to_owned(),
+                map.iter()
+                    .map(|(key, value)| {
+                        format!(
+                            "{}: {}",
+                            key.pretty_debug(indent + 1),
+                            value.pretty_debug(indent + 1)
+                        )
+                    })
+                    .collect(),
+            ),
+            Value::ArgList(args, keywords) => {
+                let mut children: Vec<String> = args
+                    .iter()
+                    .map(|arg| arg.node.pretty_debug(indent + 1))
+                    .collect();
+                children.push(format!(
+                    "keywords: {}",
+                    Value::Map(keywords.clone()).pretty_debug(indent + 1)
+                ));
+                ("ArgList(".to_owned(), children)
+            }
+        };
+
+        if children.is_empty() {
+            return format!("{})", name);
+        }
+
+        let padding = "  ".repeat(indent + 1);
+        let separator = if name.ends_with('(') { "" } else { "," };
+
+        format!(
+            "{}{}\n{}{}\n{})",
+            name,
+            separator,
+            padding,
+            children.join(&format!(",\n{}", padding)),
+            "  ".repeat(indent)
+        )
+    }
+
     pub fn as_list(self) -> Vec<Value> {
         match self {
             Value::List(v, ..) => v,
             Value::Map(m) => m.as_list(),
-            Value::ArgList(v) => v.into_iter().map(|val| val.node).collect(),
+            Value::ArgList(v, ..) => v.into_iter().map(|val| val.node).collect(),
             v => vec![v],
         }
     }
@@ -339,14 +455,21 @@ impl Value {
             map: parser.map,
             path: parser.path,
             scopes: parser.scopes,
-            global_scope: parser.global_scope,
+            modules: parser.modules,
+            module: parser.module,
             super_selectors: parser.super_selectors,
             span_before: parser.span_before,
             content: parser.content,
+            call_stack: parser.call_stack,
+            options: parser.options,
+            dependencies: parser.dependencies,
+            import_stack: parser.import_stack,
+            imported: parser.imported,
             flags: parser.flags,
             at_root: parser.at_root,
             at_root_has_selector: parser.at_root_has_selector,
             extender: parser.extender,
+            media_queries: parser.media_queries,
         }
         .parse_selector(allows_parent, true, String::new())
     }
@@ -380,6 +503,7 @@ impl Value {
                             }
                         }
                     }
+                    ListSeparator::Slash => return Ok(None),
                 }
 
                 result.join(sep.as_str())
@@ -388,3 +512,85 @@ impl Value {
         }))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use codemap::{CodeMap, Spanned};
+
+    use super::{Number, SassMap, Value};
+    use crate::{
+        common::{Brackets, ListSeparator, QuoteKind},
+        unit::Unit,
+    };
+
+    fn dimension(num: i32, unit: Unit) -> Value {
+        Value::Dimension(Number::from(num), unit)
+    }
+
+    fn string(s: &str, quotes: QuoteKind) -> Value {
+        Value::String(s.to_owned(), quotes)
+    }
+
+    #[test]
+    fn pretty_debug_nested_list() {
+        let value = Value::List(
+            vec![
+                dimension(1, Unit::Px),
+                Value::List(
+                    vec![string("a", QuoteKind::Quoted), string("b", QuoteKind::None)],
+                    ListSeparator::Space,
+                    Brackets::Bracketed,
+                ),
+                Value::List(Vec::new(), ListSeparator::Space, Brackets::None),
+            ],
+            ListSeparator::Comma,
+            Brackets::None,
+        );
+        assert_eq!(
+            "List(Comma, None,\n  Dimension(1, px),\n  List(Space, Bracketed,\n    String(\"a\"),\n    String(b)\n  ),\n  List(Space, None)\n)",
+            value.pretty_debug(0)
+        );
+    }
+
+    #[test]
+    fn pretty_debug_map() {
+        let mut inner = SassMap::new();
+        inner.insert(string("c", QuoteKind::None), Value::Null);
+        let mut map = SassMap::new();
+        map.insert(string("a", QuoteKind::Quoted), dimension(1, Unit::None));
+        map.insert(string("b", QuoteKind::Quoted), Value::Map(inner));
+        assert_eq!(
+            "Map(\n  String(\"a\"): Dimension(1),\n  String(\"b\"): Map(\n    String(c): Null\n  )\n)",
+            Value::Map(map).pretty_debug(0)
+        );
+    }
+
+    #[test]
+    fn pretty_debug_arglist() {
+        let span = CodeMap::new().add_file("".to_owned(), String::new()).span;
+        let mut keywords = SassMap::new();
+        keywords.insert(string("b", QuoteKind::Quoted), Value::True);
+        let value = Value::ArgList(
+            vec![Spanned {
+                node: dimension(1, Unit::Em),
+                span,
+            }],
+            keywords,
+        );
+        assert_eq!(
+            "ArgList(\n  Dimension(1, em),\n  keywords: Map(\n    String(\"b\"): True\n  )\n)",
+            value.pretty_debug(0)
+        );
+    }
+
+    #[test]
+    fn alternate_debug_is_pretty_debug() {
+        let value = Value::List(
+            vec![Value::True, Value::False],
+            ListSeparator::Comma,
+            Brackets::None,
+        );
+        assert_eq!(value.pretty_debug(0), format!("{:#?}", value));
+        assert_eq!("List([True, False], Comma, None)", format!("{:?}", value));
+    }
+}
@@ -8,12 +8,23 @@
 //!
 //! Builtin functions are those that have been implemented in rust and are
 //! in the global scope.
+//!
+//! Plain CSS functions are those returned by `get-function($name, $css: true)`,
+//! and are emitted as-is when called.
 
 use std::fmt;
 
+use codemap::Spanned;
+
 use crate::{
-    args::CallArgs, atrule::Function, builtin::Builtin, common::Identifier, error::SassResult,
-    parse::Parser, value::Value,
+    args::CallArgs,
+    atrule::Function,
+    builtin::Builtin,
+    common::{Identifier, QuoteKind},
+    error::SassResult,
+    parse::Parser,
+    stack,
+    value::Value,
 };
 
 /// A Sass function
@@ -26,15 +37,18 @@ use crate::{
 pub(crate) enum SassFunction {
     Builtin(Builtin, Identifier),
     UserDefined(Box<Function>, Identifier),
+    /// A function with no Sass definition, whose name is kept verbatim
+    Plain(String),
 }
 
 impl SassFunction {
     /// Get the name of the function referenced
     ///
     /// Used mainly in debugging and `inspect()`
-    pub fn name(&self) -> &Identifier {
+    pub fn name(&self) -> &str {
         match self {
-            Self::Builtin(_, name) | Self::UserDefined(_, name) => name,
+            Self::Builtin(_, name) | Self::UserDefined(_, name) => name.as_str(),
+            Self::Plain(name) => name,
         }
     }
 
@@ -45,13 +59,26 @@ impl SassFunction {
         match &self {
             Self::Builtin(..) => "Builtin",
             Self::UserDefined(..) => "UserDefined",
+            Self::Plain(..) => "Plain",
         }
     }
 
     pub fn call(self, args: CallArgs, parser: &mut Parser<'_>) -> SassResult<Value> {
         match self {
             Self::Builtin(f, ..) => f.0(args, parser),
-            Self::UserDefined(f, ..) => parser.eval_function(*f, args),
+            Self::UserDefined(f, name) => {
+                parser.push_call_stack(Spanned {
+                    node: format!("{}()", name),
+                    span: args.span(),
+                })?;
+                let val = stack::grow(|| parser.eval_function(*f, args));
+                parser.call_stack.pop();
+                val
+            }
+            Self::Plain(name) => {
+                let args = args.to_css_string(parser)?.node;
+                Ok(Value::String(format!("{}{}", name, args), QuoteKind::None))
+            }
         }
     }
 }
@@ -67,15 +94,11 @@ impl fmt::Debug for SassFunction {
 
 impl PartialEq for SassFunction {
     fn eq(&self, other: &Self) -> bool {
-        match self {
-            Self::UserDefined(f, ..) => match other {
-                Self::UserDefined(f2, ..) => f == f2,
-                Self::Builtin(..) => false,
-            },
-            Self::Builtin(f, ..) => match other {
-                Self::UserDefined(..) => false,
-                Self::Builtin(f2, ..) => f == f2,
-            },
+        match (self, other) {
+            (Self::UserDefined(f, ..), Self::UserDefined(f2, ..)) => f == f2,
+            (Self::Builtin(f, ..), Self::Builtin(f2, ..)) => f == f2,
+            (Self::Plain(name), Self::Plain(name2)) => name == name2,
+            _ => false,
         }
     }
 }
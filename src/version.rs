@@ -0,0 +1,54 @@
+//! # Version
+//!
+//! The version of grass, for tools which embed it and need to report or
+//! compare it.
+use std::fmt::{self, Display};
+
+/// The version of grass, e.g. `"0.10.0"`
+pub const VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// A `major.minor.patch` version number
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Version {
+    pub major: u32,
+    pub minor: u32,
+    pub patch: u32,
+}
+
+impl Version {
+    /// The version of grass being used
+    #[must_use]
+    #[inline]
+    pub fn current() -> Self {
+        // `CARGO_PKG_VERSION` is always a valid semver version
+        Version::parse(VERSION).unwrap()
+    }
+
+    /// Parse a version of the form `major.minor.patch`. Any pre-release or
+    /// build metadata, e.g. `-beta.1` or `+abc`, is ignored
+    #[must_use]
+    pub fn parse(s: &str) -> Option<Self> {
+        let core = s.split(|c| c == '-' || c == '+').next()?;
+        let mut parts = core.split('.').map(str::parse::<u32>);
+
+        let major = parts.next()?.ok()?;
+        let minor = parts.next()?.ok()?;
+        let patch = parts.next()?.ok()?;
+
+        if parts.next().is_some() {
+            return None;
+        }
+
+        Some(Version {
+            major,
+            minor,
+            patch,
+        })
+    }
+}
+
+impl Display for Version {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+    }
+}
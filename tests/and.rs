@@ -40,6 +40,31 @@ test!(
     "a {\n  color: 1 and 2 and 3;\n}\n",
     "a {\n  color: 3;\n}\n"
 );
+test!(
+    null_and_two_is_null,
+    "a {\n  color: inspect(null and 2);\n}\n",
+    "a {\n  color: null;\n}\n"
+);
+test!(
+    zero_and_two,
+    "a {\n  color: 0 and 2;\n}\n",
+    "a {\n  color: 2;\n}\n"
+);
+test!(
+    false_and_two,
+    "a {\n  color: false and 2;\n}\n",
+    "a {\n  color: false;\n}\n"
+);
+test!(
+    one_and_false,
+    "a {\n  color: 1 and false;\n}\n",
+    "a {\n  color: false;\n}\n"
+);
+test!(
+    empty_string_and_two,
+    "a {\n  color: \"\" and 2;\n}\n",
+    "a {\n  color: 2;\n}\n"
+);
 test!(
     part_of_binop,
     "a {\n  color: 1 - and;\n}\n",
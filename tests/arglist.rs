@@ -30,3 +30,33 @@ test!(
     }",
     "a {\n  width: 180px;\n}\n"
 );
+test!(
+    arglist_collects_extra_positional_args,
+    "@use \"sass:meta\";\n@mixin foo($a, $args...) {\n    color: $args;\n    color: meta.inspect(meta.keywords($args));\n}\na {\n    @include foo(1, 2, 3);\n}\n",
+    "a {\n  color: 2, 3;\n  color: ();\n}\n"
+);
+test!(
+    arglist_collects_extra_keyword_args,
+    "@use \"sass:meta\";\n@mixin foo($a, $args...) {\n    color: length($args);\n    color: meta.inspect(meta.keywords($args));\n}\na {\n    @include foo(1, $b: 2, $c: 3);\n}\n",
+    "a {\n  color: 0;\n  color: (b: 2, c: 3);\n}\n"
+);
+test!(
+    arglist_without_extra_args_is_empty,
+    "@use \"sass:meta\";\n@function foo($a, $args...) {\n    @return length($args) meta.inspect(meta.keywords($args));\n}\na {\n    color: foo(1);\n}\n",
+    "a {\n  color: 0 ();\n}\n"
+);
+test!(
+    arglist_collects_positional_and_keyword_args,
+    "@use \"sass:meta\";\n@function foo($a, $args...) {\n    @return $args meta.inspect(meta.keywords($args));\n}\na {\n    color: foo(1, 2, $c: 3);\n}\n",
+    "a {\n  color: 2 (c: 3);\n}\n"
+);
+test!(
+    arglist_passed_on_with_splat_keeps_keywords,
+    "@use \"sass:meta\";\n@mixin foo($a, $args...) {\n    color: $a $args;\n    color: meta.inspect(meta.keywords($args));\n}\n@mixin bar($args...) {\n    @include foo($args...);\n}\na {\n    @include bar(1, 2, $c: 3);\n}\n",
+    "a {\n  color: 1 2;\n  color: (c: 3);\n}\n"
+);
+test!(
+    list_splat_followed_by_map_splat,
+    "@use \"sass:meta\";\n@function foo($a, $args...) {\n    @return $a $args meta.inspect(meta.keywords($args));\n}\n$list: 1 2;\n$map: (c: 3);\na {\n    color: foo($list..., $map...);\n}\n",
+    "a {\n  color: 1 2 (c: 3);\n}\n"
+);
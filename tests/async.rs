@@ -0,0 +1,102 @@
+#![cfg(test)]
+use std::{
+    future::Future,
+    io::Write,
+    pin::Pin,
+    sync::Arc,
+    task::{Context, Poll, Wake},
+    thread::{self, Thread},
+};
+
+use tempfile::Builder;
+
+struct ThreadWaker(Thread);
+
+impl Wake for ThreadWaker {
+    fn wake(self: Arc<Self>) {
+        self.0.unpark();
+    }
+}
+
+/// A minimal executor, so that these tests do not depend on any particular
+/// async runtime
+fn block_on<F: Future>(mut future: F) -> F::Output {
+    let waker = Arc::new(ThreadWaker(thread::current())).into();
+    let mut cx = Context::from_waker(&waker);
+    // SAFETY: `future` is never moved after being pinned
+    let mut future = unsafe { Pin::new_unchecked(&mut future) };
+    loop {
+        match future.as_mut().poll(&mut cx) {
+            Poll::Ready(output) => return output,
+            Poll::Pending => thread::park(),
+        }
+    }
+}
+
+#[test]
+fn from_path_async() {
+    let mut f = Builder::new()
+        .rand_bytes(0)
+        .prefix("")
+        .suffix("from_path_async.scss")
+        .tempfile_in("")
+        .unwrap();
+    write!(f, "a {{\n  color: red;\n}}\n").unwrap();
+    assert_eq!(
+        "a {\n  color: red;\n}\n",
+        block_on(grass::from_path_async(
+            "from_path_async.scss",
+            &grass::Options::default()
+        ))
+        .unwrap()
+    );
+}
+
+#[test]
+fn from_path_async_error() {
+    let mut f = Builder::new()
+        .rand_bytes(0)
+        .prefix("")
+        .suffix("from_path_async_error.scss")
+        .tempfile_in("")
+        .unwrap();
+    write!(f, "a {{\n  color: $foo;\n}}\n").unwrap();
+    match block_on(grass::from_path_async(
+        "from_path_async_error.scss",
+        &grass::Options::default(),
+    )) {
+        Ok(..) => panic!("did not fail"),
+        Err(e) => assert!(e.to_string().starts_with("Error: Undefined variable.\n")),
+    }
+}
+
+#[test]
+fn from_path_async_file_not_found() {
+    assert!(block_on(grass::from_path_async(
+        "from_path_async_does_not_exist.scss",
+        &grass::Options::default()
+    ))
+    .is_err());
+}
+
+#[test]
+fn from_path_async_infinitely_recursive_mixin() {
+    let mut f = Builder::new()
+        .rand_bytes(0)
+        .prefix("")
+        .suffix("from_path_async_infinitely_recursive_mixin.scss")
+        .tempfile_in("")
+        .unwrap();
+    write!(
+        f,
+        "@mixin foo {{\n  @include foo;\n}}\n\na {{\n  @include foo;\n}}\n"
+    )
+    .unwrap();
+    match block_on(grass::from_path_async(
+        "from_path_async_infinitely_recursive_mixin.scss",
+        &grass::Options::default(),
+    )) {
+        Ok(..) => panic!("did not fail"),
+        Err(e) => assert!(e.to_string().starts_with("Error: stack overflow\n")),
+    }
+}
@@ -18,3 +18,48 @@ test!(
     "@charset \"foo\";\na {\n  color: red;\n}\n",
     "a {\n  color: red;\n}\n"
 );
+
+#[test]
+fn charset_never() {
+    let input = "a {\n  color: 🦆;\n}\n";
+    assert_eq!(
+        "a {\n  color: 🦆;\n}\n",
+        &grass::from_string(
+            input.to_string(),
+            &grass::Options::default().charset(grass::CharsetBehavior::Never)
+        )
+        .expect(input)
+    );
+}
+
+#[test]
+fn charset_always_ascii() {
+    let input = "a {\n  color: red;\n}\n";
+    assert_eq!(
+        "@charset \"UTF-8\";\na {\n  color: red;\n}\n",
+        &grass::from_string(
+            input.to_string(),
+            &grass::Options::default().charset(grass::CharsetBehavior::Always)
+        )
+        .expect(input)
+    );
+}
+
+#[test]
+fn charset_always_empty_output() {
+    let input = "$a: red;";
+    assert_eq!(
+        "",
+        &grass::from_string(
+            input.to_string(),
+            &grass::Options::default().charset(grass::CharsetBehavior::Always)
+        )
+        .expect(input)
+    );
+}
+
+test!(
+    non_ascii_in_later_rule,
+    "a {\n  color: red;\n}\n\nb {\n  content: \"é\";\n}\n",
+    "@charset \"UTF-8\";\na {\n  color: red;\n}\n\nb {\n  content: \"é\";\n}\n"
+);
@@ -0,0 +1,239 @@
+#![cfg(test)]
+
+#[macro_use]
+mod macros;
+
+/// Calls which should produce the same value through the `sass:color` module
+/// as through the equivalent global function, as
+/// `(module function, global function, arguments)`
+const GLOBAL_ALIASES: &[(&str, &str, &str)] = &[
+    ("red", "red", "#123456"),
+    ("green", "green", "#123456"),
+    ("blue", "blue", "#123456"),
+    ("mix", "mix", "red, blue, 25%"),
+    ("hue", "hue", "#123456"),
+    ("saturation", "saturation", "#123456"),
+    ("lightness", "lightness", "#123456"),
+    ("grayscale", "grayscale", "#123456"),
+    ("complement", "complement", "#123456"),
+    ("invert", "invert", "#123456"),
+    ("alpha", "alpha", "rgba(1, 2, 3, 0.4)"),
+    ("adjust", "adjust-color", "red, $blue: 10"),
+    ("change", "change-color", "red, $lightness: 10%"),
+    ("scale", "scale-color", "red, $alpha: -50%"),
+    ("ie-hex-str", "ie-hex-str", "#123456"),
+];
+
+/// Colors which should survive a round trip through their HWB channels
+const HWB_ROUND_TRIP: &[&str] = &[
+    "red",
+    "white",
+    "black",
+    "#6b717f",
+    "#123456",
+    "#bada55",
+    "rgba(12, 200, 99, 0.5)",
+];
+
+fn compile(input: String) -> String {
+    grass::from_string(input.clone(), &grass::Options::default())
+        .unwrap_or_else(|e| panic!("failed to compile {:?}: {}", input, e))
+}
+
+#[test]
+fn module_functions_match_global_functions() {
+    for (module_fn, global_fn, args) in GLOBAL_ALIASES {
+        let namespaced = compile(format!(
+            "@use \"sass:color\";\na {{\n  color: inspect(color.{}({}));\n}}\n",
+            module_fn, args
+        ));
+        let global = compile(format!(
+            "a {{\n  color: inspect({}({}));\n}}\n",
+            global_fn, args
+        ));
+        assert_eq!(global, namespaced, "color.{}({})", module_fn, args);
+    }
+}
+
+#[test]
+fn hwb_round_trip() {
+    for color in HWB_ROUND_TRIP {
+        let output = compile(format!(
+            "@use \"sass:color\";\n$c: {};\na {{\n  color: color.hwb(color.hue($c), color.whiteness($c), color.blackness($c), color.alpha($c));\n}}\n",
+            color
+        ));
+        let expected = compile(format!("a {{\n  color: {};\n}}\n", color));
+        assert_eq!(expected, output, "{}", color);
+    }
+}
+
+test!(
+    whiteness,
+    "@use \"sass:color\";\na {\n  color: color.whiteness(#6b717f);\n}\n",
+    "a {\n  color: 41.9607843137%;\n}\n"
+);
+test!(
+    blackness,
+    "@use \"sass:color\";\na {\n  color: color.blackness(#6b717f);\n}\n",
+    "a {\n  color: 50.1960784314%;\n}\n"
+);
+test!(
+    whiteness_of_white,
+    "@use \"sass:color\";\na {\n  color: color.whiteness(white);\n}\n",
+    "a {\n  color: 100%;\n}\n"
+);
+test!(
+    blackness_of_black,
+    "@use \"sass:color\";\na {\n  color: color.blackness(black);\n}\n",
+    "a {\n  color: 100%;\n}\n"
+);
+test!(
+    hwb_pure_hue,
+    "@use \"sass:color\";\na {\n  color: color.hwb(0, 0%, 0%);\n}\n",
+    "a {\n  color: red;\n}\n"
+);
+test!(
+    hwb_whiteness_and_blackness_over_100_percent_are_normalized,
+    "@use \"sass:color\";\na {\n  color: color.hwb(120, 40%, 80%);\n}\n",
+    "a {\n  color: #555555;\n}\n"
+);
+test!(
+    hwb_with_alpha,
+    "@use \"sass:color\";\na {\n  color: color.hwb(210, 0%, 60%, 0.5);\n}\n",
+    "a {\n  color: rgba(0, 51, 102, 0.5);\n}\n"
+);
+error!(
+    whiteness_of_non_color,
+    "@use \"sass:color\";\na {\n  color: color.whiteness(1);\n}\n",
+    "Error: $color: 1 is not a color."
+);
+error!(
+    hwb_whiteness_without_percent,
+    "@use \"sass:color\";\na {\n  color: color.hwb(0, 10, 0%);\n}\n",
+    "Error: $whiteness: Expected 10 to have unit \"%\"."
+);
+error!(
+    hwb_blackness_out_of_range,
+    "@use \"sass:color\";\na {\n  color: color.hwb(0, 0%, 101%);\n}\n",
+    "Error: $blackness: Expected 101% to be within 0% and 100%."
+);
+test!(
+    to_space_srgb_is_unchanged,
+    "@use \"sass:color\";\na {\n  color: color.to-space(#123456, \"srgb\");\n}\n",
+    "a {\n  color: #123456;\n}\n"
+);
+test!(
+    to_space_rgb_unquoted,
+    "@use \"sass:color\";\na {\n  color: color.to-space(red, rgb);\n}\n",
+    "a {\n  color: red;\n}\n"
+);
+test!(
+    to_space_is_case_insensitive,
+    "@use \"sass:color\";\na {\n  color: color.to-space(rgba(1, 2, 3, 0.5), \"HSL\");\n}\n",
+    "a {\n  color: rgba(1, 2, 3, 0.5);\n}\n"
+);
+error!(
+    to_space_unsupported_space,
+    "@use \"sass:color\";\na {\n  color: color.to-space(red, \"oklch\");\n}\n",
+    "Error: $space: The \"oklch\" color space isn't supported yet."
+);
+error!(
+    to_space_unknown_space,
+    "@use \"sass:color\";\na {\n  color: color.to-space(red, \"foo\");\n}\n",
+    "Error: $space: Unknown color space \"foo\"."
+);
+error!(
+    to_space_non_color,
+    "@use \"sass:color\";\na {\n  color: color.to-space(1, \"srgb\");\n}\n",
+    "Error: $color: 1 is not a color."
+);
+test!(
+    adjust_whiteness,
+    "@use \"sass:color\";\na {\n  color: color.adjust(red, $whiteness: 20%);\n}\n",
+    "a {\n  color: #ff3333;\n}\n"
+);
+test!(
+    adjust_blackness,
+    "@use \"sass:color\";\na {\n  color: color.adjust(red, $blackness: 20%);\n}\n",
+    "a {\n  color: #cc0000;\n}\n"
+);
+test!(
+    adjust_hue_with_hwb,
+    "@use \"sass:color\";\na {\n  color: color.adjust(#ff3333, $hue: 120deg, $whiteness: -20%);\n}\n",
+    "a {\n  color: lime;\n}\n"
+);
+test!(
+    adjust_hwb_over_100_percent_is_normalized,
+    "@use \"sass:color\";\na {\n  color: color.adjust(red, $whiteness: 80%, $blackness: 60%);\n}\n",
+    "a {\n  color: #929292;\n}\n"
+);
+test!(
+    adjust_hwb_with_alpha,
+    "@use \"sass:color\";\na {\n  color: color.adjust(red, $whiteness: 20%, $alpha: -0.5);\n}\n",
+    "a {\n  color: rgba(255, 51, 51, 0.5);\n}\n"
+);
+test!(
+    adjust_whiteness_global_function,
+    "a {\n  color: adjust-color(red, $whiteness: 20%);\n}\n",
+    "a {\n  color: #ff3333;\n}\n"
+);
+error!(
+    adjust_hsl_and_hwb,
+    "@use \"sass:color\";\na {\n  color: color.adjust(red, $lightness: 10%, $whiteness: 10%);\n}\n",
+    "Error: HSL parameters may not be passed along with HWB parameters."
+);
+error!(
+    adjust_rgb_and_hwb,
+    "@use \"sass:color\";\na {\n  color: color.adjust(red, $red: 10, $blackness: 10%);\n}\n",
+    "Error: RGB parameters may not be passed along with HWB parameters."
+);
+error!(
+    adjust_rgb_and_hue,
+    "@use \"sass:color\";\na {\n  color: color.adjust(red, $red: 10, $hue: 10);\n}\n",
+    "Error: RGB parameters may not be passed along with HSL parameters."
+);
+error!(
+    adjust_whiteness_without_percent,
+    "@use \"sass:color\";\na {\n  color: color.adjust(red, $whiteness: 10);\n}\n",
+    "Error: $whiteness: Expected 10 to have unit \"%\"."
+);
+error!(
+    adjust_blackness_out_of_range,
+    "@use \"sass:color\";\na {\n  color: color.adjust(red, $blackness: -101%);\n}\n",
+    "Error: $blackness: Expected -101% to be within -100% and 100%."
+);
+test!(
+    mix_srgb_method,
+    "@use \"sass:color\";\na {\n  color: color.mix(red, blue, $method: srgb);\n}\n",
+    "a {\n  color: purple;\n}\n"
+);
+test!(
+    mix_srgb_method_positional_with_weight,
+    "@use \"sass:color\";\na {\n  color: color.mix(red, blue, 25%, \"srgb\");\n}\n",
+    "a {\n  color: #4000bf;\n}\n"
+);
+test!(
+    mix_srgb_method_premultiplies_alpha,
+    "@use \"sass:color\";\na {\n  color: color.mix(rgba(255, 0, 0, 0.5), blue, $method: srgb);\n}\n",
+    "a {\n  color: rgba(85, 0, 170, 0.75);\n}\n"
+);
+test!(
+    mix_without_method_uses_legacy_weighting,
+    "@use \"sass:color\";\na {\n  color: color.mix(rgba(255, 0, 0, 0.5), blue);\n}\n",
+    "a {\n  color: rgba(64, 0, 191, 0.75);\n}\n"
+);
+error!(
+    mix_unsupported_method,
+    "@use \"sass:color\";\na {\n  color: color.mix(red, blue, $method: oklch);\n}\n",
+    "Error: $method: The \"oklch\" color space isn't supported yet."
+);
+error!(
+    mix_unknown_method,
+    "@use \"sass:color\";\na {\n  color: color.mix(red, blue, $method: foo);\n}\n",
+    "Error: $method: Unknown color space \"foo\"."
+);
+error!(
+    mix_method_not_string,
+    "@use \"sass:color\";\na {\n  color: color.mix(red, blue, $method: 1);\n}\n",
+    "Error: $method: 1 is not a string."
+);
@@ -262,6 +262,16 @@ test!(
     "a {\n  color: hue(rgb(1, 1, 1));\n}\n",
     "a {\n  color: 0deg;\n}\n"
 );
+test!(
+    hue_is_not_rounded,
+    "a {\n  color: hue(#bada55);\n}\n",
+    "a {\n  color: 74.4360902256deg;\n}\n"
+);
+test!(
+    hue_red_greatest_and_blue_greater_than_green,
+    "a {\n  color: hue(#ff0080);\n}\n",
+    "a {\n  color: 329.8823529412deg;\n}\n"
+);
 test!(
     saturation,
     "a {\n  color: saturation(hsl(193, 67%, 28%));\n}\n",
@@ -623,3 +633,63 @@ test!(
     "a {\n  color: blue(rgba(1.5, 1.5, 1.5, 1));\n}\n",
     "a {\n  color: 2;\n}\n"
 );
+test!(
+    computed_color_uses_six_digit_hex,
+    "a {\n  color: rgb(170, 187, 204);\n}\n",
+    "a {\n  color: #aabbcc;\n}\n"
+);
+test!(
+    computed_color_uses_name_even_if_longer_than_hex,
+    "a {\n  color: rgb(250, 250, 210);\n}\n",
+    "a {\n  color: lightgoldenrodyellow;\n}\n"
+);
+test!(
+    computed_translucent_color_uses_rgba,
+    "a {\n  color: rgba(#f00, 0.5);\n}\n",
+    "a {\n  color: rgba(255, 0, 0, 0.5);\n}\n"
+);
+test!(
+    literal_color_keeps_its_representation,
+    "a {\n  color: #f00;\n  color: #FF0000;\n  color: black;\n}\n",
+    "a {\n  color: #f00;\n  color: #FF0000;\n  color: black;\n}\n"
+);
+test!(
+    transparent_is_preserved,
+    "a {\n  color: transparent;\n}\n",
+    "a {\n  color: transparent;\n}\n"
+);
+test!(
+    transparent_is_a_color,
+    "a {\n  color: type-of(transparent);\n}\n",
+    "a {\n  color: color;\n}\n"
+);
+test!(
+    darken_transparent,
+    "a {\n  color: darken(transparent, 10%);\n}\n",
+    "a {\n  color: rgba(0, 0, 0, 0);\n}\n"
+);
+test!(
+    lighten_transparent,
+    "a {\n  color: lighten(transparent, 10%);\n}\n",
+    "a {\n  color: rgba(26, 26, 26, 0);\n}\n"
+);
+test!(
+    current_color_is_preserved,
+    "a {\n  color: currentColor;\n  color: type-of(currentColor);\n}\n",
+    "a {\n  color: currentColor;\n  color: string;\n}\n"
+);
+test!(
+    transparent_equals_rgba,
+    "a {\n  color: transparent == rgba(0, 0, 0, 0);\n}\n",
+    "a {\n  color: true;\n}\n"
+);
+test!(
+    colors_equal_regardless_of_representation,
+    "a {\n  color: red == #f00;\n  color: red == rgb(255, 0, 0);\n  color: hsl(0, 100%, 50%) == red;\n  color: red != #f00;\n}\n",
+    "a {\n  color: true;\n  color: true;\n  color: true;\n  color: false;\n}\n"
+);
+test!(
+    colors_with_different_alpha_are_not_equal,
+    "a {\n  color: red == rgba(255, 0, 0, 0.5);\n}\n",
+    "a {\n  color: false;\n}\n"
+);
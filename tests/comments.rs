@@ -63,3 +63,23 @@ test!(
     "$a: foo;/* interpolation #{1 + 1} in #{$a} comments */",
     "/* interpolation 2 in foo comments */\n"
 );
+test!(
+    multiline_comment_between_property_and_colon,
+    "a {\n  color /* foo */ : red;\n}\n",
+    "a {\n  color: red;\n}\n"
+);
+test!(
+    silent_comment_between_property_and_colon,
+    "a {\n  color // foo\n  : red;\n}\n",
+    "a {\n  color: red;\n}\n"
+);
+test!(
+    multiline_comment_between_value_tokens,
+    "a {\n  margin: 1px /* foo */ 2px;\n}\n",
+    "a {\n  margin: 1px 2px;\n}\n"
+);
+test!(
+    multiline_comment_inside_function_args,
+    "a {\n  color: rgba(1, /* foo */ 2, 3, 0.5);\n}\n",
+    "a {\n  color: rgba(1, 2, 3, 0.5);\n}\n"
+);
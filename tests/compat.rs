@@ -0,0 +1,61 @@
+//! Common real-world patterns, with the output of Dart Sass for each
+//!
+//! Unlike the sass-spec tests, these are whole snippets as they might appear
+//! in a project, and serve as a regression suite for them.
+#![cfg(test)]
+
+#[macro_use]
+mod macros;
+
+test!(
+    mixin_with_content_block,
+    "@mixin hover {\n  &:hover {\n    @content;\n  }\n}\n.button {\n  color: red;\n  @include hover {\n    color: blue;\n  }\n}\n",
+    ".button {\n  color: red;\n}\n.button:hover {\n  color: blue;\n}\n"
+);
+test!(
+    mixin_with_arguments_and_content_block,
+    "@mixin breakpoint($width) {\n  @media (min-width: $width) {\n    @content;\n  }\n}\n.container {\n  padding: 0;\n  @include breakpoint(768px) {\n    padding: 0 15px;\n  }\n}\n",
+    ".container {\n  padding: 0;\n}\n@media (min-width: 768px) {\n  .container {\n    padding: 0 15px;\n  }\n}\n"
+);
+test!(
+    extend_placeholder,
+    "%message {\n  border: 1px solid #ccc;\n  padding: 10px;\n}\n.success {\n  @extend %message;\n  border-color: green;\n}\n.error {\n  @extend %message;\n  border-color: red;\n}\n",
+    ".error, .success {\n  border: 1px solid #ccc;\n  padding: 10px;\n}\n\n.success {\n  border-color: green;\n}\n\n.error {\n  border-color: red;\n}\n"
+);
+test!(
+    extend_class,
+    ".btn {\n  padding: 4px;\n}\n.btn-primary {\n  @extend .btn;\n  color: blue;\n}\n",
+    ".btn, .btn-primary {\n  padding: 4px;\n}\n\n.btn-primary {\n  color: blue;\n}\n"
+);
+test!(
+    each_over_map,
+    "$theme-colors: (\"primary\": blue, \"danger\": red);\n@each $name, $color in $theme-colors {\n  .text-#{$name} {\n    color: $color !important;\n  }\n}\n",
+    ".text-primary {\n  color: blue !important;\n}\n\n.text-danger {\n  color: red !important;\n}\n"
+);
+test!(
+    media_inside_style_rule,
+    ".sidebar {\n  width: 300px;\n  @media screen and (orientation: landscape) {\n    width: 500px;\n  }\n}\n",
+    ".sidebar {\n  width: 300px;\n}\n@media screen and (orientation: landscape) {\n  .sidebar {\n    width: 500px;\n  }\n}\n"
+);
+test!(
+    #[ignore = "nested media queries are not yet merged"]
+    nested_media,
+    ".sidebar {\n  width: 300px;\n  @media screen and (orientation: landscape) {\n    width: 500px;\n    @media (min-width: 800px) {\n      width: 600px;\n    }\n  }\n}\n",
+    ".sidebar {\n  width: 300px;\n}\n@media screen and (orientation: landscape) {\n  .sidebar {\n    width: 500px;\n  }\n}\n@media screen and (orientation: landscape) and (min-width: 800px) {\n  .sidebar {\n    width: 600px;\n  }\n}\n"
+);
+test!(
+    color_adjust,
+    "@use \"sass:color\";\n.a {\n  color: color.adjust(#6b717f, $red: 15);\n  background: color.adjust(#d2e1dd, $red: -10, $blue: 10);\n  border-color: color.adjust(#998099, $lightness: -30%, $alpha: -0.4);\n}\n",
+    ".a {\n  color: #7a717f;\n  background: #c8e1e7;\n  border-color: rgba(71, 57, 71, 0.6);\n}\n"
+);
+test!(
+    calc_with_interpolated_variable,
+    "$gutter: 20px;\n.col {\n  width: calc(100% - #{$gutter * 2});\n}\n",
+    ".col {\n  width: calc(100% - 40px);\n}\n"
+);
+test!(
+    #[ignore = "variables in calc() are not yet evaluated"]
+    calc_with_variable,
+    "$gutter: 20px;\n.col {\n  width: calc(100% - $gutter * 2);\n}\n",
+    ".col {\n  width: calc(100% - 40px);\n}\n"
+);
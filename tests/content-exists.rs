@@ -55,3 +55,33 @@ error!(
     "@mixin foo {\n    color: content-exists();\n}\n\na {\n    @include foo{};\n}\n",
     "Error: Mixin doesn't accept a content block."
 );
+test!(
+    guards_content_in_control_flow,
+    "@mixin foo {\n    @if content-exists() {\n        @content;\n    } @else {\n        color: none;\n    }\n}\n\na {\n    @include foo;\n}\n\nb {\n    @include foo {\n        color: red;\n    }\n}\n",
+    "a {\n  color: none;\n}\n\nb {\n  color: red;\n}\n"
+);
+test!(
+    inside_each_within_mixin,
+    "@mixin foo {\n    @each $i in 1 {\n        color: content-exists();\n    }\n    @content;\n}\n\na {\n    @include foo {}\n}\n",
+    "a {\n  color: true;\n}\n"
+);
+test!(
+    through_meta_module,
+    "@use \"sass:meta\";\n@mixin foo {\n    color: meta.content-exists();\n    @content;\n}\n\na {\n    @include foo;\n}\n\nb {\n    @include foo {}\n}\n",
+    "a {\n  color: false;\n}\n\nb {\n  color: true;\n}\n"
+);
+error!(
+    through_meta_module_outside_mixin,
+    "@use \"sass:meta\";\na {\n    color: meta.content-exists();\n}\n",
+    "Error: content-exists() may only be called within a mixin."
+);
+error!(
+    in_content_block_outside_mixin,
+    "@mixin foo {\n    @content;\n}\n\n@include foo {\n    a {\n        color: content-exists();\n    }\n}\n",
+    "Error: content-exists() may only be called within a mixin."
+);
+test!(
+    in_content_block_inside_mixin,
+    "@mixin foo {\n    @content;\n}\n\n@mixin bar {\n    @include foo {\n        a {\n            color: content-exists();\n        }\n    }\n}\n\n@include bar;\n",
+    "a {\n  color: false;\n}\n"
+);
@@ -0,0 +1,108 @@
+#![cfg(test)]
+
+#[macro_use]
+mod macros;
+
+test!(
+    debug_does_not_halt_compilation,
+    "a {\n  @debug \"hi\";\n  color: red;\n}\n",
+    "a {\n  color: red;\n}\n"
+);
+test!(
+    debug_at_toplevel,
+    "@debug 1 + 1;\na {\n  color: red;\n}\n",
+    "a {\n  color: red;\n}\n"
+);
+test!(
+    debug_without_semicolon,
+    "a {\n  color: red;\n  @debug foo\n}\n",
+    "a {\n  color: red;\n}\n"
+);
+error!(
+    debug_undefined_variable,
+    "a {\n  @debug $foo;\n}\n", "Error: Undefined variable."
+);
+
+/// Compile `path` with the `grass` binary, which must succeed, returning what
+/// it wrote to stdout and stderr
+#[cfg(feature = "commandline")]
+fn compile_with_output(path: &str) -> (String, String) {
+    let output = std::process::Command::new(env!("CARGO_BIN_EXE_grass"))
+        .arg(path)
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    (
+        String::from_utf8(output.stdout).unwrap(),
+        String::from_utf8(output.stderr).unwrap(),
+    )
+}
+
+/// `@debug` prints strings without quotes, and other values as `inspect()`
+/// would, to stderr
+#[test]
+#[cfg(feature = "commandline")]
+fn debug_message_format() {
+    tempfile!(
+        "debug_message_format.scss",
+        "@debug (1px, 2px);\n@debug ();\n@debug true;\n@debug \"a b\";\n@debug null;\n@debug (a: b);\n@debug [1 2];\n"
+    );
+    assert_eq!(
+        "debug_message_format.scss:1:1 Debug: 1px, 2px\n\
+         debug_message_format.scss:2:1 Debug: ()\n\
+         debug_message_format.scss:3:1 Debug: true\n\
+         debug_message_format.scss:4:1 Debug: a b\n\
+         debug_message_format.scss:5:1 Debug: null\n\
+         debug_message_format.scss:6:1 Debug: (a: b)\n\
+         debug_message_format.scss:7:1 Debug: [1 2]\n",
+        compile_with_output("debug_message_format.scss").1
+    );
+}
+
+#[test]
+#[cfg(feature = "commandline")]
+fn debug_does_not_halt_compilation_output() {
+    tempfile!(
+        "debug_does_not_halt_compilation_output.scss",
+        "a {\n  @debug \"hi\";\n  color: red;\n}\n"
+    );
+    assert_eq!(
+        (
+            "a {\n  color: red;\n}\n".to_owned(),
+            "debug_does_not_halt_compilation_output.scss:2:3 Debug: hi\n".to_owned()
+        ),
+        compile_with_output("debug_does_not_halt_compilation_output.scss")
+    );
+}
+
+#[test]
+#[cfg(feature = "commandline")]
+fn debug_at_toplevel_output() {
+    tempfile!(
+        "debug_at_toplevel_output.scss",
+        "@debug 1 + 1;\na {\n  color: red;\n}\n"
+    );
+    assert_eq!(
+        (
+            "a {\n  color: red;\n}\n".to_owned(),
+            "debug_at_toplevel_output.scss:1:1 Debug: 2\n".to_owned()
+        ),
+        compile_with_output("debug_at_toplevel_output.scss")
+    );
+}
+
+#[test]
+#[cfg(feature = "commandline")]
+fn debug_without_semicolon_output() {
+    tempfile!(
+        "debug_without_semicolon_output.scss",
+        "a {\n  color: red;\n  @debug foo\n}\n"
+    );
+    assert_eq!(
+        (
+            "a {\n  color: red;\n}\n".to_owned(),
+            "debug_without_semicolon_output.scss:3:3 Debug: foo\n".to_owned()
+        ),
+        compile_with_output("debug_without_semicolon_output.scss")
+    );
+}
@@ -118,3 +118,90 @@ error!(
     "a {\n  color: 1 / get-function(lighten);\n}\n",
     "Error: get-function(\"lighten\") isn't a valid CSS value."
 );
+test!(
+    slash_between_number_literals_is_preserved,
+    "a {\n  color: 1/3;\n}\n"
+);
+test!(
+    slash_between_number_literals_with_whitespace,
+    "a {\n  color: 1 / 3;\n}\n",
+    "a {\n  color: 1/3;\n}\n"
+);
+test!(slash_in_font_shorthand, "a {\n  font: 16px/1.5 serif;\n}\n");
+test!(
+    slash_between_numbers_with_units_is_preserved,
+    "a {\n  color: 10px/8px;\n}\n"
+);
+test!(
+    slash_in_grid_row,
+    "a {\n  grid-row: 1 / span 2;\n}\n",
+    "a {\n  grid-row: 1/span 2;\n}\n"
+);
+test!(
+    slash_chain_is_preserved,
+    "a {\n  grid-area: 1 / 2 / 3 / 4;\n}\n",
+    "a {\n  grid-area: 1/2/3/4;\n}\n"
+);
+test!(
+    slash_with_negative_numbers_is_preserved,
+    "a {\n  color: -4/2;\n  color: 4/-2;\n}\n"
+);
+test!(
+    slash_in_space_and_comma_lists_is_preserved,
+    "a {\n  color: 4/2 6/3, 8/4;\n}\n"
+);
+test!(slash_in_brackets_is_preserved, "a {\n  color: [4/2];\n}\n");
+test!(
+    slash_in_media_query_is_preserved,
+    "@media (min-aspect-ratio: 16/9) {\n  a {\n    color: red;\n  }\n}\n"
+);
+test!(
+    slash_in_parens_divides,
+    "a {\n  color: (4/2);\n}\n",
+    "a {\n  color: 2;\n}\n"
+);
+test!(
+    slash_with_paren_operand_divides,
+    "a {\n  color: (4)/2;\n  color: 4/(2);\n}\n",
+    "a {\n  color: 2;\n  color: 2;\n}\n"
+);
+test!(
+    slash_with_variable_divides,
+    "$a: 4;\na {\n  color: $a/2;\n  color: 8/$a;\n}\n",
+    "a {\n  color: 2;\n  color: 2;\n}\n"
+);
+test!(
+    slash_with_function_call_divides,
+    "a {\n  color: round(4.2)/2;\n}\n",
+    "a {\n  color: 2;\n}\n"
+);
+test!(
+    slash_as_operand_of_addition_divides,
+    "a {\n  color: 4/2 + 1;\n  color: 1 + 4/2;\n}\n",
+    "a {\n  color: 3;\n  color: 3;\n}\n"
+);
+test!(
+    slash_as_operand_of_multiplication_divides,
+    "a {\n  color: 4/2 * 3;\n}\n",
+    "a {\n  color: 6;\n}\n"
+);
+test!(
+    slash_as_operand_of_comparison_divides,
+    "a {\n  color: 4/2 == 2;\n}\n",
+    "a {\n  color: true;\n}\n"
+);
+test!(
+    slash_stored_in_variable_divides,
+    "$a: 4/2;\na {\n  color: $a;\n}\n",
+    "a {\n  color: 2;\n}\n"
+);
+test!(
+    slash_passed_to_function_divides,
+    "a {\n  color: abs(4/2);\n}\n",
+    "a {\n  color: 2;\n}\n"
+);
+test!(
+    slash_returned_from_function_divides,
+    "@function foo() {\n  @return 4/2;\n}\na {\n  color: foo();\n}\n",
+    "a {\n  color: 2;\n}\n"
+);
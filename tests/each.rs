@@ -52,3 +52,37 @@ error!(
     missing_closing_curly_brace,
     "@each $i in 1 {", "Error: expected \"}\"."
 );
+test!(
+    loop_variable_shadows_outer_variable,
+    "$i: 5;\n@each $i in 1 2 {}\na {\n  color: $i;\n}\n",
+    "a {\n  color: 5;\n}\n"
+);
+test!(
+    assigns_outer_variable_within_body,
+    "a {\n  $sum: 0;\n  @each $key, $value in (a: 1, b: 2) {\n    $sum: $sum + $value;\n  }\n  color: $sum;\n}\n",
+    "a {\n  color: 3;\n}\n"
+);
+test!(
+    return_from_within_function,
+    "@function foo() {\n  @each $i in 1 2 3 {\n    @if $i == 2 {\n      @return $i;\n    }\n  }\n  @return null;\n}\na {\n  color: foo();\n}\n",
+    "a {\n  color: 2;\n}\n"
+);
+error!(
+    loop_variable_undefined_after_loop,
+    "@each $i in 1 2 {}\na {\n  color: $i;\n}\n", "Error: Undefined variable."
+);
+test!(
+    each_map_in_list_of_maps,
+    "@use \"sass:map\";\n$themes: (name: light, bg: white), (name: dark, bg: black);\n@each $theme in $themes {\n  .theme-#{map.get($theme, name)} {\n    background: map.get($theme, bg);\n  }\n}\n",
+    ".theme-light {\n  background: white;\n}\n\n.theme-dark {\n  background: black;\n}\n"
+);
+test!(
+    each_key_value_over_map,
+    "a {\n  @each $name, $bg in (name: light, bg: white) {\n    #{$name}: $bg;\n  }\n}\n",
+    "a {\n  name: light;\n  bg: white;\n}\n"
+);
+test!(
+    each_single_var_keeps_comma_separated_element,
+    "a {\n  @each $i in (a, b) (c, d) {\n    color: inspect($i);\n  }\n}\n",
+    "a {\n  color: a, b;\n  color: c, d;\n}\n"
+);
@@ -58,3 +58,48 @@ test!(
     "a {\n  color: (\"foo\",) != (foo,);\n}\n",
     "a {\n  color: false;\n}\n"
 );
+test!(
+    null_does_not_equal_false,
+    "a {\n  color: null == false;\n  color: null != false;\n}\n",
+    "a {\n  color: false;\n  color: true;\n}\n"
+);
+test!(
+    null_equals_null,
+    "a {\n  color: null == null;\n}\n",
+    "a {\n  color: true;\n}\n"
+);
+test!(
+    empty_strings_equal_regardless_of_quotes,
+    "a {\n  color: \"\" == \"\";\n  color: \"\" == '';\n}\n",
+    "a {\n  color: true;\n  color: true;\n}\n"
+);
+test!(
+    values_of_different_types_are_not_equal,
+    "a {\n  color: 1 == \"1\";\n  color: red == true;\n  color: false == 0;\n  color: 1 != \"1\";\n}\n",
+    "a {\n  color: false;\n  color: false;\n  color: false;\n  color: true;\n}\n"
+);
+test!(
+    if_equals_null,
+    "$a: null;\n@if $a == null {\n  a {\n    color: red;\n  }\n}\n",
+    "a {\n  color: red;\n}\n"
+);
+test!(
+    if_not_equals_empty_string,
+    "$a: \"\";\n@if $a != \"\" {\n  a {\n    color: red;\n  }\n} @else {\n  a {\n    color: blue;\n  }\n}\n",
+    "a {\n  color: blue;\n}\n"
+);
+test!(
+    maps_equal_regardless_of_order,
+    "a {\n  color: (a: 1, b: 2) == (b: 2, a: 1);\n  color: (a: 1, b: 2) != (b: 2, a: 1);\n}\n",
+    "a {\n  color: true;\n  color: false;\n}\n"
+);
+test!(
+    maps_compare_keys_and_values_as_values,
+    "a {\n  color: (\"a\": 1) == (a: 1.0);\n  color: (a: 1) == (a: 2);\n  color: (a: 1) == (b: 1);\n}\n",
+    "a {\n  color: true;\n  color: false;\n  color: false;\n}\n"
+);
+test!(
+    empty_list_equals_empty_map,
+    "a {\n  color: () == map-remove((a: 1), a);\n  color: map-remove((a: 1), a) == ();\n  color: [] == map-remove((a: 1), a);\n}\n",
+    "a {\n  color: true;\n  color: true;\n  color: false;\n}\n"
+);
@@ -248,3 +248,37 @@ error!(
     "a {foo: {bar: red", "Error: Expected identifier."
 );
 error!(toplevel_nullbyte, "\u{0}", "Error: expected selector.");
+
+fn error_display(input: &str) -> String {
+    match grass::from_string(input.to_string(), &grass::Options::default()) {
+        Ok(..) => panic!("did not fail"),
+        Err(e) => e.to_string(),
+    }
+}
+
+#[test]
+fn display_shows_source_line_and_pointer() {
+    assert_eq!(
+        "Error: Undefined mixin.\n  |\n1 | a { @include foo; }\n  |     ^^^^^^^^^^^^\n  |\n./stdin:1:5\n",
+        error_display("a { @include foo; }")
+    );
+}
+
+#[test]
+fn display_pads_gutter_to_line_number() {
+    assert_eq!(
+        "Error: Undefined mixin.\n   |\n10 |   @include foo;\n   |   ^^^^^^^^^^^^\n   |\n./stdin:10:3\n",
+        error_display("\n\n\n\n\n\n\n\na {\n  @include foo;\n}\n")
+    );
+}
+
+#[test]
+fn display_of_file_shows_its_path() {
+    tempfile!("error_display_of_file.scss", "a {\n  @include foo;\n}\n");
+    let e = grass::from_path("error_display_of_file.scss", &grass::Options::default())
+        .expect_err("did not fail");
+    assert_eq!(
+        "Error: Undefined mixin.\n  |\n2 |   @include foo;\n  |   ^^^^^^^^^^^^\n  |\n./error_display_of_file.scss:2:3\n",
+        e.to_string()
+    );
+}
@@ -1396,7 +1396,6 @@ test!(
     "@media screen {\n  @unknown {\n    .foo, .bar {\n      a: b;\n    }\n  }\n}\n"
 );
 test!(
-    #[ignore = "media queries are not yet parsed correctly"]
     extend_within_separate_media_queries,
     "@media screen {.foo {a: b}}
     @media screen {.bar {@extend .foo}}
@@ -1419,6 +1418,41 @@ test!(
     ",
     "@media screen {\n  @flooblehoof {\n    .foo, .bar {\n      a: b;\n    }\n  }\n}\n@media screen {\n  @flooblehoof {}\n}\n"
 );
+error!(
+    extend_outer_selector_from_within_media,
+    ".foo {a: b}
+    @media screen {.bar {@extend .foo}}
+    ",
+    "Error: You may not @extend an outer selector from within @media."
+);
+error!(
+    extend_outer_selector_defined_after_media,
+    "@media screen {.bar {@extend .foo}}
+    .foo {a: b}
+    ",
+    "Error: You may not @extend an outer selector from within @media."
+);
+error!(
+    extend_across_media_queries,
+    "@media print {.foo {a: b}}
+    @media screen {.bar {@extend .foo}}
+    ",
+    "Error: You may not @extend selectors across media queries."
+);
+test!(
+    optional_extend_outer_selector_from_within_media,
+    ".foo {a: b}
+    @media screen {.bar {@extend .foo !optional}}
+    ",
+    ".foo {\n  a: b;\n}\n"
+);
+test!(
+    extend_selector_within_media_from_outside,
+    "@media screen {.foo {a: b}}
+    .bar {@extend .foo}
+    ",
+    "@media screen {\n  .foo, .bar {\n    a: b;\n  }\n}\n"
+);
 test!(
     extend_succeeds_when_one_extend_fails_but_others_dont,
     "a.bar {a: b}
@@ -1428,19 +1462,35 @@ test!(
     "a.bar {\n  a: b;\n}\n\n.bar, b.foo {\n  c: d;\n}\n"
 );
 test!(
-    #[ignore = "!optional extend is not yet implemented"]
     optional_extend_succeeds_when_extendee_doesnt_exist,
     ".foo {@extend .bar !optional}",
     ""
 );
 test!(
-    #[ignore = "!optional extend is not yet implemented"]
     optional_extend_succeeds_when_extension_fails,
     "a.bar {a: b}
     b.foo {@extend .bar !optional}
     ",
     "a.bar {\n  a: b;\n}\n"
 );
+error!(
+    extend_missing_selector,
+    ".foo {@extend .bar}", "Error: The target selector was not found."
+);
+error!(
+    extend_missing_placeholder,
+    ".foo {@extend %bar}", "Error: The target selector was not found."
+);
+error!(
+    extend_missing_selector_optional_elsewhere,
+    ".foo {@extend .bar}\n.baz {@extend .bar !optional}",
+    "Error: The target selector was not found."
+);
+test!(
+    extend_selector_defined_after_extend,
+    ".foo {@extend .bar}\n.bar {a: b}\n",
+    ".bar, .foo {\n  a: b;\n}\n"
+);
 test!(
     psuedo_element_superselector_1,
     "%x#bar {a: b} // Add an id to make the results have high specificity
@@ -0,0 +1,211 @@
+#![cfg(all(test, feature = "ffi"))]
+use std::{
+    ffi::{CStr, CString},
+    os::raw::c_char,
+    ptr,
+};
+
+// the C interface is only reachable through its symbols, so the crate
+// must be linked explicitly
+extern crate grass;
+
+#[repr(C)]
+struct GrassOptions {
+    recursion_limit: usize,
+    source_map: GrassSourceMap,
+    charset: GrassCharset,
+    line_ending: GrassLineEnding,
+}
+
+#[repr(C)]
+#[allow(dead_code)]
+enum GrassSourceMap {
+    None,
+    Inline,
+}
+
+#[repr(C)]
+#[allow(dead_code)]
+enum GrassCharset {
+    Auto,
+    Always,
+    Never,
+}
+
+#[repr(C)]
+#[allow(dead_code)]
+enum GrassLineEnding {
+    Lf,
+    CrLf,
+    Auto,
+}
+
+enum GrassCompileCtx {}
+
+extern "C" {
+    fn grass_options_default() -> GrassOptions;
+    fn grass_context_new() -> *mut GrassCompileCtx;
+    fn grass_context_free(ctx: *mut GrassCompileCtx);
+    fn grass_compile_string(
+        ctx: *mut GrassCompileCtx,
+        scss: *const c_char,
+        options: *const GrassOptions,
+    ) -> *mut c_char;
+    fn grass_free_string(s: *mut c_char);
+    fn grass_error_message(ctx: *const GrassCompileCtx) -> *const c_char;
+}
+
+#[test]
+fn compile_string() {
+    let input = CString::new("a {\n  color: red;\n}\n").unwrap();
+    unsafe {
+        let ctx = grass_context_new();
+        let css = grass_compile_string(ctx, input.as_ptr(), ptr::null());
+        assert!(!css.is_null());
+        assert_eq!(
+            "a {\n  color: red;\n}\n",
+            CStr::from_ptr(css).to_str().unwrap()
+        );
+        assert!(grass_error_message(ctx).is_null());
+        grass_free_string(css);
+        grass_context_free(ctx);
+    }
+}
+
+#[test]
+fn compile_string_error() {
+    let input = CString::new("a {\n  color: $foo;\n}\n").unwrap();
+    unsafe {
+        let ctx = grass_context_new();
+        let css = grass_compile_string(ctx, input.as_ptr(), ptr::null());
+        assert!(css.is_null());
+        assert!(CStr::from_ptr(grass_error_message(ctx))
+            .to_str()
+            .unwrap()
+            .starts_with("Error: Undefined variable.\n"));
+        grass_context_free(ctx);
+    }
+}
+
+#[test]
+fn compile_string_with_options() {
+    let input =
+        CString::new("@mixin foo {\n  @include foo;\n}\n\na {\n  @include foo;\n}\n").unwrap();
+    let options = GrassOptions {
+        recursion_limit: 10,
+        ..unsafe { grass_options_default() }
+    };
+    unsafe {
+        let ctx = grass_context_new();
+        let css = grass_compile_string(ctx, input.as_ptr(), &options);
+        assert!(css.is_null());
+        assert!(CStr::from_ptr(grass_error_message(ctx))
+            .to_str()
+            .unwrap()
+            .starts_with("Error: stack overflow\n"));
+        grass_context_free(ctx);
+    }
+}
+
+#[test]
+fn compile_string_with_source_map() {
+    let input = CString::new("a {\n  color: red;\n}\n").unwrap();
+    let options = GrassOptions {
+        source_map: GrassSourceMap::Inline,
+        ..unsafe { grass_options_default() }
+    };
+    unsafe {
+        let ctx = grass_context_new();
+        let css = grass_compile_string(ctx, input.as_ptr(), &options);
+        assert!(!css.is_null());
+        assert!(CStr::from_ptr(css)
+            .to_str()
+            .unwrap()
+            .contains("/*# sourceMappingURL=data:application/json;base64,"));
+        grass_free_string(css);
+        grass_context_free(ctx);
+    }
+}
+
+#[test]
+fn compile_string_with_charset() {
+    let input = CString::new("a {\n  color: red;\n}\n").unwrap();
+    let options = GrassOptions {
+        charset: GrassCharset::Always,
+        ..unsafe { grass_options_default() }
+    };
+    unsafe {
+        let ctx = grass_context_new();
+        let css = grass_compile_string(ctx, input.as_ptr(), &options);
+        assert!(!css.is_null());
+        assert_eq!(
+            "@charset \"UTF-8\";\na {\n  color: red;\n}\n",
+            CStr::from_ptr(css).to_str().unwrap()
+        );
+        grass_free_string(css);
+        grass_context_free(ctx);
+    }
+}
+
+#[test]
+fn compile_string_with_line_ending() {
+    let input = CString::new("a {\n  color: red;\n}\n").unwrap();
+    let options = GrassOptions {
+        line_ending: GrassLineEnding::CrLf,
+        ..unsafe { grass_options_default() }
+    };
+    unsafe {
+        let ctx = grass_context_new();
+        let css = grass_compile_string(ctx, input.as_ptr(), &options);
+        assert!(!css.is_null());
+        assert_eq!(
+            "a {\r\n  color: red;\r\n}\r\n",
+            CStr::from_ptr(css).to_str().unwrap()
+        );
+        grass_free_string(css);
+        grass_context_free(ctx);
+    }
+}
+
+/// Every function and type of the C interface, used as a C program would
+const C_EXAMPLE: &str = r#"#include <stdio.h>
+#include "grass.h"
+
+int main(void) {
+    struct GrassOptions options = grass_options_default();
+    options.recursion_limit = 100;
+    options.source_map = GRASS_SOURCE_MAP_NONE;
+    options.charset = GRASS_CHARSET_NEVER;
+    options.line_ending = GRASS_LINE_ENDING_CR_LF;
+
+    struct GrassCompileCtx *ctx = grass_context_new();
+    char *css = grass_compile_string(ctx, "a { color: red; }", &options);
+    if (css == NULL) {
+        fprintf(stderr, "%s\n", grass_error_message(ctx));
+        grass_context_free(ctx);
+        return 1;
+    }
+
+    printf("%s", css);
+    grass_free_string(css);
+    grass_context_free(ctx);
+    return 0;
+}
+"#;
+
+/// The generated header is checked by compiling a C program against it
+#[test]
+fn c_header() {
+    let dir = tempfile::tempdir().unwrap();
+    let example = dir.path().join("example.c");
+    std::fs::write(&example, C_EXAMPLE).unwrap();
+
+    let status =
+        std::process::Command::new(std::env::var("CC").unwrap_or_else(|_| "cc".to_owned()))
+            .args(&["-std=c99", "-Wall", "-Werror", "-fsyntax-only", "-I"])
+            .arg(env!("OUT_DIR"))
+            .arg(&example)
+            .status()
+            .expect("a C compiler is needed to check grass.h");
+    assert!(status.success());
+}
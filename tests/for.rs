@@ -79,3 +79,40 @@ test!(
     }",
     "a {\n  color: red;\n}\n"
 );
+test!(
+    assigning_loop_variable_does_not_affect_iteration,
+    "@for $i from 1 through 3 {\n  $i: 5;\n  a {\n    color: $i;\n  }\n}\n",
+    "a {\n  color: 5;\n}\n\na {\n  color: 5;\n}\n\na {\n  color: 5;\n}\n"
+);
+test!(
+    loop_variable_is_rebound_each_iteration,
+    "@for $i from 1 through 3 {\n  a {\n    color: $i;\n  }\n  @if $i == 1 {\n    $i: 10;\n  }\n}\n",
+    "a {\n  color: 1;\n}\n\na {\n  color: 2;\n}\n\na {\n  color: 3;\n}\n"
+);
+test!(
+    assigning_loop_variable_in_nested_block_does_not_affect_iteration,
+    "@for $i from 1 to 3 {\n  @if $i == 1 {\n    $i: 10;\n  }\n  a {\n    color: $i;\n  }\n}\n",
+    "a {\n  color: 10;\n}\n\na {\n  color: 2;\n}\n"
+);
+test!(
+    through_includes_end,
+    "a {\n  @for $i from 0 through 2 {\n    color: $i;\n  }\n}\n",
+    "a {\n  color: 0;\n  color: 1;\n  color: 2;\n}\n"
+);
+test!(
+    integer_valued_decimal_bounds,
+    "a {\n  @for $i from 1.0 through 2.0 {\n    color: $i;\n  }\n}\n",
+    "a {\n  color: 1;\n  color: 2;\n}\n"
+);
+error!(
+    decimal_start,
+    "a {\n  @for $i from 0.5 through 3 {\n    color: $i;\n  }\n}\n", "Error: 0.5 is not an int."
+);
+error!(
+    decimal_end,
+    "a {\n  @for $i from 0 to 2.5 {\n    color: $i;\n  }\n}\n", "Error: 2.5 is not an int."
+);
+error!(
+    decimal_end_counting_down,
+    "a {\n  @for $i from 0 through -1.5 {\n    color: $i;\n  }\n}\n", "Error: -1.5 is not an int."
+);
@@ -0,0 +1,372 @@
+#![cfg(test)]
+
+#[macro_use]
+mod macros;
+
+#[test]
+fn forward_exposes_all_members() {
+    let input = "@use \"forward_all__api\";\na {\n color: forward_all__api.$a;\n}";
+    tempfile!("forward_all__api.scss", "@forward \"forward_all__lib\";");
+    tempfile!("forward_all__lib.scss", "$a: red;");
+    assert_eq!(
+        "a {\n  color: red;\n}\n",
+        &grass::from_string(input.to_string(), &grass::Options::default()).expect(input)
+    );
+}
+
+#[test]
+fn forward_show_variable_and_mixin() {
+    let input = "@use \"forward_show__api\" as api;\na {\n color: api.$a;\n @include api.m;\n}";
+    tempfile!(
+        "forward_show__api.scss",
+        "@forward \"forward_show__lib\" show $a, m;"
+    );
+    tempfile!(
+        "forward_show__lib.scss",
+        "$a: red;\n$b: blue;\n@mixin m {\n width: 1px;\n}"
+    );
+    assert_eq!(
+        "a {\n  color: red;\n  width: 1px;\n}\n",
+        &grass::from_string(input.to_string(), &grass::Options::default()).expect(input)
+    );
+}
+
+#[test]
+fn forward_show_function() {
+    let input = "@use \"forward_show_fn__api\" as api;\na {\n color: api.double(2);\n}";
+    tempfile!(
+        "forward_show_fn__api.scss",
+        "@forward \"forward_show_fn__lib\" show double;"
+    );
+    tempfile!(
+        "forward_show_fn__lib.scss",
+        "@function double($n) {\n @return $n * 2;\n}"
+    );
+    assert_eq!(
+        "a {\n  color: 4;\n}\n",
+        &grass::from_string(input.to_string(), &grass::Options::default()).expect(input)
+    );
+}
+
+#[test]
+fn forward_show_excludes_unlisted_variable() {
+    let input = "@use \"forward_show_excl__api\" as api;\na {\n color: api.$b;\n}";
+    tempfile!(
+        "forward_show_excl__api.scss",
+        "@forward \"forward_show_excl__lib\" show $a;"
+    );
+    tempfile!("forward_show_excl__lib.scss", "$a: red;\n$b: blue;");
+    match grass::from_string(input.to_string(), &grass::Options::default()) {
+        Ok(..) => panic!("did not fail"),
+        Err(e) => assert!(e.to_string().starts_with("Error: Undefined variable.")),
+    }
+}
+
+#[test]
+fn forward_show_variable_does_not_show_mixin_of_same_name() {
+    let input = "@use \"forward_show_kind__api\" as api;\na {\n @include api.a;\n}";
+    tempfile!(
+        "forward_show_kind__api.scss",
+        "@forward \"forward_show_kind__lib\" show $a;"
+    );
+    tempfile!(
+        "forward_show_kind__lib.scss",
+        "$a: red;\n@mixin a {\n color: red;\n}"
+    );
+    match grass::from_string(input.to_string(), &grass::Options::default()) {
+        Ok(..) => panic!("did not fail"),
+        Err(e) => assert!(e.to_string().starts_with("Error: Undefined mixin.")),
+    }
+}
+
+#[test]
+fn forward_hide_excludes_listed_members() {
+    let input = "@use \"forward_hide__api\" as api;\na {\n color: api.$internal;\n}";
+    tempfile!(
+        "forward_hide__api.scss",
+        "@forward \"forward_hide__lib\" hide $internal;"
+    );
+    tempfile!("forward_hide__lib.scss", "$a: red;\n$internal: blue;");
+    match grass::from_string(input.to_string(), &grass::Options::default()) {
+        Ok(..) => panic!("did not fail"),
+        Err(e) => assert!(e.to_string().starts_with("Error: Undefined variable.")),
+    }
+}
+
+#[test]
+fn forward_hide_keeps_other_members() {
+    let input =
+        "@use \"forward_hide_keep__api\" as api;\na {\n color: api.$a;\n @include api.m;\n}";
+    tempfile!(
+        "forward_hide_keep__api.scss",
+        "@forward \"forward_hide_keep__lib\" hide $internal, other;"
+    );
+    tempfile!(
+        "forward_hide_keep__lib.scss",
+        "$a: red;\n$internal: blue;\n@mixin m {\n width: $internal;\n}\n@mixin other {\n width: 0;\n}"
+    );
+    assert_eq!(
+        "a {\n  color: red;\n  width: blue;\n}\n",
+        &grass::from_string(input.to_string(), &grass::Options::default()).expect(input)
+    );
+}
+
+#[test]
+fn forward_hide_mixin() {
+    let input = "@use \"forward_hide_mixin__api\" as api;\na {\n @include api.other;\n}";
+    tempfile!(
+        "forward_hide_mixin__api.scss",
+        "@forward \"forward_hide_mixin__lib\" hide other;"
+    );
+    tempfile!(
+        "forward_hide_mixin__lib.scss",
+        "@mixin other {\n width: 0;\n}"
+    );
+    match grass::from_string(input.to_string(), &grass::Options::default()) {
+        Ok(..) => panic!("did not fail"),
+        Err(e) => assert!(e.to_string().starts_with("Error: Undefined mixin.")),
+    }
+}
+
+#[test]
+fn forward_emits_css_once() {
+    let input = "@use \"forward_css__api\";\n@use \"forward_css__lib\";";
+    tempfile!("forward_css__api.scss", "@forward \"forward_css__lib\";");
+    tempfile!("forward_css__lib.scss", "a {\n color: red;\n}");
+    assert_eq!(
+        "a {\n  color: red;\n}\n",
+        &grass::from_string(input.to_string(), &grass::Options::default()).expect(input)
+    );
+}
+
+#[test]
+fn forward_with_configures_default_variable() {
+    let input = "@use \"forward_with__api\" as api;\na {\n color: api.$a;\n}";
+    tempfile!(
+        "forward_with__api.scss",
+        "@forward \"forward_with__lib\" with ($a: blue);"
+    );
+    tempfile!("forward_with__lib.scss", "$a: red !default;");
+    assert_eq!(
+        "a {\n  color: blue;\n}\n",
+        &grass::from_string(input.to_string(), &grass::Options::default()).expect(input)
+    );
+}
+
+#[test]
+fn forward_with_default_is_overridden_by_use_with() {
+    let input = "@use \"forward_with_guarded__api\" as api with ($a: green);\na {\n color: api.$a;\n b: api.$b;\n}";
+    tempfile!(
+        "forward_with_guarded__api.scss",
+        "@forward \"forward_with_guarded__lib\" with ($a: blue !default, $b: 1px !default);"
+    );
+    tempfile!(
+        "forward_with_guarded__lib.scss",
+        "$a: red !default;\n$b: 0 !default;"
+    );
+    assert_eq!(
+        "a {\n  color: green;\n  b: 1px;\n}\n",
+        &grass::from_string(input.to_string(), &grass::Options::default()).expect(input)
+    );
+}
+
+#[test]
+fn forward_passes_on_use_with() {
+    let input = "@use \"forward_pass_with__api\" as api with ($a: green);\na {\n color: api.$a;\n}";
+    tempfile!(
+        "forward_pass_with__api.scss",
+        "@forward \"forward_pass_with__lib\";"
+    );
+    tempfile!("forward_pass_with__lib.scss", "$a: red !default;");
+    assert_eq!(
+        "a {\n  color: green;\n}\n",
+        &grass::from_string(input.to_string(), &grass::Options::default()).expect(input)
+    );
+}
+
+#[test]
+fn forward_with_and_show() {
+    let input = "@use \"forward_with_show__api\" as api;\na {\n color: map-get(api.$a, b);\n}";
+    tempfile!(
+        "forward_with_show__api.scss",
+        "@forward \"forward_with_show__lib\" show $a with ($a: (b: c), $b: [1, 2]);"
+    );
+    tempfile!(
+        "forward_with_show__lib.scss",
+        "$a: red !default;\n$b: null !default;"
+    );
+    assert_eq!(
+        "a {\n  color: c;\n}\n",
+        &grass::from_string(input.to_string(), &grass::Options::default()).expect(input)
+    );
+}
+
+#[test]
+fn forward_with_variable_not_default() {
+    let input = "@use \"forward_with_not_default__api\";";
+    tempfile!(
+        "forward_with_not_default__api.scss",
+        "@forward \"forward_with_not_default__lib\" with ($a: blue);"
+    );
+    tempfile!("forward_with_not_default__lib.scss", "$a: red;");
+    match grass::from_string(input.to_string(), &grass::Options::default()) {
+        Ok(..) => panic!("did not fail"),
+        Err(e) => assert!(e.to_string().starts_with(
+            "Error: This variable was not declared with !default in the @used module."
+        )),
+    }
+}
+
+#[test]
+fn forward_with_overrides_use_with() {
+    let input = "@use \"forward_with_override__api\" with ($a: green);";
+    tempfile!(
+        "forward_with_override__api.scss",
+        "@forward \"forward_with_override__lib\" with ($a: blue);"
+    );
+    tempfile!("forward_with_override__lib.scss", "$a: red !default;");
+    match grass::from_string(input.to_string(), &grass::Options::default()) {
+        Ok(..) => panic!("did not fail"),
+        Err(e) => assert!(e.to_string().starts_with(
+            "Error: This variable was not declared with !default in the @used module."
+        )),
+    }
+}
+
+#[test]
+fn forward_with_conflicting_configurations() {
+    let input = "@use \"forward_with_conflict__api\";";
+    tempfile!(
+        "forward_with_conflict__api.scss",
+        "@forward \"forward_with_conflict__lib\" with ($a: blue);\n@forward \"forward_with_conflict__lib\" with ($a: green);"
+    );
+    tempfile!("forward_with_conflict__lib.scss", "$a: red !default;");
+    match grass::from_string(input.to_string(), &grass::Options::default()) {
+        Ok(..) => panic!("did not fail"),
+        Err(e) => assert!(e.to_string().starts_with(
+            "Error: This module was already loaded, so it can't be configured using \"with\"."
+        )),
+    }
+}
+
+#[test]
+fn forward_already_loaded_module_does_not_use_configuration() {
+    let input = "@use \"forward_loaded__lib\";\n@use \"forward_loaded__api\" with ($a: green);";
+    tempfile!(
+        "forward_loaded__api.scss",
+        "@forward \"forward_loaded__lib\";"
+    );
+    tempfile!("forward_loaded__lib.scss", "$a: red !default;");
+    match grass::from_string(input.to_string(), &grass::Options::default()) {
+        Ok(..) => panic!("did not fail"),
+        Err(e) => assert!(e.to_string().starts_with(
+            "Error: This variable was not declared with !default in the @used module."
+        )),
+    }
+}
+
+#[test]
+fn forward_prefix() {
+    let input = "@use \"forward_prefix__api\" as api;\na {\n color: api.$fn-a;\n color: api.fn-double(2);\n @include api.fn-m;\n}";
+    tempfile!(
+        "forward_prefix__api.scss",
+        "@forward \"forward_prefix__lib\" as fn-*;"
+    );
+    tempfile!(
+        "forward_prefix__lib.scss",
+        "$a: red;\n@function double($n) {\n @return $n * 2;\n}\n@mixin m {\n width: 1px;\n}"
+    );
+    assert_eq!(
+        "a {\n  color: red;\n  color: 4;\n  width: 1px;\n}\n",
+        &grass::from_string(input.to_string(), &grass::Options::default()).expect(input)
+    );
+}
+
+#[test]
+fn forward_prefix_hides_original_name() {
+    let input = "@use \"forward_prefix_orig__api\" as api;\na {\n color: api.$a;\n}";
+    tempfile!(
+        "forward_prefix_orig__api.scss",
+        "@forward \"forward_prefix_orig__lib\" as fn-*;"
+    );
+    tempfile!("forward_prefix_orig__lib.scss", "$a: red;");
+    match grass::from_string(input.to_string(), &grass::Options::default()) {
+        Ok(..) => panic!("did not fail"),
+        Err(e) => assert!(e.to_string().starts_with("Error: Undefined variable.")),
+    }
+}
+
+#[test]
+fn forward_prefix_and_show() {
+    let input = "@use \"forward_prefix_show__api\" as api;\na {\n color: api.$fn-a;\n}";
+    tempfile!(
+        "forward_prefix_show__api.scss",
+        "@forward \"forward_prefix_show__lib\" as fn-* show $fn-a;"
+    );
+    tempfile!("forward_prefix_show__lib.scss", "$a: red;\n$b: blue;");
+    assert_eq!(
+        "a {\n  color: red;\n}\n",
+        &grass::from_string(input.to_string(), &grass::Options::default()).expect(input)
+    );
+}
+
+#[test]
+fn forward_prefix_and_hide() {
+    let input = "@use \"forward_prefix_hide__api\" as api;\na {\n color: api.$fn-b;\n}";
+    tempfile!(
+        "forward_prefix_hide__api.scss",
+        "@forward \"forward_prefix_hide__lib\" as fn-* hide $fn-b;"
+    );
+    tempfile!("forward_prefix_hide__lib.scss", "$a: red;\n$b: blue;");
+    match grass::from_string(input.to_string(), &grass::Options::default()) {
+        Ok(..) => panic!("did not fail"),
+        Err(e) => assert!(e.to_string().starts_with("Error: Undefined variable.")),
+    }
+}
+
+#[test]
+fn forward_prefix_configuration() {
+    let input = "@use \"forward_prefix_config__api\" with ($fn-a: blue);\na {\n color: forward_prefix_config__api.$fn-a;\n}";
+    tempfile!(
+        "forward_prefix_config__api.scss",
+        "@forward \"forward_prefix_config__lib\" as fn-*;"
+    );
+    tempfile!("forward_prefix_config__lib.scss", "$a: red !default;");
+    assert_eq!(
+        "a {\n  color: blue;\n}\n",
+        &grass::from_string(input.to_string(), &grass::Options::default()).expect(input)
+    );
+}
+
+#[test]
+fn forward_does_not_expose_private_members() {
+    tempfile!(
+        "forward_private__api.scss",
+        "@forward \"forward_private__lib\";\n@forward \"forward_private__other\" as o-*;"
+    );
+    tempfile!(
+        "forward_private__lib.scss",
+        "$_a: red;\n@function -f() {\n  @return 1;\n}"
+    );
+    tempfile!("forward_private__other.scss", "$_b: red;");
+    for input in &[
+        "@use \"forward_private__api\" as api;\na {\n color: api.$_a;\n}",
+        "@use \"forward_private__api\" as api;\na {\n color: api.-f();\n}",
+    ] {
+        match grass::from_string(input.to_string(), &grass::Options::default()) {
+            Ok(..) => panic!("did not fail: {}", input),
+            Err(e) => assert!(
+                e.to_string().starts_with(
+                    "Error: Private members can't be accessed from outside their modules."
+                ),
+                "{}",
+                e
+            ),
+        }
+    }
+    let input = "@use \"forward_private__api\" as api;\na {\n color: api.$o-_b;\n}";
+    match grass::from_string(input.to_string(), &grass::Options::default()) {
+        Ok(..) => panic!("did not fail"),
+        Err(e) => assert!(e.to_string().starts_with("Error: Undefined variable.")),
+    }
+}
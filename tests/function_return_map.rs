@@ -0,0 +1,45 @@
+#![cfg(test)]
+
+#[macro_use]
+mod macros;
+
+test!(
+    returned_map_values_by_map_get,
+    "@use \"sass:map\";\n@function size() {\n  @return (width: 100px, height: 50px);\n}\na {\n  width: map.get(size(), width);\n  height: map-get(size(), \"height\");\n}\n",
+    "a {\n  width: 100px;\n  height: 50px;\n}\n"
+);
+test!(
+    returned_map_stored_in_variable,
+    "@use \"sass:map\";\n@function size($width, $height: $width * 0.5) {\n  @return (width: $width, height: $height);\n}\na {\n  $size: size(10px);\n  width: map.get($size, width);\n  height: map.get($size, height);\n}\n",
+    "a {\n  width: 10px;\n  height: 5px;\n}\n"
+);
+test!(
+    returned_map_is_a_map,
+    "@function size() {\n  @return (width: 100px, height: 50px);\n}\na {\n  color: type-of(size());\n  color: inspect(size());\n}\n",
+    "a {\n  color: map;\n  color: (width: 100px, height: 50px);\n}\n"
+);
+test!(
+    map_returning_function_through_call,
+    "@use \"sass:map\";\n@function size() {\n  @return (width: 100px, height: 50px);\n}\na {\n  width: map.get(call(get-function(\"size\")), width);\n}\n",
+    "a {\n  width: 100px;\n}\n"
+);
+test!(
+    returned_map_keys,
+    "@use \"sass:map\";\n@function size() {\n  @return (width: 100px, height: 50px);\n}\na {\n  color: map.keys(size());\n  color: map.has-key(size(), width);\n}\n",
+    "a {\n  color: width, height;\n  color: true;\n}\n"
+);
+test!(
+    returned_map_in_each,
+    "@function size() {\n  @return (width: 100px, height: 50px);\n}\na {\n  @each $property, $value in size() {\n    #{$property}: $value;\n  }\n}\n",
+    "a {\n  width: 100px;\n  height: 50px;\n}\n"
+);
+test!(
+    returned_map_merged,
+    "@use \"sass:map\";\n@function size() {\n  @return (width: 100px, height: 50px);\n}\na {\n  color: inspect(map.merge(size(), (height: 10px)));\n  color: inspect(map.remove(size(), width));\n}\n",
+    "a {\n  color: (width: 100px, height: 10px);\n  color: (height: 50px);\n}\n"
+);
+error!(
+    returned_map_as_property_value,
+    "@function size() {\n  @return (width: 100px);\n}\na {\n  color: size();\n}\n",
+    "Error: (width: 100px) isn't a valid CSS value."
+);
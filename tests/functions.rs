@@ -143,3 +143,60 @@ test!(
     }",
     "a {\n  color: red;\n}\n"
 );
+#[test]
+fn infinitely_recursive_function() {
+    let input = "@function foo() {\n  @return foo();\n}\n\na {\n  color: foo();\n}\n";
+    match grass::from_string(
+        input.to_string(),
+        &grass::Options::default().recursion_limit(10),
+    ) {
+        Ok(..) => panic!("did not fail"),
+        Err(e) => assert!(e.to_string().starts_with("Error: stack overflow\n")),
+    }
+}
+#[test]
+fn infinitely_recursive_function_default_limit() {
+    let input = "@function foo() {\n  @return foo();\n}\n\na {\n  color: foo();\n}\n";
+    match grass::from_string(input.to_string(), &grass::Options::default()) {
+        Ok(..) => panic!("did not fail"),
+        Err(e) => assert!(e.to_string().starts_with("Error: stack overflow\n")),
+    }
+}
+#[test]
+fn infinitely_recursive_function_in_control_flow_default_limit() {
+    let input = "@function foo($i) {\n  @if $i > 0 {\n    @each $j in 1 2 {\n      @return foo($i + 1);\n    }\n  }\n  @return $i;\n}\n\na {\n  color: foo(1);\n}\n";
+    match grass::from_string(input.to_string(), &grass::Options::default()) {
+        Ok(..) => panic!("did not fail"),
+        Err(e) => assert!(e.to_string().starts_with("Error: stack overflow\n")),
+    }
+}
+test!(
+    local_function_found_from_nested_style_rule,
+    "a {\n  @function foo() {\n    @return red;\n  }\n  b {\n    color: foo();\n  }\n}\n",
+    "a b {\n  color: red;\n}\n"
+);
+test!(
+    local_function_not_visible_outside_its_scope,
+    "a {\n  @function foo() {\n    @return red;\n  }\n}\nb {\n  color: foo();\n}\n",
+    "b {\n  color: foo();\n}\n"
+);
+test!(
+    default_arg_evaluated_when_called,
+    "$base-size: 1px;\n@function foo($size: $base-size * 3) {\n  @return $size;\n}\n$base-size: 5px;\na {\n  color: foo();\n}\n",
+    "a {\n  color: 15px;\n}\n"
+);
+test!(
+    early_return_from_if_inside_for,
+    "@function find($list, $val) {\n  @for $i from 1 through length($list) {\n    @if nth($list, $i) == $val {\n      @return $i;\n    }\n  }\n  @return null;\n}\na {\n  color: find(a b c b, b);\n  color: inspect(find(a b, z));\n}\n",
+    "a {\n  color: 2;\n  color: null;\n}\n"
+);
+test!(
+    early_return_stops_iterating,
+    "@function find($list, $val) {\n  @for $i from 1 through length($list) {\n    @if $i > 2 {\n      @error \"kept iterating\";\n    }\n    @if nth($list, $i) == $val {\n      @return $i;\n    }\n  }\n  @return null;\n}\na {\n  color: find(a b c d, b);\n}\n",
+    "a {\n  color: 2;\n}\n"
+);
+test!(
+    early_return_from_if_inside_each,
+    "@function position($list, $val) {\n  $n: 0;\n  @each $item in $list {\n    $n: $n + 1;\n    @if $item == $val {\n      @return $n;\n    }\n  }\n  @return -1;\n}\na {\n  color: position(a b c, b);\n  color: position(a b c, z);\n}\n",
+    "a {\n  color: 2;\n  color: -1;\n}\n"
+);
@@ -121,3 +121,73 @@ test!(
     "a {\n  color: call(call(get-function(get-function), darken), red, 10%);\n}\n",
     "a {\n  color: #cc0000;\n}\n"
 );
+test!(
+    call_builtin_stored_in_variable,
+    "$f: get-function(\"darken\");\na {\n  color: call($f, red, 10%);\n}\n",
+    "a {\n  color: #cc0000;\n}\n"
+);
+test!(
+    builtin_name_hyphen_underscore_normalized,
+    "a {\n  color: call(get-function(str_length), \"abc\");\n}\n",
+    "a {\n  color: 3;\n}\n"
+);
+test!(
+    call_css_function,
+    "a {\n  color: call(get-function(foo, $css: true), 1, 2px);\n}\n",
+    "a {\n  color: foo(1, 2px);\n}\n"
+);
+test!(
+    css_function_shadows_builtin,
+    "a {\n  color: call(get-function(rgb, $css: true), 1, 2, 3);\n}\n",
+    "a {\n  color: rgb(1, 2, 3);\n}\n"
+);
+test!(
+    css_function_keeps_underscores,
+    "a {\n  color: call(get-function(foo_bar, $css: true));\n}\n",
+    "a {\n  color: foo_bar();\n}\n"
+);
+test!(
+    type_of_css_function,
+    "a {\n  color: type-of(get-function(foo, $css: true));\n}\n",
+    "a {\n  color: function;\n}\n"
+);
+test!(
+    inspect_css_function,
+    "a {\n  color: inspect(get-function(foo, $css: true));\n}\n",
+    "a {\n  color: get-function(\"foo\");\n}\n"
+);
+test!(
+    css_function_not_equal_to_builtin,
+    "a {\n  color: get-function(rgb, $css: true) == get-function(rgb);\n  color: get-function(foo, $css: true) == get-function(foo, $css: true);\n}\n",
+    "a {\n  color: false;\n  color: true;\n}\n"
+);
+error!(
+    call_css_function_keyword_args,
+    "a {\n  color: call(get-function(foo, $css: true), $a: 1);\n}\n",
+    "Error: Plain CSS functions don't support keyword arguments."
+);
+error!(
+    interpolate_function_in_value,
+    "$f: get-function(darken);\na {\n  color: #{$f};\n}\n",
+    "Error: get-function(\"darken\") isn't a valid CSS value."
+);
+error!(
+    interpolate_function_in_quoted_string,
+    "$f: get-function(darken);\na {\n  color: \"#{$f}\";\n}\n",
+    "Error: get-function(\"darken\") isn't a valid CSS value."
+);
+error!(
+    interpolate_function_in_selector,
+    "$f: get-function(darken);\na#{$f} {\n  color: red;\n}\n",
+    "Error: get-function(\"darken\") isn't a valid CSS value."
+);
+error!(
+    emit_function_in_list,
+    "$f: get-function(darken);\na {\n  color: 1 $f;\n}\n",
+    "Error: get-function(\"darken\") isn't a valid CSS value."
+);
+test!(
+    inspect_function_in_list,
+    "$f: get-function(darken);\na {\n  color: inspect(1 $f);\n}\n",
+    "a {\n  color: 1 get-function(\"darken\");\n}\n"
+);
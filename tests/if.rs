@@ -140,6 +140,25 @@ test!(
     "@mixin foo {\n    color: red;\n}\n\n@if true {\n    a {\n        @include foo;\n    }\n}\n",
     "a {\n  color: red;\n}\n"
 );
+test!(
+    else_if_branch_without_trailing_semicolon,
+    "a {\n  @if false {\n    color: red\n  } @else if true {\n    color: blue\n  }\n}\n",
+    "a {\n  color: blue;\n}\n"
+);
+test!(
+    else_branch_without_trailing_semicolon,
+    "a {\n  @if false {\n    color: red\n  } @else {\n    color: blue\n  }\n}\n",
+    "a {\n  color: blue;\n}\n"
+);
+test!(
+    only_first_truthy_branch_is_evaluated,
+    "$x: 0;\n@if false {\n  $x: 1;\n} @else if true {\n  $x: 2;\n} @else if true {\n  $x: 3;\n} @else {\n  $x: $undefined;\n}\na {\n  color: $x;\n}\n",
+    "a {\n  color: 2;\n}\n"
+);
+error!(
+    unclosed_else_if_body,
+    "a { @if false {} @else if true { color: red", "Error: expected \"}\"."
+);
 error!(
     nothing_after_escape,
     "@if \\", "Error: Expected expression."
@@ -1,50 +1,16 @@
 #![cfg(test)]
-use std::io::Write;
-use tempfile::Builder;
+use std::path::PathBuf;
 
 #[macro_use]
 mod macros;
 
-/// Create a temporary file with the given name
-/// and contents.
-///
-/// This must be a macro rather than a function
-/// because the tempfile will be deleted when it
-/// exits scope
-macro_rules! tempfile {
-    ($name:literal, $content:literal) => {
-        let mut f = Builder::new()
-            .rand_bytes(0)
-            .prefix("")
-            .suffix($name)
-            .tempfile_in("")
-            .unwrap();
-        write!(f, "{}", $content).unwrap();
-    };
-    ($name:literal, $content:literal, dir=$dir:literal) => {
-        let _d = Builder::new()
-            .rand_bytes(0)
-            .prefix("")
-            .suffix($dir)
-            .tempdir_in("")
-            .unwrap();
-        let mut f = Builder::new()
-            .rand_bytes(0)
-            .prefix("")
-            .suffix($name)
-            .tempfile_in($dir)
-            .unwrap();
-        write!(f, "{}", $content).unwrap();
-    };
-}
-
 #[test]
 fn imports_variable() {
     let input = "@import \"imports_variable\";\na {\n color: $a;\n}";
     tempfile!("imports_variable", "$a: red;");
     assert_eq!(
         "a {\n  color: red;\n}\n",
-        &grass::from_string(input.to_string()).expect(input)
+        &grass::from_string(input.to_string(), &grass::Options::default()).expect(input)
     );
 }
 
@@ -54,7 +20,7 @@ fn import_no_semicolon() {
     tempfile!("import_no_semicolon", "$a: red;");
     assert_eq!(
         "a {\n  color: red;\n}\n",
-        &grass::from_string(input.to_string()).expect(input)
+        &grass::from_string(input.to_string(), &grass::Options::default()).expect(input)
     );
 }
 
@@ -62,7 +28,7 @@ fn import_no_semicolon() {
 fn import_no_quotes() {
     let input = "@import import_no_quotes";
     tempfile!("import_no_quotes", "$a: red;");
-    match grass::from_string(input.to_string()) {
+    match grass::from_string(input.to_string(), &grass::Options::default()) {
         Ok(..) => panic!("did not fail"),
         Err(e) => assert_eq!(
             "Error: Expected string.",
@@ -81,7 +47,7 @@ fn single_quotes_import() {
     tempfile!("single_quotes_import", "$a: red;");
     assert_eq!(
         "a {\n  color: red;\n}\n",
-        &grass::from_string(input.to_string()).expect(input)
+        &grass::from_string(input.to_string(), &grass::Options::default()).expect(input)
     );
 }
 
@@ -91,7 +57,7 @@ fn finds_name_scss() {
     tempfile!("finds_name_scss.scss", "$a: red;");
     assert_eq!(
         "a {\n  color: red;\n}\n",
-        &grass::from_string(input.to_string()).expect(input)
+        &grass::from_string(input.to_string(), &grass::Options::default()).expect(input)
     );
 }
 
@@ -101,7 +67,7 @@ fn finds_underscore_name_scss() {
     tempfile!("_finds_underscore_name_scss.scss", "$a: red;");
     assert_eq!(
         "a {\n  color: red;\n}\n",
-        &grass::from_string(input.to_string()).expect(input)
+        &grass::from_string(input.to_string(), &grass::Options::default()).expect(input)
     );
 }
 
@@ -113,7 +79,7 @@ fn chained_imports() {
     tempfile!("chained_imports__c.scss", "$a: red;");
     assert_eq!(
         "a {\n  color: red;\n}\n",
-        &grass::from_string(input.to_string()).expect(input)
+        &grass::from_string(input.to_string(), &grass::Options::default()).expect(input)
     );
 }
 
@@ -132,13 +98,488 @@ fn chained_imports_in_directory() {
     tempfile!("chained_imports_in_directory__c.scss", "$a: red;");
     assert_eq!(
         "a {\n  color: red;\n}\n",
-        &grass::from_string(input.to_string()).expect(input)
+        &grass::from_string(input.to_string(), &grass::Options::default()).expect(input)
+    );
+}
+
+#[test]
+fn compile_with_deps_lists_transitive_imports() {
+    tempfile!(
+        "compile_with_deps__main.scss",
+        "@import \"compile_with_deps__a\";\na {\n color: $a;\n}"
+    );
+    tempfile!(
+        "compile_with_deps__a.scss",
+        "@import \"compile_with_deps__b\";"
+    );
+    tempfile!("_compile_with_deps__b.scss", "$a: red;");
+    let (css, deps) =
+        grass::compile_with_deps("compile_with_deps__main.scss", &grass::Options::default())
+            .unwrap();
+    assert_eq!("a {\n  color: red;\n}\n", css);
+    assert_eq!(
+        vec![
+            PathBuf::from("compile_with_deps__main.scss"),
+            PathBuf::from("compile_with_deps__a.scss"),
+            PathBuf::from("_compile_with_deps__b.scss"),
+        ],
+        deps
+    );
+}
+
+#[test]
+fn compile_with_deps_lists_each_file_once() {
+    tempfile!(
+        "compile_with_deps_once__main.scss",
+        "@import \"compile_with_deps_once__a\";\n@import \"compile_with_deps_once__a\";"
+    );
+    tempfile!("compile_with_deps_once__a.scss", "$a: red;");
+    let (_, deps) = grass::compile_with_deps(
+        "compile_with_deps_once__main.scss",
+        &grass::Options::default(),
+    )
+    .unwrap();
+    assert_eq!(
+        vec![
+            PathBuf::from("compile_with_deps_once__main.scss"),
+            PathBuf::from("compile_with_deps_once__a.scss"),
+        ],
+        deps
+    );
+}
+
+#[test]
+fn import_cycle() {
+    let input = "@import \"import_cycle__a\";";
+    tempfile!("import_cycle__a.scss", "@import \"import_cycle__b\";");
+    tempfile!("import_cycle__b.scss", "@import \"import_cycle__a\";");
+    match grass::from_string(input.to_string(), &grass::Options::default()) {
+        Ok(..) => panic!("did not fail"),
+        Err(e) => assert_eq!(
+            "Error: Circular @import: import_cycle__a.scss \u{2192} import_cycle__b.scss \u{2192} import_cycle__a.scss",
+            e.to_string()
+                .chars()
+                .take_while(|c| *c != '\n')
+                .collect::<String>()
+                .as_str()
+        ),
+    }
+}
+
+#[test]
+fn import_same_file_twice_is_not_a_cycle() {
+    let input = "@import \"import_twice__a\";\n@import \"import_twice__a\";";
+    tempfile!("import_twice__a.scss", "a {\n color: red;\n}");
+    assert_eq!(
+        "a {\n  color: red;\n}\n\na {\n  color: red;\n}\n",
+        &grass::from_string(input.to_string(), &grass::Options::default()).expect(input)
+    );
+}
+
+#[test]
+fn import_with_media_type() {
+    let input = "@import \"import_with_media_type\" screen;";
+    tempfile!("import_with_media_type.scss", "a {\n color: red;\n}");
+    assert_eq!(
+        "@media screen {\n  a {\n    color: red;\n  }\n}\n",
+        &grass::from_string(input.to_string(), &grass::Options::default()).expect(input)
+    );
+}
+
+#[test]
+fn import_with_comma_separated_media_types() {
+    let input = "@import \"import_with_comma_separated_media_types\" screen, print;";
+    tempfile!(
+        "import_with_comma_separated_media_types.scss",
+        "a {\n color: red;\n}"
+    );
+    assert_eq!(
+        "@media screen, print {\n  a {\n    color: red;\n  }\n}\n",
+        &grass::from_string(input.to_string(), &grass::Options::default()).expect(input)
+    );
+}
+
+#[test]
+fn import_with_media_feature() {
+    let input = "@import \"import_with_media_feature\" (prefers-color-scheme: dark);";
+    tempfile!("import_with_media_feature.scss", "a {\n color: red;\n}");
+    assert_eq!(
+        "@media (prefers-color-scheme: dark) {\n  a {\n    color: red;\n  }\n}\n",
+        &grass::from_string(input.to_string(), &grass::Options::default()).expect(input)
+    );
+}
+
+#[test]
+fn import_with_media_type_and_feature() {
+    let input = "@import \"import_with_media_type_and_feature\" screen and (min-width: 10px);";
+    tempfile!(
+        "import_with_media_type_and_feature.scss",
+        "a {\n color: red;\n}"
+    );
+    assert_eq!(
+        "@media screen and (min-width: 10px) {\n  a {\n    color: red;\n  }\n}\n",
+        &grass::from_string(input.to_string(), &grass::Options::default()).expect(input)
+    );
+}
+
+#[test]
+fn import_with_media_feature_containing_variable() {
+    let input = "$scheme: dark;\n@import \"import_with_media_feature_var\" (prefers-color-scheme: $scheme);";
+    tempfile!("import_with_media_feature_var.scss", "a {\n color: red;\n}");
+    assert_eq!(
+        "@media (prefers-color-scheme: dark) {\n  a {\n    color: red;\n  }\n}\n",
+        &grass::from_string(input.to_string(), &grass::Options::default()).expect(input)
     );
 }
 
+#[test]
+fn import_with_media_inside_style_rule() {
+    let input = "a {\n @import \"import_with_media_inside_style_rule\" print;\n}";
+    tempfile!(
+        "import_with_media_inside_style_rule.scss",
+        "color: red;\nb {\n color: blue;\n}"
+    );
+    assert_eq!(
+        "@media print {\n  a {\n    color: red;\n  }\n  a b {\n    color: blue;\n  }\n}\n",
+        &grass::from_string(input.to_string(), &grass::Options::default()).expect(input)
+    );
+}
+
+test!(
+    plain_css_url_import,
+    "@import url(foo.css);\na {\n  color: red;\n}\n",
+    "@import url(foo.css);\na {\n  color: red;\n}\n"
+);
+test!(
+    plain_css_url_import_with_media,
+    "@import url(foo.css) screen, print;",
+    "@import url(foo.css) screen, print;\n"
+);
+test!(
+    plain_css_quoted_url_import_with_media,
+    "@import url(\"foo.css\") screen;",
+    "@import url(\"foo.css\") screen;\n"
+);
+test!(
+    plain_css_string_import_with_media,
+    "@import \"foo.css\" print and (orientation: landscape);",
+    "@import \"foo.css\" print and (orientation: landscape);\n"
+);
+test!(
+    plain_css_http_import,
+    "@import \"http://example.com/foo\";",
+    "@import \"http://example.com/foo\";\n"
+);
 error!(
     missing_input_after_import,
     "@import", "Error: expected more input."
 );
 
 // todo: test for calling paths, e.g. `grass b\index.scss`
+
+#[test]
+fn import_same_file_twice_duplicates_output_by_default() {
+    let input = "@import \"import_twice_default\";\n@import \"import_twice_default\";";
+    tempfile!("import_twice_default.scss", "a {\n color: red;\n}");
+    assert_eq!(
+        "a {\n  color: red;\n}\n\na {\n  color: red;\n}\n",
+        &grass::from_string(input.to_string(), &grass::Options::default()).expect(input)
+    );
+}
+
+#[test]
+fn dedup_imports_skips_second_import() {
+    let input = "@import \"import_twice_dedup\";\n@import \"_import_twice_dedup.scss\";\nb {\n color: $a;\n}";
+    tempfile!("_import_twice_dedup.scss", "$a: red;\na {\n color: $a;\n}");
+    assert_eq!(
+        "a {\n  color: red;\n}\n\nb {\n  color: red;\n}\n",
+        &grass::from_string(
+            input.to_string(),
+            &grass::Options::default().dedup_imports(true)
+        )
+        .expect(input)
+    );
+}
+
+#[test]
+fn dedup_imports_first_import_wins() {
+    let input = "a {\n @import \"import_dedup_nested\";\n}\n@import \"import_dedup_nested\";";
+    tempfile!("import_dedup_nested.scss", "color: red;");
+    assert_eq!(
+        "a {\n  color: red;\n}\n",
+        &grass::from_string(
+            input.to_string(),
+            &grass::Options::default().dedup_imports(true)
+        )
+        .expect(input)
+    );
+}
+
+#[test]
+fn dedup_imports_does_not_skip_distinct_files() {
+    let input = "@import \"import_dedup_distinct_a\";\n@import \"import_dedup_distinct_b\";";
+    tempfile!("import_dedup_distinct_a.scss", "a {\n color: red;\n}");
+    tempfile!("import_dedup_distinct_b.scss", "b {\n color: red;\n}");
+    assert_eq!(
+        "a {\n  color: red;\n}\n\nb {\n  color: red;\n}\n",
+        &grass::from_string(
+            input.to_string(),
+            &grass::Options::default().dedup_imports(true)
+        )
+        .expect(input)
+    );
+}
+
+/// Write `files` beneath a new temporary directory, then compile the first
+/// of them with `grass::from_path`
+fn compile_files(files: &[(&str, &str)]) -> String {
+    compile_files_with_deps(files, &grass::Options::default()).0
+}
+
+/// Compile the first of `files`, returning the output and the dependencies
+/// relative to the directory the files were written to
+fn compile_files_with_deps(
+    files: &[(&str, &str)],
+    options: &grass::Options,
+) -> (String, Vec<PathBuf>) {
+    let dir = tempfile::tempdir().unwrap();
+    for (path, contents) in files {
+        let path = dir.path().join(path);
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, contents).unwrap();
+    }
+    let input = dir.path().join(files[0].0);
+    let (css, deps) = grass::compile_with_deps(input.to_str().unwrap(), options).unwrap();
+    let deps = deps
+        .into_iter()
+        .map(|path| path.strip_prefix(dir.path()).unwrap().to_path_buf())
+        .collect();
+    (css, deps)
+}
+
+#[test]
+fn relative_import_chain_resolves_against_each_file() {
+    assert_eq!(
+        "a {\n  color: red;\n}\n",
+        compile_files(&[
+            ("src/main.scss", "@import \"components/button\";"),
+            (
+                "src/components/_button.scss",
+                "@import \"../base/mixins\";\na {\n color: $color;\n}"
+            ),
+            ("src/base/_mixins.scss", "@import \"../tokens\";"),
+            ("src/_tokens.scss", "$color: red;"),
+        ])
+    );
+}
+
+#[test]
+fn relative_use_chain_resolves_against_each_file() {
+    assert_eq!(
+        "a {\n  color: red;\n}\n",
+        compile_files(&[
+            ("src/main.scss", "@use \"components/button\";"),
+            (
+                "src/components/_button.scss",
+                "@use \"../base/forward\";\na {\n color: forward.$color;\n}"
+            ),
+            ("src/base/_forward.scss", "@forward \"../tokens\";"),
+            ("src/_tokens.scss", "$color: red;"),
+        ])
+    );
+}
+
+#[test]
+fn load_css_in_mixin_resolves_against_declaring_file() {
+    assert_eq!(
+        "a {\n  color: red;\n}\n",
+        compile_files(&[
+            ("main.scss", "@use \"lib/loader\";\n@include loader.load;"),
+            (
+                "lib/_loader.scss",
+                "@use \"sass:meta\";\n@mixin load {\n @include meta.load-css(\"theme\");\n}"
+            ),
+            ("lib/_theme.scss", "a {\n color: red;\n}"),
+        ])
+    );
+}
+
+#[test]
+fn load_css_in_content_block_resolves_against_including_file() {
+    assert_eq!(
+        "a {\n  color: red;\n}\n",
+        compile_files(&[
+            (
+                "main.scss",
+                "@use \"sass:meta\";\n@use \"lib/wrapper\";\n@include wrapper.wrap {\n @include meta.load-css(\"theme\");\n}"
+            ),
+            ("lib/_wrapper.scss", "@mixin wrap {\n @content;\n}"),
+            ("_theme.scss", "a {\n color: red;\n}"),
+        ])
+    );
+}
+
+#[test]
+fn import_glob_imports_matching_files_in_order() {
+    let (css, deps) = compile_files_with_deps(
+        &[
+            ("main.scss", "@import \"components/**\";"),
+            ("components/_button.scss", "a {\n color: red;\n}"),
+            ("components/card.scss", "b {\n color: red;\n}"),
+            ("components/nav/menu.scss", "c {\n color: red;\n}"),
+            ("components/readme.md", "not scss"),
+        ],
+        &grass::Options::default().allow_import_globs(true),
+    );
+    assert_eq!(
+        "a {\n  color: red;\n}\n\nb {\n  color: red;\n}\n\nc {\n  color: red;\n}\n",
+        css
+    );
+    assert_eq!(
+        vec![
+            PathBuf::from("main.scss"),
+            PathBuf::from("components/_button.scss"),
+            PathBuf::from("components/card.scss"),
+            PathBuf::from("components/nav/menu.scss"),
+        ],
+        deps
+    );
+}
+
+#[test]
+fn import_glob_with_wildcard_file_name() {
+    let (css, ..) = compile_files_with_deps(
+        &[
+            ("main.scss", "@import \"themes/*-dark\";"),
+            ("themes/blue-dark.scss", "a {\n color: blue;\n}"),
+            ("themes/blue-light.scss", "a {\n color: white;\n}"),
+        ],
+        &grass::Options::default().allow_import_globs(true),
+    );
+    assert_eq!("a {\n  color: blue;\n}\n", css);
+}
+
+#[test]
+fn import_glob_with_character_class_within_nested_directories() {
+    let (css, ..) = compile_files_with_deps(
+        &[
+            ("main.scss", "@import \"src/**/[!c]?\";"),
+            ("src/a1.scss", "a {\n color: red;\n}"),
+            ("src/nested/b2.scss", "b {\n color: red;\n}"),
+            ("src/nested/c3.scss", "c {\n color: red;\n}"),
+            ("src/nested/a10.scss", "d {\n color: red;\n}"),
+        ],
+        &grass::Options::default().allow_import_globs(true),
+    );
+    assert_eq!("a {\n  color: red;\n}\n\nb {\n  color: red;\n}\n", css);
+}
+
+#[test]
+fn import_glob_disabled_by_default() {
+    let input = "@import \"import_glob_disabled__*\";";
+    tempfile!("import_glob_disabled__a.scss", "a {\n color: red;\n}");
+    match grass::from_string(input.to_string(), &grass::Options::default()) {
+        Ok(..) => panic!("did not fail"),
+        Err(e) => assert_eq!(
+            "Error: Can't find stylesheet to import.",
+            e.to_string().lines().next().unwrap()
+        ),
+    }
+}
+
+#[test]
+fn import_from_node_modules() {
+    let (css, deps) = compile_files_with_deps(
+        &[
+            ("src/main.scss", "@import \"~bootstrap/scss/bootstrap\";"),
+            (
+                "node_modules/bootstrap/scss/bootstrap.scss",
+                "@import \"variables\";\na {\n color: $primary;\n}",
+            ),
+            (
+                "node_modules/bootstrap/scss/_variables.scss",
+                "$primary: blue;",
+            ),
+        ],
+        &grass::Options::default().node_modules_resolution(true),
+    );
+    assert_eq!("a {\n  color: blue;\n}\n", css);
+    assert_eq!(
+        vec![
+            PathBuf::from("src/main.scss"),
+            PathBuf::from("node_modules/bootstrap/scss/bootstrap.scss"),
+            PathBuf::from("node_modules/bootstrap/scss/_variables.scss"),
+        ],
+        deps
+    );
+}
+
+#[test]
+fn import_from_closest_node_modules() {
+    let (css, ..) = compile_files_with_deps(
+        &[
+            ("packages/app/main.scss", "@import \"~theme\";"),
+            (
+                "packages/app/node_modules/theme/_index.scss",
+                "a {\n color: red;\n}",
+            ),
+            ("node_modules/theme/_index.scss", "a {\n color: blue;\n}"),
+        ],
+        &grass::Options::default().node_modules_resolution(true),
+    );
+    assert_eq!("a {\n  color: red;\n}\n", css);
+}
+
+#[test]
+fn use_from_node_modules() {
+    let (css, ..) = compile_files_with_deps(
+        &[
+            (
+                "main.scss",
+                "@use \"~theme/colors\";\na {\n color: colors.$primary;\n}",
+            ),
+            ("node_modules/theme/_colors.scss", "$primary: red;"),
+        ],
+        &grass::Options::default().node_modules_resolution(true),
+    );
+    assert_eq!("a {\n  color: red;\n}\n", css);
+}
+
+error!(
+    import_node_modules_disabled_by_default,
+    "@import \"~import_node_modules_disabled\";", "Error: Can't find stylesheet to import."
+);
+
+#[test]
+fn import_inside_mixin() {
+    let input =
+        "@mixin foo {\n @import \"import_inside_mixin\";\n color: $a;\n}\na {\n @include foo;\n}";
+    tempfile!(
+        "import_inside_mixin.scss",
+        "$a: red;\nb {\n color: blue;\n}"
+    );
+    assert_eq!(
+        "a {\n  color: red;\n}\na b {\n  color: blue;\n}\n",
+        &grass::from_string(input.to_string(), &grass::Options::default()).expect(input)
+    );
+}
+
+#[test]
+fn import_inside_mixin_is_evaluated_each_include() {
+    let input = "@mixin foo {\n @import \"import_inside_mixin_each\";\n}\na {\n @include foo;\n}\nb {\n @include foo;\n}";
+    tempfile!("import_inside_mixin_each.scss", "color: red;");
+    assert_eq!(
+        "a {\n  color: red;\n}\n\nb {\n  color: red;\n}\n",
+        &grass::from_string(input.to_string(), &grass::Options::default()).expect(input)
+    );
+}
+
+error!(
+    import_missing_file,
+    "@import \"import_missing_file\";", "Error: Can't find stylesheet to import."
+);
+error!(
+    import_missing_file_inside_mixin,
+    "@mixin foo {\n @import \"import_missing_file_inside_mixin\";\n}\na {\n @include foo;\n}",
+    "Error: Can't find stylesheet to import."
+);
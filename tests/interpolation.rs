@@ -77,3 +77,72 @@ error!(
     error_message_when_at_start_of_value,
     "a {\n  color: #{2px*5px};\n}\n", "Error: 10px*px isn't a valid CSS value."
 );
+test!(
+    variable_in_quoted_string,
+    "$name: world;\na {\n  color: \"Hello, #{$name}!\";\n}\n",
+    "a {\n  color: \"Hello, world!\";\n}\n"
+);
+test!(
+    color_in_quoted_string,
+    "$c: red;\n$d: #abc;\na {\n  color: \"color: #{$c} #{$d}\";\n}\n",
+    "a {\n  color: \"color: red #abc\";\n}\n"
+);
+test!(
+    null_in_quoted_string,
+    "a {\n  color: \"#{null}\";\n}\n",
+    "a {\n  color: \"\";\n}\n"
+);
+test!(
+    quoted_string_in_quoted_string,
+    "a {\n  color: \"#{\"nested\"}\";\n}\n",
+    "a {\n  color: \"nested\";\n}\n"
+);
+test!(
+    escaped_hash_in_quoted_string_is_not_interpolated,
+    "a {\n  color: \"\\#{skip}\";\n}\n",
+    "a {\n  color: \"#{skip}\";\n}\n"
+);
+test!(
+    list_in_quoted_string,
+    "a {\n  color: \"#{1 + 2} #{(1, 2)}\";\n}\n",
+    "a {\n  color: \"3 1, 2\";\n}\n"
+);
+error!(
+    map_in_quoted_string,
+    "a {\n  color: \"#{(a: b)}\";\n}\n", "Error: (a: b) isn't a valid CSS value."
+);
+test!(
+    null_in_selector,
+    "a-#{null}-b {\n  color: red;\n}\n",
+    "a--b {\n  color: red;\n}\n"
+);
+test!(
+    empty_list_in_selector,
+    "a-#{()}-b {\n  color: red;\n}\n",
+    "a--b {\n  color: red;\n}\n"
+);
+test!(
+    null_in_quoted_string_with_text,
+    "a {\n  color: \"text #{null} more\";\n}\n",
+    "a {\n  color: \"text  more\";\n}\n"
+);
+test!(
+    null_in_unquoted_value,
+    "a {\n  color: a#{null}b;\n}\n",
+    "a {\n  color: ab;\n}\n"
+);
+test!(
+    only_null_value_not_emitted,
+    "a {\n  width: #{null};\n  color: red;\n}\n",
+    "a {\n  color: red;\n}\n"
+);
+test!(
+    only_empty_list_value_not_emitted,
+    "a {\n  width: #{()};\n  color: red;\n}\n",
+    "a {\n  color: red;\n}\n"
+);
+test!(
+    false_is_not_empty,
+    "a-#{false} {\n  color: #{false};\n}\n",
+    "a-false {\n  color: false;\n}\n"
+);
@@ -121,3 +121,42 @@ test!(
     }",
     "@keyframes {\n  to {\n    color: red;\n  }\n  from {\n    color: green;\n  }\n}\n"
 );
+test!(
+    keyframes_include_mixin_with_stops,
+    "@mixin rotate-keyframes {\n  from {\n    transform: rotate(0deg);\n  }\n  to {\n    transform: rotate(360deg);\n  }\n}\n@keyframes spin {\n  @include rotate-keyframes;\n}\n",
+    "@keyframes spin {\n  from {\n    transform: rotate(0deg);\n  }\n  to {\n    transform: rotate(360deg);\n  }\n}\n"
+);
+test!(
+    keyframes_include_nested_mixins_inside_style_rule,
+    "@mixin stop($at) {\n  #{$at} {\n    opacity: 1;\n  }\n}\n@mixin fade {\n  @include stop(from);\n  @include stop(50%);\n}\na {\n  color: red;\n  @keyframes fade {\n    @include fade;\n  }\n}\n",
+    "a {\n  color: red;\n}\n@keyframes fade {\n  from {\n    opacity: 1;\n  }\n  50% {\n    opacity: 1;\n  }\n}\n"
+);
+test!(
+    keyframes_stops_passed_as_content,
+    "@mixin keyframes($name) {\n  @keyframes #{$name} {\n    @content;\n  }\n}\na {\n  @include keyframes(spin) {\n    0%, 50.5% {\n      color: red;\n    }\n  }\n}\n",
+    "@keyframes spin {\n  0%, 50.5% {\n    color: red;\n  }\n}\n"
+);
+test!(
+    keyframes_percentage_with_decimal_and_exponent,
+    "@keyframes foo {\n  12.5%, .5%, +5%, 1e1% {\n    color: red;\n  }\n}\n",
+    "@keyframes foo {\n  12.5%, .5%, +5%, 1e1% {\n    color: red;\n  }\n}\n"
+);
+error!(
+    keyframes_sign_without_number,
+    "@keyframes foo {\n  +% {\n    color: red;\n  }\n}\n", "Error: Expected number."
+);
+test!(
+    keyframes_in_mixin_included_in_style_rule_is_hoisted,
+    "@mixin spin {\n  animation: spin 1s;\n  @keyframes spin {\n    to {\n      transform: rotate(360deg);\n    }\n  }\n}\na {\n  color: red;\n  @include spin;\n  width: 1px;\n}\n",
+    "a {\n  color: red;\n  animation: spin 1s;\n  width: 1px;\n}\n@keyframes spin {\n  to {\n    transform: rotate(360deg);\n  }\n}\n"
+);
+test!(
+    keyframes_in_mixin_included_in_nested_style_rule_is_hoisted,
+    "@mixin spin {\n  @keyframes spin {\n    to {\n      color: red;\n    }\n  }\n}\na {\n  b {\n    @include spin;\n    color: red;\n  }\n}\n",
+    "a b {\n  color: red;\n}\n@keyframes spin {\n  to {\n    color: red;\n  }\n}\n"
+);
+test!(
+    keyframes_in_mixin_stays_inside_media,
+    "@mixin spin {\n  @keyframes spin {\n    to {\n      color: red;\n    }\n  }\n}\n@media screen {\n  a {\n    @include spin;\n  }\n}\n",
+    "@media screen {\n  @keyframes spin {\n    to {\n      color: red;\n    }\n  }\n}\n"
+);
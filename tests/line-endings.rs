@@ -0,0 +1,79 @@
+#![cfg(test)]
+
+use grass::{LineEnding, Options};
+
+fn compile(input: &str, line_ending: LineEnding) -> String {
+    grass::from_string(
+        input.to_string(),
+        &Options::default().line_ending(line_ending),
+    )
+    .expect(input)
+}
+
+#[test]
+fn lf_by_default() {
+    let input = "a {\r\n  color: red;\r\n}\r\n";
+    assert_eq!(
+        "a {\n  color: red;\n}\n",
+        &grass::from_string(input.to_string(), &Options::default()).expect(input)
+    );
+}
+
+#[test]
+fn crlf() {
+    assert_eq!(
+        "a {\r\n  color: red;\r\n}\r\n\r\nb {\r\n  color: blue;\r\n}\r\n",
+        compile(
+            "a {\n  color: red;\n}\n\nb {\n  color: blue;\n}\n",
+            LineEnding::CrLf
+        )
+    );
+}
+
+#[test]
+fn crlf_with_charset() {
+    assert_eq!(
+        "@charset \"UTF-8\";\r\na {\r\n  content: \"é\";\r\n}\r\n",
+        compile("a {\n  content: \"é\";\n}\n", LineEnding::CrLf)
+    );
+}
+
+#[test]
+fn crlf_multiline_comment() {
+    assert_eq!(
+        "/* a\r\n * b\r\n */\r\na {\r\n  color: red;\r\n}\r\n",
+        compile("/* a\n * b\n */\na {\n  color: red;\n}\n", LineEnding::CrLf)
+    );
+}
+
+#[test]
+fn auto_detects_crlf() {
+    assert_eq!(
+        "a {\r\n  color: red;\r\n}\r\n",
+        compile("a {\r\n  color: red;\r\n}\r\n", LineEnding::Auto)
+    );
+}
+
+#[test]
+fn auto_detects_lf() {
+    assert_eq!(
+        "a {\n  color: red;\n}\n",
+        compile("a {\n  color: red;\r\n}\r\n", LineEnding::Auto)
+    );
+}
+
+#[test]
+fn auto_without_newline_is_lf() {
+    assert_eq!(
+        "a {\n  color: red;\n}\n",
+        compile("a { color: red; }", LineEnding::Auto)
+    );
+}
+
+#[test]
+fn lf_normalizes_crlf_input() {
+    assert_eq!(
+        "a {\n  color: red;\n}\n",
+        compile("a {\r\n  color: red;\r\n}\r\n", LineEnding::Lf)
+    );
+}
@@ -0,0 +1,150 @@
+#![cfg(test)]
+
+#[macro_use]
+mod macros;
+
+/// Calls which should produce the same value through the `sass:list` module
+/// as through the equivalent global function, as
+/// `(module function, global function, arguments)`
+const GLOBAL_ALIASES: &[(&str, &str, &str)] = &[
+    ("append", "append", "1 2, 3"),
+    ("append", "append", "(1, 2), 3"),
+    ("append", "append", "1 2, 3, comma"),
+    ("append", "append", "[1 2], 3"),
+    ("append", "append", "(), 1"),
+    ("index", "index", "a b c, b"),
+    ("index", "index", "a b c, d"),
+    ("is-bracketed", "is-bracketed", "[a b]"),
+    ("is-bracketed", "is-bracketed", "a b"),
+    ("join", "join", "a b, c d"),
+    ("join", "join", "(a, b), c d"),
+    ("join", "join", "a b, c d, comma, true"),
+    ("length", "length", "a b c"),
+    ("length", "length", "(a: b, c: d)"),
+    ("nth", "nth", "a b c, -1"),
+    ("separator", "list-separator", "(a, b)"),
+    ("separator", "list-separator", "a"),
+    ("set-nth", "set-nth", "a b c, 2, d"),
+    ("zip", "zip", "1px 2px, 3px 4px"),
+];
+
+fn compile(input: String) -> String {
+    grass::from_string(input.clone(), &grass::Options::default())
+        .unwrap_or_else(|e| panic!("failed to compile {:?}: {}", input, e))
+}
+
+#[test]
+fn module_functions_match_global_functions() {
+    for (module_fn, global_fn, args) in GLOBAL_ALIASES {
+        let namespaced = compile(format!(
+            "@use \"sass:list\";\na {{\n  color: inspect(list.{}({}));\n}}\n",
+            module_fn, args
+        ));
+        let global = compile(format!(
+            "a {{\n  color: inspect({}({}));\n}}\n",
+            global_fn, args
+        ));
+        assert_eq!(global, namespaced, "list.{}({})", module_fn, args);
+    }
+}
+
+test!(
+    list_append,
+    "@use \"sass:list\";\na {\n  color: list.append(a b, c);\n}\n",
+    "a {\n  color: a b c;\n}\n"
+);
+test!(
+    list_separator,
+    "@use \"sass:list\";\na {\n  color: list.separator((a, b));\n}\n",
+    "a {\n  color: comma;\n}\n"
+);
+test!(
+    list_global_aliases_remain,
+    "@use \"sass:list\";\na {\n  color: list-separator((a, b));\n}\n",
+    "a {\n  color: comma;\n}\n"
+);
+test!(
+    list_slash,
+    "@use \"sass:list\";\na {\n  font: list.slash(12px, 1.5);\n}\n",
+    "a {\n  font: 12px / 1.5;\n}\n"
+);
+test!(
+    list_slash_separator,
+    "@use \"sass:list\";\na {\n  color: list.separator(list.slash(a, b, c));\n}\n",
+    "a {\n  color: slash;\n}\n"
+);
+test!(
+    list_append_to_slash_list,
+    "@use \"sass:list\";\na {\n  color: list.append(list.slash(a, b), c);\n}\n",
+    "a {\n  color: a / b / c;\n}\n"
+);
+test!(
+    list_join_slash_separator,
+    "@use \"sass:list\";\na {\n  color: list.join(a b, c, $separator: slash);\n}\n",
+    "a {\n  color: a / b / c;\n}\n"
+);
+test!(
+    list_first,
+    "@use \"sass:list\";\na {\n  color: list.first(a b c);\n}\n",
+    "a {\n  color: a;\n}\n"
+);
+test!(
+    list_last,
+    "@use \"sass:list\";\na {\n  color: list.last(a b c);\n}\n",
+    "a {\n  color: c;\n}\n"
+);
+test!(
+    list_first_of_single_value,
+    "@use \"sass:list\";\na {\n  color: list.first(a);\n}\n",
+    "a {\n  color: a;\n}\n"
+);
+error!(
+    list_slash_one_element,
+    "@use \"sass:list\";\na {\n  color: list.slash(a b);\n}\n",
+    "Error: At least two elements are required."
+);
+error!(
+    list_first_empty,
+    "@use \"sass:list\";\na {\n  color: list.first(());\n}\n",
+    "Error: $list: List may not be empty."
+);
+error!(
+    list_append_invalid_separator,
+    "@use \"sass:list\";\na {\n  color: list.append(a, b, foo);\n}\n",
+    "Error: $separator: Must be \"space\", \"comma\", \"slash\", or \"auto\"."
+);
+test!(
+    list_append_chained_from_empty_list,
+    "@use \"sass:list\";\na {\n  color: inspect(list.append(list.append((), 1), 2));\n}\n",
+    "a {\n  color: 1 2;\n}\n"
+);
+test!(
+    list_append_chained_from_empty_list_with_comma,
+    "@use \"sass:list\";\na {\n  color: inspect(list.append(list.append((), 1, comma), 2));\n}\n",
+    "a {\n  color: 1, 2;\n}\n"
+);
+test!(
+    list_append_to_empty_list_with_comma,
+    "@use \"sass:list\";\na {\n  color: inspect(list.append((), 1, comma));\n}\n",
+    "a {\n  color: (1,);\n}\n"
+);
+test!(
+    empty_list_is_not_null,
+    "@use \"sass:list\";\na {\n  color: list.length(());\n  color: type-of(());\n  color: () == null;\n}\n",
+    "a {\n  color: 0;\n  color: list;\n  color: false;\n}\n"
+);
+test!(
+    empty_list_separator_is_space,
+    "@use \"sass:list\";\na {\n  color: list.separator(());\n}\n",
+    "a {\n  color: space;\n}\n"
+);
+test!(
+    join_empty_lists,
+    "@use \"sass:list\";\na {\n  color: inspect(list.join((), ()));\n}\n",
+    "a {\n  color: ();\n}\n"
+);
+test!(
+    index_in_empty_list,
+    "@use \"sass:list\";\na {\n  color: inspect(list.index((), 1));\n}\n",
+    "a {\n  color: null;\n}\n"
+);
@@ -7,7 +7,7 @@ macro_rules! test {
         #[test]
         #[allow(non_snake_case)]
         fn $func() {
-            let sass = grass::from_string($input.to_string())
+            let sass = grass::from_string($input.to_string(), &grass::Options::default())
                 .expect(concat!("failed to parse on ", $input));
             assert_eq!(
                 String::from($input),
@@ -20,7 +20,7 @@ macro_rules! test {
         #[test]
         #[allow(non_snake_case)]
         fn $func() {
-            let sass = grass::from_string($input.to_string())
+            let sass = grass::from_string($input.to_string(), &grass::Options::default())
                 .expect(concat!("failed to parse on ", $input));
             assert_eq!(
                 String::from($output),
@@ -39,7 +39,7 @@ macro_rules! error {
         #[test]
         #[allow(non_snake_case)]
         fn $func() {
-            match grass::from_string($input.to_string()) {
+            match grass::from_string($input.to_string(), &grass::Options::default()) {
                 Ok(..) => panic!("did not fail"),
                 Err(e) => assert_eq!($err, e.to_string()
                                                 .chars()
@@ -51,3 +51,37 @@ macro_rules! error {
         }
     };
 }
+
+/// Create a temporary file with the given name
+/// and contents.
+///
+/// This must be a macro rather than a function
+/// because the tempfile will be deleted when it
+/// exits scope
+#[macro_export]
+macro_rules! tempfile {
+    ($name:literal, $content:literal) => {
+        let mut f = tempfile::Builder::new()
+            .rand_bytes(0)
+            .prefix("")
+            .suffix($name)
+            .tempfile_in("")
+            .unwrap();
+        std::io::Write::write_fmt(&mut f, format_args!("{}", $content)).unwrap();
+    };
+    ($name:literal, $content:literal, dir=$dir:literal) => {
+        let _d = tempfile::Builder::new()
+            .rand_bytes(0)
+            .prefix("")
+            .suffix($dir)
+            .tempdir_in("")
+            .unwrap();
+        let mut f = tempfile::Builder::new()
+            .rand_bytes(0)
+            .prefix("")
+            .suffix($name)
+            .tempfile_in($dir)
+            .unwrap();
+        std::io::Write::write_fmt(&mut f, format_args!("{}", $content)).unwrap();
+    };
+}
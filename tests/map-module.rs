@@ -0,0 +1,56 @@
+#![cfg(test)]
+
+#[macro_use]
+mod macros;
+
+/// Calls which should produce the same value through the `sass:map` module
+/// as through the equivalent global function, as
+/// `(module function, global function, arguments)`
+const GLOBAL_ALIASES: &[(&str, &str, &str)] = &[
+    ("get", "map-get", "(a: 1, b: 2), b"),
+    ("get", "map-get", "(a: 1), c"),
+    ("has-key", "map-has-key", "(a: 1), a"),
+    ("has-key", "map-has-key", "(a: 1), b"),
+    ("keys", "map-keys", "(a: 1, b: 2)"),
+    ("values", "map-values", "(a: 1, b: 2)"),
+    ("merge", "map-merge", "(a: 1), (b: 2)"),
+    ("merge", "map-merge", "(a: 1), (a: 2)"),
+    ("remove", "map-remove", "(a: 1, b: 2), a"),
+    ("remove", "map-remove", "(a: 1, b: 2), a, b"),
+];
+
+fn compile(input: String) -> String {
+    grass::from_string(input.clone(), &grass::Options::default())
+        .unwrap_or_else(|e| panic!("failed to compile {:?}: {}", input, e))
+}
+
+#[test]
+fn module_functions_match_global_functions() {
+    for (module_fn, global_fn, args) in GLOBAL_ALIASES {
+        let namespaced = compile(format!(
+            "@use \"sass:map\";\na {{\n  color: inspect(map.{}({}));\n}}\n",
+            module_fn, args
+        ));
+        let global = compile(format!(
+            "a {{\n  color: inspect({}({}));\n}}\n",
+            global_fn, args
+        ));
+        assert_eq!(global, namespaced, "map.{}({})", module_fn, args);
+    }
+}
+
+test!(
+    map_get,
+    "@use \"sass:map\";\na {\n  color: map.get((a: red), a);\n}\n",
+    "a {\n  color: red;\n}\n"
+);
+test!(
+    map_with_namespace,
+    "@use \"sass:map\" as m;\na {\n  color: m.get((a: red), a);\n}\n",
+    "a {\n  color: red;\n}\n"
+);
+test!(
+    map_global_aliases_remain,
+    "@use \"sass:map\";\na {\n  color: map-get((a: red), a);\n}\n",
+    "a {\n  color: red;\n}\n"
+);
@@ -182,3 +182,23 @@ test!(
     "$a: (foo: red, ); a {\n  color: inspect($a);\n}\n",
     "a {\n  color: (foo: red);\n}\n"
 );
+test!(
+    map_remove_uses_sass_equality,
+    "a {\n  color: inspect(map-remove((1in: a, b: c), 96px));\n}\n",
+    "a {\n  color: (b: c);\n}\n"
+);
+test!(
+    map_remove_returns_map_when_key_missing,
+    "a {\n  color: inspect(map-remove((a: b), c));\n}\n",
+    "a {\n  color: (a: b);\n}\n"
+);
+test!(
+    map_merge_uses_sass_equality,
+    "a {\n  color: inspect(map-merge((1in: a, c: d), (96px: b, e: f)));\n}\n",
+    "a {\n  color: (1in: b, c: d, e: f);\n}\n"
+);
+test!(
+    map_has_key_uses_sass_equality,
+    "a {\n  color: map-has-key((1in: a), 96px);\n}\n",
+    "a {\n  color: true;\n}\n"
+);
@@ -0,0 +1,89 @@
+#![cfg(test)]
+
+#[macro_use]
+mod macros;
+
+/// Calls which should produce the same value through the `sass:math` module
+/// as through the equivalent global function, as
+/// `(module function, global function, arguments)`
+const GLOBAL_ALIASES: &[(&str, &str, &str)] = &[
+    ("abs", "abs", "-1px"),
+    ("ceil", "ceil", "1.2"),
+    ("floor", "floor", "1.8"),
+    ("round", "round", "1.5"),
+    ("percentage", "percentage", "0.5"),
+    ("compatible", "comparable", "1px, 1in"),
+    ("compatible", "comparable", "1px, 1s"),
+    ("unit", "unit", "1px"),
+    ("is-unitless", "unitless", "1"),
+    ("is-unitless", "unitless", "1px"),
+];
+
+fn compile(input: String) -> String {
+    grass::from_string(input.clone(), &grass::Options::default())
+        .unwrap_or_else(|e| panic!("failed to compile {:?}: {}", input, e))
+}
+
+#[test]
+fn module_functions_match_global_functions() {
+    for (module_fn, global_fn, args) in GLOBAL_ALIASES {
+        let namespaced = compile(format!(
+            "@use \"sass:math\";\na {{\n  color: inspect(math.{}({}));\n}}\n",
+            module_fn, args
+        ));
+        let global = compile(format!(
+            "a {{\n  color: inspect({}({}));\n}}\n",
+            global_fn, args
+        ));
+        assert_eq!(global, namespaced, "math.{}({})", module_fn, args);
+    }
+}
+
+test!(
+    pi,
+    "@use \"sass:math\";\na {\n  color: math.$pi;\n}\n",
+    "a {\n  color: 3.1415926536;\n}\n"
+);
+test!(
+    e,
+    "@use \"sass:math\";\na {\n  color: math.$e;\n}\n",
+    "a {\n  color: 2.7182818285;\n}\n"
+);
+test!(
+    pi_in_arithmetic,
+    "@use \"sass:math\";\n$radius: 2px;\na {\n  color: 2 * math.$pi * $radius;\n}\n",
+    "a {\n  color: 12.5663706144px;\n}\n"
+);
+test!(
+    pi_through_global_use,
+    "@use \"sass:math\" as *;\na {\n  color: $pi;\n}\n",
+    "a {\n  color: 3.1415926536;\n}\n"
+);
+test!(
+    constants_in_module_variables,
+    "@use \"sass:math\";\n@use \"sass:meta\";\na {\n  color: meta.inspect(meta.module-variables(math));\n}\n",
+    "a {\n  color: (\"e\": 2.7182818285, \"pi\": 3.1415926536);\n}\n"
+);
+test!(
+    max_is_evaluated,
+    "@use \"sass:math\";\na {\n  color: math.max(1px, 3px, 2px);\n}\n",
+    "a {\n  color: 3px;\n}\n"
+);
+test!(
+    min_is_evaluated,
+    "@use \"sass:math\";\na {\n  color: math.min(1px, 3px, 2px);\n}\n",
+    "a {\n  color: 1px;\n}\n"
+);
+error!(
+    assign_pi,
+    "@use \"sass:math\";\nmath.$pi: 3;\n", "Error: Cannot modify variable in other module."
+);
+error!(
+    assign_e_inside_style_rule,
+    "@use \"sass:math\";\na {\n  math.$e: 3;\n}\n",
+    "Error: Cannot modify variable in other module."
+);
+error!(
+    undefined_module_variable,
+    "@use \"sass:math\";\na {\n  color: math.$tau;\n}\n", "Error: Undefined variable."
+);
@@ -104,12 +104,19 @@ test!(
     "a {\n  color: true;\n}\n"
 );
 test!(
+    #[cfg(feature = "random")]
     random_limit_one,
     "a {\n  color: random(1);\n}\n",
     "a {\n  color: 1;\n}\n"
 );
 test!(
+    #[cfg(feature = "random")]
     random_limit_big_one,
     "a {\n  color: random(1000000000000000001 - 1000000000000000000);\n}\n",
     "a {\n  color: 1;\n}\n"
 );
+error!(
+    #[cfg(not(feature = "random"))]
+    random_without_random_feature,
+    "a {\n  color: random();\n}\n", "Error: grass was built without the 'random' feature"
+);
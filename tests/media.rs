@@ -17,6 +17,31 @@ test!(
     "@media foo {\n  a {\n    color: red;\n  }\n}\n"
 );
 test!(empty_body, "@media (min-width: 2px) {}", "");
+test!(
+    body_of_empty_rulesets,
+    "@media screen {\n  a {}\n  b {\n    color: null;\n  }\n}\n",
+    ""
+);
+test!(
+    nested_in_style_rule_empty_body,
+    "a {\n  @media screen {\n    b {}\n  }\n}\n",
+    ""
+);
+test!(
+    body_of_only_placeholders,
+    "@media screen {\n  %a {\n    color: red;\n  }\n}\n",
+    ""
+);
+test!(
+    nested_media_empty_body,
+    "@media screen {\n  @media (min-width: 2px) {\n    a {}\n  }\n}\n",
+    ""
+);
+test!(
+    empty_media_between_styles_emits_one_newline,
+    "a {\n  color: red;\n}\n\n@media screen {\n  b {}\n}\n\nc {\n  color: red;\n}\n",
+    "a {\n  color: red;\n}\n\nc {\n  color: red;\n}\n"
+);
 test!(
     newlines_are_not_emitted_for_child_styles,
     "a {
@@ -0,0 +1,259 @@
+#![cfg(test)]
+
+#[macro_use]
+mod macros;
+
+/// Calls which should produce the same value through the `sass:meta` module
+/// as through the global function of the same name, as `(function, arguments)`
+const GLOBAL_ALIASES: &[(&str, &str)] = &[
+    ("type-of", "1px"),
+    ("type-of", "(a: b)"),
+    ("inspect", "(1, 2)"),
+    ("inspect", "null"),
+    ("feature-exists", "at-error"),
+    ("feature-exists", "foo"),
+    ("variable-exists", "foo"),
+    ("global-variable-exists", "foo"),
+    ("function-exists", "rgb"),
+    ("function-exists", "foo"),
+    ("mixin-exists", "foo"),
+    ("call", "get-function(unquote), \"a\""),
+];
+
+fn compile(input: String) -> String {
+    grass::from_string(input.clone(), &grass::Options::default())
+        .unwrap_or_else(|e| panic!("failed to compile {:?}: {}", input, e))
+}
+
+#[test]
+fn module_functions_match_global_functions() {
+    for (function, args) in GLOBAL_ALIASES {
+        let namespaced = compile(format!(
+            "@use \"sass:meta\";\na {{\n  color: inspect(meta.{}({}));\n}}\n",
+            function, args
+        ));
+        let global = compile(format!(
+            "a {{\n  color: inspect({}({}));\n}}\n",
+            function, args
+        ));
+        assert_eq!(global, namespaced, "meta.{}({})", function, args);
+    }
+}
+
+test!(
+    type_of_through_namespace,
+    "@use \"sass:meta\";\na {\n  color: meta.type-of(1px);\n}\n",
+    "a {\n  color: number;\n}\n"
+);
+test!(
+    type_of_through_custom_namespace,
+    "@use \"sass:meta\" as m;\na {\n  color: m.type-of(red);\n}\n",
+    "a {\n  color: color;\n}\n"
+);
+test!(
+    meta_functions_through_global_use,
+    "@use \"sass:meta\" as *;\n@use \"sass:string\";\na {\n  color: map-has-key(module-functions(string), \"index\");\n}\n",
+    "a {\n  color: true;\n}\n"
+);
+test!(
+    keywords_of_named_rest_args,
+    "@use \"sass:meta\";\n@function foo($args...) {\n  @return meta.inspect(meta.keywords($args));\n}\na {\n  color: foo(1, $b: 2, $a: c);\n}\n",
+    "a {\n  color: (a: c, b: 2);\n}\n"
+);
+test!(
+    keywords_global_function,
+    "@mixin foo($args...) {\n  color: inspect(keywords($args));\n}\na {\n  @include foo($a: 1);\n}\n",
+    "a {\n  color: (a: 1);\n}\n"
+);
+test!(
+    keywords_without_named_args_is_empty,
+    "@function foo($args...) {\n  @return length(keywords($args));\n}\na {\n  color: foo(1, 2);\n}\n",
+    "a {\n  color: 0;\n}\n"
+);
+test!(
+    keywords_passed_on_through_splat,
+    "@function foo($a, $b) {\n  @return $a $b;\n}\n@function bar($args...) {\n  @return foo($args...);\n}\na {\n  color: bar(1, $b: 2);\n}\n",
+    "a {\n  color: 1 2;\n}\n"
+);
+test!(
+    get_function_from_builtin_module,
+    "@use \"sass:meta\";\n@use \"sass:string\";\na {\n  color: meta.call(meta.get-function(to-upper-case, $module: string), \"a\");\n}\n",
+    "a {\n  color: \"A\";\n}\n"
+);
+test!(
+    function_exists_in_builtin_module,
+    "@use \"sass:meta\";\n@use \"sass:string\";\na {\n  color: meta.function-exists(index, string);\n  color: meta.function-exists(foo, string);\n}\n",
+    "a {\n  color: true;\n  color: false;\n}\n"
+);
+test!(
+    mixin_exists_in_builtin_module,
+    "@use \"sass:meta\";\na {\n  color: meta.mixin-exists(load-css, meta);\n}\n",
+    "a {\n  color: true;\n}\n"
+);
+test!(
+    type_of_calc,
+    "@use \"sass:meta\";\na {\n  color: meta.type-of(calc(1px + 2%));\n}\n",
+    "a {\n  color: calculation;\n}\n"
+);
+test!(
+    type_of_css_min,
+    "a {\n  color: type-of(min(1px, 2%));\n}\n",
+    "a {\n  color: calculation;\n}\n"
+);
+test!(
+    calc_args_of_single_expression,
+    "@use \"sass:meta\";\na {\n  color: meta.inspect(meta.calc-args(calc(1px + 2%)));\n}\n",
+    "a {\n  color: (1px + 2%,);\n}\n"
+);
+test!(
+    calc_args_of_min,
+    "@use \"sass:meta\";\na {\n  color: meta.calc-args(min(1px, 2%));\n}\n",
+    "a {\n  color: 1px, 2%;\n}\n"
+);
+test!(
+    calc_args_numbers_are_numbers,
+    "@use \"sass:meta\";\na {\n  color: meta.type-of(nth(meta.calc-args(calc(-1.5px, 2)), 1));\n  color: nth(meta.calc-args(calc(-1.5px, 2)), 2) + 1;\n}\n",
+    "a {\n  color: number;\n  color: 3;\n}\n"
+);
+test!(
+    calc_args_nested_calculation,
+    "@use \"sass:meta\";\na {\n  color: meta.type-of(nth(meta.calc-args(max(1px, calc(1px + 2%))), 2));\n  color: meta.type-of(nth(meta.calc-args(max(var(--a, 1px), 2px)), 1));\n}\n",
+    "a {\n  color: calculation;\n  color: string;\n}\n"
+);
+test!(
+    calc_args_ignores_commas_in_strings_and_parens,
+    "@use \"sass:meta\";\na {\n  color: length(meta.calc-args(calc(var(--a, \"b, c\"))));\n}\n",
+    "a {\n  color: 1;\n}\n"
+);
+test!(
+    calc_value_of_number,
+    "@use \"sass:meta\";\na {\n  color: meta.calc-value(2px);\n  color: meta.type-of(meta.calc-value(2px));\n}\n",
+    "a {\n  color: calc(2px);\n  color: calculation;\n}\n"
+);
+test!(
+    calc_equals_identical_calc,
+    "a {\n  color: calc(1px + 2%) == calc(1px + 2%);\n}\n",
+    "a {\n  color: true;\n}\n"
+);
+test!(
+    module_variables_of_builtin_module,
+    "@use \"sass:math\";\n@use \"sass:meta\";\na {\n  color: meta.inspect(meta.module-variables(math));\n}\n",
+    "a {\n  color: (\"e\": 2.7182818285, \"pi\": 3.1415926536);\n}\n"
+);
+test!(
+    module_variables_by_quoted_namespace,
+    "@use \"sass:math\" as m;\n@use \"sass:meta\";\na {\n  color: map-get(meta.module-variables(\"m\"), \"pi\") == m.$pi;\n}\n",
+    "a {\n  color: true;\n}\n"
+);
+test!(
+    module_variables_of_module_without_variables,
+    "@use \"sass:string\";\n@use \"sass:meta\";\na {\n  color: meta.inspect(meta.module-variables(string));\n}\n",
+    "a {\n  color: ();\n}\n"
+);
+error!(
+    module_variables_of_unloaded_module,
+    "@use \"sass:meta\";\na {\n  color: meta.module-variables(math);\n}\n",
+    "Error: There is no module with the namespace \"math\"."
+);
+error!(
+    module_variables_of_non_string,
+    "@use \"sass:meta\";\na {\n  color: meta.module-variables(1);\n}\n",
+    "Error: $module: 1 is not a string."
+);
+error!(
+    calc_args_of_non_calculation,
+    "@use \"sass:meta\";\na {\n  color: meta.calc-args(\"calc(1px)\");\n}\n",
+    "Error: $calc: \"calc(1px)\" is not a calculation."
+);
+error!(
+    calc_value_of_non_number,
+    "@use \"sass:meta\";\na {\n  color: meta.calc-value(a);\n}\n",
+    "Error: $number: a is not a number."
+);
+error!(
+    keywords_of_non_arglist,
+    "@use \"sass:meta\";\na {\n  color: meta.keywords((a: b));\n}\n",
+    "Error: $args: (a: b) is not an argument list."
+);
+error!(
+    get_function_with_css_and_module,
+    "@use \"sass:meta\";\na {\n  color: meta.get-function(foo, $css: true, $module: meta);\n}\n",
+    "Error: $css and $module may not both be passed at once."
+);
+error!(
+    get_function_unknown_namespace,
+    "@use \"sass:meta\";\na {\n  color: meta.get-function(foo, $module: bar);\n}\n",
+    "Error: There is no module with the namespace \"bar\"."
+);
+error!(
+    get_function_not_in_module,
+    "@use \"sass:meta\";\na {\n  color: meta.get-function(foo, $module: meta);\n}\n",
+    "Error: Function not found: foo"
+);
+
+#[test]
+fn get_function_from_user_module() {
+    let input = "@use \"sass:meta\";\n@use \"meta_get_function__lib\" as lib;\na {\n  color: meta.call(meta.get-function(double, $module: lib), 2);\n}\n";
+    tempfile!(
+        "meta_get_function__lib.scss",
+        "@function double($n) {\n  @return $n * 2;\n}\n"
+    );
+    assert_eq!(
+        "a {\n  color: 4;\n}\n",
+        &grass::from_string(input.to_string(), &grass::Options::default()).expect(input)
+    );
+}
+
+#[test]
+fn module_variables() {
+    let input = "@use \"sass:meta\";\n@use \"meta_module_variables__lib\" as lib;\na {\n  color: meta.inspect(meta.module-variables(lib));\n}\n";
+    tempfile!(
+        "meta_module_variables__lib.scss",
+        "@forward \"meta_module_variables__other\" hide $d;\n$b: 2;\n$a: red;\n"
+    );
+    tempfile!("meta_module_variables__other.scss", "$c: 3;\n$d: 4;\n");
+    assert_eq!(
+        "a {\n  color: (\"a\": red, \"b\": 2, \"c\": 3);\n}\n",
+        &grass::from_string(input.to_string(), &grass::Options::default()).expect(input)
+    );
+}
+
+#[test]
+fn module_functions() {
+    let input = "@use \"sass:meta\";\n@use \"meta_module_functions__lib\" as lib;\na {\n  color: meta.inspect(meta.module-functions(lib));\n  color: meta.call(map-get(meta.module-functions(lib), \"double\"), 2);\n}\n";
+    tempfile!(
+        "meta_module_functions__lib.scss",
+        "@function double($n) {\n  @return $n * 2;\n}\n"
+    );
+    assert_eq!(
+        "a {\n  color: (\"double\": get-function(\"double\"));\n  color: 4;\n}\n",
+        &grass::from_string(input.to_string(), &grass::Options::default()).expect(input)
+    );
+}
+
+#[test]
+fn module_variables_through_prefixed_forward() {
+    let input = "@use \"sass:meta\";\n@use \"meta_module_variables_prefix__lib\" as lib;\na {\n  color: meta.inspect(meta.module-variables(lib));\n}\n";
+    tempfile!(
+        "meta_module_variables_prefix__lib.scss",
+        "@forward \"meta_module_variables_prefix__other\" as o-*;\n"
+    );
+    tempfile!("meta_module_variables_prefix__other.scss", "$c: 3;\n");
+    assert_eq!(
+        "a {\n  color: (\"o-c\": 3);\n}\n",
+        &grass::from_string(input.to_string(), &grass::Options::default()).expect(input)
+    );
+}
+
+#[test]
+fn module_functions_excludes_private_functions() {
+    let input = "@use \"sass:meta\";\n@use \"meta_module_functions_private__lib\" as lib;\na {\n  color: meta.inspect(meta.module-functions(lib));\n}\n";
+    tempfile!(
+        "meta_module_functions_private__lib.scss",
+        "@function double($n) {\n  @return -half($n) * 4;\n}\n@function -half($n) {\n  @return $n / 2;\n}\n@function _triple($n) {\n  @return $n * 3;\n}\n"
+    );
+    assert_eq!(
+        "a {\n  color: (\"double\": get-function(\"double\"));\n}\n",
+        &grass::from_string(input.to_string(), &grass::Options::default()).expect(input)
+    );
+}
@@ -106,4 +106,22 @@ error!(
     max_incompatible_units,
     "$a: 1px;\n$b: 2%;\na {\n  color: max($a, $b);\n}\n", "Error: Incompatible units px and %."
 );
+test!(
+    max_env_with_fallback,
+    "a {\n  padding-top: max(env(safe-area-inset-top, 0px), 1rem);\n}\n"
+);
+test!(
+    min_env_with_interpolated_fallback,
+    "$a: 16px;\na {\n  padding-top: min(env(safe-area-inset-top, #{$a}) + 1px, var(--b, 2px));\n}\n",
+    "a {\n  padding-top: min(env(safe-area-inset-top, 16px) + 1px, var(--b, 2px));\n}\n"
+);
+test!(
+    max_calc_with_interpolation,
+    "$a: 16px;\na {\n  width: max(calc(1px + #{$a}), 2px);\n}\n",
+    "a {\n  width: max(calc(1px + 16px), 2px);\n}\n"
+);
+test!(
+    min_followed_by_another_declaration,
+    "a {\n  color: min(1px, 2px);\n  width: 1px;\n}\n"
+);
 // todo: special functions, min(calc(1), $b);
@@ -22,6 +22,34 @@ test!(
     keyword_important_not_at_end,
     "a {\n  height: !important 1;\n}\n"
 );
+test!(
+    important_trailing_shorthand,
+    "a {\n  background: red !important;\n}\n"
+);
+test!(
+    important_inside_interpolation_not_repeated,
+    "$val: red !important;\na {\n  background: #{$val} !important;\n}\n",
+    "a {\n  background: red !important;\n}\n"
+);
+test!(
+    important_inside_variable_not_repeated,
+    "$val: red !important;\na {\n  background: $val !important;\n}\n",
+    "a {\n  background: red !important;\n}\n"
+);
+test!(
+    important_in_mixin_argument,
+    "@mixin foo($x) {\n  background: $x;\n}\na {\n  @include foo(red !important);\n}\n",
+    "a {\n  background: red !important;\n}\n"
+);
+test!(
+    important_in_mixin_argument_and_body_not_repeated,
+    "@mixin foo($x) {\n  background: $x !important;\n}\na {\n  @include foo(red !important);\n}\n",
+    "a {\n  background: red !important;\n}\n"
+);
+test!(
+    important_inside_quoted_string_is_repeated,
+    "a {\n  content: \"a !important\" !important;\n}\n"
+);
 test!(
     emits_double_newline_between_unrelated_styles,
     "a {\n  color: red;\n}\n\nb {\n  color: blue;\n}\n"
@@ -117,3 +145,17 @@ test!(
     "a {\n  a: b\n}\n\nb {}\n",
     "a {\n  a: b;\n}\n"
 );
+#[test]
+fn from_bytes_valid_utf8() {
+    assert_eq!(
+        "a {\n  color: red;\n}\n",
+        grass::from_bytes(b"a {\n  color: red;\n}\n", &grass::Options::default()).unwrap()
+    );
+}
+#[test]
+fn from_bytes_invalid_utf8() {
+    match grass::from_bytes(b"a {\n  color: \xFF;\n}\n", &grass::Options::default()) {
+        Ok(..) => panic!("did not fail"),
+        Err(e) => assert_eq!("Error: Invalid UTF-8 character \"\\xFF\"\n", e.to_string()),
+    }
+}
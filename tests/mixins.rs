@@ -224,11 +224,36 @@ error!(
     "@mixin foo() {\n    @function bar() {\n        @return foo;\n    }\n}\n\na {\n    @include foo {\n        color: red;\n    }\n}\n",
     "Error: Mixins may not contain function declarations."
 );
+error!(
+    mixin_inside_mixin,
+    "@mixin foo {\n    @mixin bar {\n        color: red;\n    }\n}\n\na {\n    @include foo;\n}\n",
+    "Error: Mixins may not contain mixin declarations."
+);
+error!(
+    mixin_inside_style_rule_inside_mixin,
+    "@mixin foo {\n    b {\n        @mixin bar {\n            color: red;\n        }\n\n        @include bar;\n    }\n}\n\na {\n    @include foo;\n}\n",
+    "Error: Mixins may not contain mixin declarations."
+);
+error!(
+    empty_mixin_inside_style_rule_inside_mixin,
+    "@mixin a { b { @mixin c {} } }\n@include a;\n",
+    "Error: Mixins may not contain mixin declarations."
+);
+error!(
+    mixin_inside_function,
+    "@function foo() {\n    @mixin bar {}\n    @return 1;\n}\n\na {\n    color: foo();\n}\n",
+    "Error: This at-rule is not allowed here."
+);
 error!(
     content_inside_control_flow_outside_mixin,
     "a {\n    @if true {\n        @content;\n    }\n}\n",
     "Error: @content is only allowed within mixin declarations."
 );
+error!(
+    content_inside_content_block_outside_mixin,
+    "@mixin foo {\n    @content;\n}\n\n@include foo {\n    @content;\n}\n",
+    "Error: @content is only allowed within mixin declarations."
+);
 error!(
     undefined_mixin,
     "a {@include foo;}", "Error: Undefined mixin."
@@ -364,3 +389,174 @@ error!(
     }",
     "Error: Missing argument $a."
 );
+#[test]
+fn infinitely_recursive_mixin() {
+    let input = "@mixin foo {\n  @include foo;\n}\n\na {\n  @include foo;\n}\n";
+    match grass::from_string(
+        input.to_string(),
+        &grass::Options::default().recursion_limit(10),
+    ) {
+        Ok(..) => panic!("did not fail"),
+        Err(e) => assert!(e.to_string().starts_with("Error: stack overflow\n")),
+    }
+}
+#[test]
+fn infinitely_recursive_mixin_default_limit() {
+    let input = "@mixin foo {\n  @include foo;\n}\n\na {\n  @include foo;\n}\n";
+    match grass::from_string(input.to_string(), &grass::Options::default()) {
+        Ok(..) => panic!("did not fail"),
+        Err(e) => assert!(e.to_string().starts_with("Error: stack overflow\n")),
+    }
+}
+#[test]
+fn infinitely_recursive_mixin_default_limit_parse_string() {
+    let input = "@mixin foo {\n  @include foo;\n}\n\na {\n  @include foo;\n}\n";
+    match grass::parse_string(input.to_string(), &grass::Options::default()) {
+        Ok(..) => panic!("did not fail"),
+        Err(e) => assert!(e.to_string().starts_with("Error: stack overflow\n")),
+    }
+}
+#[test]
+fn recursive_mixin_above_default_recursion_limit() {
+    let input = "@mixin foo($i) {\n  @if $i > 0 {\n    @include foo($i - 1);\n  } @else {\n    color: red;\n  }\n}\n\na {\n  @include foo(4000);\n}\n";
+    assert_eq!(
+        "a {\n  color: red;\n}\n",
+        grass::from_string(
+            input.to_string(),
+            &grass::Options::default().recursion_limit(5000),
+        )
+        .expect(input)
+    );
+}
+#[test]
+fn recursive_mixin_within_recursion_limit() {
+    let input = "@mixin foo($i) {\n  @if $i > 0 {\n    @include foo($i - 1);\n  } @else {\n    color: red;\n  }\n}\n\na {\n  @include foo(9);\n}\n";
+    assert_eq!(
+        "a {\n  color: red;\n}\n",
+        grass::from_string(
+            input.to_string(),
+            &grass::Options::default().recursion_limit(10),
+        )
+        .expect(input)
+    );
+}
+error!(
+    mixin_declared_in_style_rule_inside_mixin,
+    "@mixin outer {\n  a {\n    @mixin inner {\n      color: red;\n    }\n    @include inner;\n  }\n}\n@include outer;\n",
+    "Error: Mixins may not contain mixin declarations."
+);
+test!(
+    mixin_declared_in_each,
+    "@each $x in 1 2 {\n  @mixin foo {\n    color: $x;\n  }\n  a {\n    @include foo;\n  }\n}\n",
+    "a {\n  color: 1;\n}\n\na {\n  color: 2;\n}\n"
+);
+test!(
+    local_mixin_found_from_nested_style_rule,
+    "a {\n  @mixin foo {\n    color: red;\n  }\n  b {\n    @include foo;\n  }\n}\n",
+    "a b {\n  color: red;\n}\n"
+);
+test!(
+    local_mixin_shadows_outer_mixin_only_within_its_scope,
+    "@mixin foo {\n  color: outer;\n}\na {\n  b {\n    @mixin foo {\n      color: inner;\n    }\n    @include foo;\n  }\n  c {\n    @include foo;\n  }\n}\n",
+    "a b {\n  color: inner;\n}\na c {\n  color: outer;\n}\n"
+);
+error!(
+    local_mixin_not_visible_outside_its_scope,
+    "a {\n  @mixin foo {\n    color: red;\n  }\n}\nb {\n  @include foo;\n}\n",
+    "Error: Undefined mixin."
+);
+test!(
+    content_block_selectors_nest_inside_media_in_mixin,
+    "@mixin foo {\n  @media (min-width: 10px) {\n    @content;\n  }\n}\n.x {\n  @include foo {\n    a {\n      color: red;\n    }\n  }\n}\n",
+    "@media (min-width: 10px) {\n  .x a {\n    color: red;\n  }\n}\n"
+);
+test!(
+    content_block_selectors_nest_inside_selector_where_content_is,
+    "@mixin foo {\n  .w {\n    @content;\n  }\n}\n.y {\n  @include foo {\n    a {\n      color: red;\n    }\n    &:hover {\n      color: blue;\n    }\n  }\n}\n",
+    ".y .w a {\n  color: red;\n}\n.y .w:hover {\n  color: blue;\n}\n"
+);
+test!(
+    content_block_with_nested_selector_at_root,
+    "@mixin foo {\n  .w {\n    @content;\n  }\n}\n@include foo {\n  a {\n    color: red;\n  }\n}\n",
+    ".w a {\n  color: red;\n}\n"
+);
+test!(
+    content_block_uses_variables_of_include_site,
+    "@mixin foo {\n  $a: mixin;\n  .w {\n    @content;\n  }\n}\n.q {\n  $a: caller;\n  @include foo {\n    a {\n      color: $a;\n    }\n  }\n}\n",
+    ".q .w a {\n  color: caller;\n}\n"
+);
+test!(
+    content_inside_parent_selector_rule,
+    "@mixin hover-lift {\n  &:hover {\n    @content;\n  }\n}\na {\n  @include hover-lift {\n    transform: translateY(-2px);\n  }\n}\n",
+    "a:hover {\n  transform: translateY(-2px);\n}\n"
+);
+test!(
+    content_inside_multiple_parent_selector_rules,
+    "@mixin foo {\n  color: red;\n  &:hover, &:focus {\n    @content;\n  }\n  & + & {\n    @content;\n  }\n}\na {\n  @include foo {\n    color: blue;\n  }\n}\n",
+    "a {\n  color: red;\n}\na:hover, a:focus {\n  color: blue;\n}\na + a {\n  color: blue;\n}\n"
+);
+test!(
+    content_inside_parent_selector_rule_with_selector_list,
+    "@mixin hover-lift {\n  &:hover {\n    @content;\n  }\n}\na, b {\n  @include hover-lift {\n    color: red;\n  }\n}\n",
+    "a:hover, b:hover {\n  color: red;\n}\n"
+);
+test!(
+    default_arg_evaluated_when_included,
+    "$base-size: 1px;\n@mixin foo($size: $base-size * 2) {\n  width: $size;\n}\n$base-size: 5px;\na {\n  @include foo;\n}\n",
+    "a {\n  width: 10px;\n}\n"
+);
+test!(
+    default_arg_evaluated_on_each_include,
+    "$base-size: 1px;\n@mixin foo($size: $base-size * 2) {\n  width: $size;\n}\na {\n  @include foo;\n}\n$base-size: 5px;\nb {\n  @include foo;\n}\n",
+    "a {\n  width: 2px;\n}\n\nb {\n  width: 10px;\n}\n"
+);
+test!(
+    default_arg_refers_to_earlier_arg,
+    "@mixin foo($a, $b: $a * 2, $c: $a + $b) {\n  color: $a $b $c;\n}\na {\n  @include foo(1);\n  @include foo(1, 5);\n}\n",
+    "a {\n  color: 1 2 3;\n  color: 1 5 6;\n}\n"
+);
+test!(
+    default_arg_calls_function,
+    "@function double($n) {\n  @return $n * 2;\n}\n@mixin foo($size: double(if(true, 3px, 0))) {\n  width: $size;\n}\na {\n  @include foo;\n}\n",
+    "a {\n  width: 6px;\n}\n"
+);
+test!(
+    content_used_more_than_once,
+    "@mixin twice {\n  @content;\n  @content;\n}\na {\n  @include twice {\n    color: red;\n  }\n}\n",
+    "a {\n  color: red;\n  color: red;\n}\n"
+);
+test!(
+    content_inside_nested_rule_in_mixin,
+    "@mixin foo {\n  b {\n    @content;\n  }\n}\na {\n  @include foo {\n    color: red;\n  }\n}\n",
+    "a b {\n  color: red;\n}\n"
+);
+test!(
+    content_without_block_is_noop,
+    "@mixin foo {\n  color: red;\n  @content;\n}\na {\n  @include foo;\n}\n",
+    "a {\n  color: red;\n}\n"
+);
+test!(
+    content_without_block_and_semicolon_is_noop,
+    "@mixin foo {\n  color: red;\n  @content\n}\na {\n  @include foo();\n}\n",
+    "a {\n  color: red;\n}\n"
+);
+test!(
+    content_using_named_and_default_args,
+    "@mixin foo {\n  @content(1, $b: 2);\n}\na {\n  @include foo using ($a, $b: 5, $c: 3) {\n    color: $a $b $c;\n  }\n}\n",
+    "a {\n  color: 1 2 3;\n}\n"
+);
+test!(
+    content_using_value_computed_from_mixin_args,
+    "@mixin foo($x) {\n  @content($x * 2);\n}\na {\n  @include foo(2) using ($v) {\n    color: $v;\n  }\n}\n",
+    "a {\n  color: 4;\n}\n"
+);
+test!(
+    content_using_rest_args,
+    "@mixin foo {\n  @content(1, 2, 3);\n}\na {\n  @include foo using ($first, $rest...) {\n    color: $first;\n    color: length($rest);\n  }\n}\n",
+    "a {\n  color: 1;\n  color: 2;\n}\n"
+);
+test!(
+    content_using_only_rest_args,
+    "@mixin foo {\n  @content(1, 2, 3);\n}\na {\n  @include foo using ($args...) {\n    color: length($args);\n  }\n}\n",
+    "a {\n  color: 3;\n}\n"
+);
@@ -38,3 +38,28 @@ test!(
     "a {\n  color: not not false;\n}\n",
     "a {\n  color: false;\n}\n"
 );
+test!(
+    not_zero,
+    "a {\n  color: not 0;\n}\n",
+    "a {\n  color: false;\n}\n"
+);
+test!(
+    not_binds_looser_than_comparison,
+    "a {\n  color: not 1 > 2;\n}\n",
+    "a {\n  color: true;\n}\n"
+);
+test!(
+    not_binds_looser_than_equality_and_arithmetic,
+    "a {\n  color: not 1 + 1 == 3;\n}\n",
+    "a {\n  color: true;\n}\n"
+);
+test!(
+    not_binds_tighter_than_and,
+    "a {\n  color: not false and true;\n}\n",
+    "a {\n  color: true;\n}\n"
+);
+test!(
+    not_binds_tighter_than_or,
+    "a {\n  color: not true or false;\n}\n",
+    "a {\n  color: false;\n}\n"
+);
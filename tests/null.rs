@@ -38,3 +38,33 @@ test!(
     "a {\n  color: NULL;\n}\n",
     "a {\n  color: NULL;\n}\n"
 );
+test!(
+    null_value_not_emitted,
+    "a {\n  color: null;\n  width: 1px;\n}\n",
+    "a {\n  width: 1px;\n}\n"
+);
+test!(
+    null_variable_value_not_emitted,
+    "$color: null;\na {\n  color: $color;\n  width: 1px;\n}\n",
+    "a {\n  width: 1px;\n}\n"
+);
+test!(
+    empty_list_value_not_emitted,
+    "a {\n  color: ();\n  width: 1px;\n}\n",
+    "a {\n  width: 1px;\n}\n"
+);
+test!(
+    optional_declarations_through_null_defaults,
+    "@mixin button($color: null, $border: null) {\n  color: $color;\n  border: $border;\n}\na {\n  @include button($border: 1px solid);\n}\n",
+    "a {\n  border: 1px solid;\n}\n"
+);
+test!(
+    null_element_of_each_not_emitted,
+    "a {\n  @each $color in red null blue {\n    color: $color;\n  }\n}\n",
+    "a {\n  color: red;\n  color: blue;\n}\n"
+);
+test!(
+    empty_bracketed_list_is_emitted,
+    "a {\n  color: [];\n}\n",
+    "a {\n  color: [];\n}\n"
+);
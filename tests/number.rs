@@ -35,6 +35,47 @@ test!(
     "a {\n  color: 1.0000;\n}\n",
     "a {\n  color: 1;\n}\n"
 );
+test!(
+    trailing_zeros_removed,
+    "a {\n  color: 1.50000;\n}\n",
+    "a {\n  color: 1.5;\n}\n"
+);
+test!(
+    trailing_zeros_removed_with_unit,
+    "a {\n  color: 100.000px;\n}\n",
+    "a {\n  color: 100px;\n}\n"
+);
+test!(
+    repeating_decimal_rounded_down,
+    "a {\n  color: (1/3);\n}\n",
+    "a {\n  color: 0.3333333333;\n}\n"
+);
+test!(
+    repeating_decimal_rounded_up,
+    "a {\n  color: (2/3);\n}\n",
+    "a {\n  color: 0.6666666667;\n}\n"
+);
+test!(
+    negative_repeating_decimal,
+    "a {\n  color: -(2/3);\n}\n",
+    "a {\n  color: -0.6666666667;\n}\n"
+);
+test!(small_decimal, "a {\n  color: 0.001;\n}\n");
+test!(
+    small_number_not_in_scientific_notation,
+    "a {\n  color: 1.5e-9;\n}\n",
+    "a {\n  color: 0.0000000015;\n}\n"
+);
+test!(
+    large_number_not_in_scientific_notation,
+    "a {\n  color: 1e21;\n}\n",
+    "a {\n  color: 1000000000000000000000;\n}\n"
+);
+test!(
+    precision_is_decimal_places_not_significant_digits,
+    "a {\n  color: 12345.678901234567;\n}\n",
+    "a {\n  color: 12345.6789012346;\n}\n"
+);
 test!(many_nines_not_rounded, "a {\n  color: 0.999999;\n}\n");
 test!(positive_integer, "a {\n  color: 1;\n}\n");
 test!(negative_integer, "a {\n  color: -1;\n}\n");
@@ -43,3 +43,28 @@ test!(
     "a {\n  color: 1 / 2 * 1em;\n}\n",
     "a {\n  color: 0.5em;\n}\n"
 );
+test!(
+    comparison_then_or,
+    "a {\n  color: 1 > 2 or 3 > 0;\n}\n",
+    "a {\n  color: true;\n}\n"
+);
+test!(
+    and_then_or,
+    "a {\n  color: false and true or true;\n}\n",
+    "a {\n  color: true;\n}\n"
+);
+test!(
+    or_then_and,
+    "a {\n  color: true or false and false;\n}\n",
+    "a {\n  color: true;\n}\n"
+);
+test!(
+    false_and_then_or_returns_right_of_or,
+    "a {\n  color: 1 < 0 and 1 or 3;\n}\n",
+    "a {\n  color: 3;\n}\n"
+);
+test!(
+    relational_then_equality,
+    "a {\n  color: true == 1 < 2;\n}\n",
+    "a {\n  color: true;\n}\n"
+);
@@ -63,3 +63,23 @@ test!(
     "a {\n  color: 0 < 1;\n}\n",
     "a {\n  color: true;\n}\n"
 );
+test!(
+    small_number_greater_than_large_negative_number,
+    "a {\n  color: 1 > -99999999999999999999999;\n  color: 1 < -99999999999999999999999;\n}\n",
+    "a {\n  color: true;\n  color: false;\n}\n"
+);
+test!(
+    large_negative_number_less_than_small_number,
+    "a {\n  color: -99999999999999999999999 < 1;\n  color: -99999999999999999999999 >= 1;\n}\n",
+    "a {\n  color: true;\n  color: false;\n}\n"
+);
+test!(
+    large_number_greater_than_small_number,
+    "a {\n  color: 99999999999999999999999 > 1;\n  color: 1 >= 99999999999999999999999;\n}\n",
+    "a {\n  color: true;\n  color: false;\n}\n"
+);
+test!(
+    large_numbers_compared,
+    "a {\n  color: 99999999999999999999998 < 99999999999999999999999;\n}\n",
+    "a {\n  color: true;\n}\n"
+);
@@ -0,0 +1,107 @@
+#![cfg(test)]
+
+use proptest::prelude::*;
+
+/// Groups of units which may be converted between one another
+const COMPATIBLE_UNITS: &[&[&str]] = &[
+    &["px", "in", "cm", "mm", "q", "pt", "pc"],
+    &["deg", "grad", "rad", "turn"],
+    &["s", "ms"],
+    &["Hz", "kHz"],
+    &["dpi", "dpcm", "dppx"],
+    &["%"],
+    &["em"],
+];
+
+/// Compile `value` as the value of a declaration, returning the emitted value
+fn eval(value: &str) -> String {
+    let input = format!("a {{\n  b: {};\n}}\n", value);
+    let css = grass::from_string(input.clone(), &grass::Options::default())
+        .unwrap_or_else(|e| panic!("failed to compile {:?}: {}", input, e));
+    css.strip_prefix("a {\n  b: ")
+        .and_then(|css| css.strip_suffix(";\n}\n"))
+        .unwrap_or_else(|| panic!("unexpected output {:?} for {:?}", css, input))
+        .to_owned()
+}
+
+/// A number with at most two decimal places, formatted as Sass would emit it
+fn number() -> impl Strategy<Value = String> {
+    (-100_000_i64..100_000).prop_map(|n| {
+        let sign = if n < 0 { "-" } else { "" };
+        let (whole, frac) = (n.abs() / 100, n.abs() % 100);
+        match frac {
+            0 => format!("{}{}", sign, whole),
+            f if f % 10 == 0 => format!("{}{}.{}", sign, whole, f / 10),
+            f => format!("{}{}.{:02}", sign, whole, f),
+        }
+    })
+}
+
+fn unit() -> impl Strategy<Value = &'static str> {
+    prop_oneof![Just(""), prop::sample::select(COMPATIBLE_UNITS.concat())]
+}
+
+/// Two units which are compatible, either of which may be unitless
+fn compatible_units() -> impl Strategy<Value = (&'static str, &'static str)> {
+    prop::sample::select(COMPATIBLE_UNITS).prop_flat_map(|units| {
+        let unit = prop_oneof![Just(""), prop::sample::select(units)];
+        (unit.clone(), unit)
+    })
+}
+
+/// Two units which may be converted between one another
+fn convertible_units() -> impl Strategy<Value = (&'static str, &'static str)> {
+    prop::sample::select(COMPATIBLE_UNITS).prop_flat_map(|units| {
+        let unit = prop::sample::select(units);
+        (unit.clone(), unit)
+    })
+}
+
+proptest! {
+    #[test]
+    fn addition_is_commutative(
+        a in number(),
+        b in number(),
+        (a_unit, b_unit) in compatible_units(),
+    ) {
+        let a = format!("{}{}", a, a_unit);
+        let b = format!("{}{}", b, b_unit);
+        prop_assert_eq!(eval(&format!("({} + {}) == ({} + {})", a, b, b, a)), "true");
+    }
+
+    #[test]
+    fn subtracting_self_is_zero(a in number(), unit in unit()) {
+        prop_assert_eq!(eval(&format!("{0}{1} - {0}{1}", a, unit)), format!("0{}", unit));
+    }
+
+    #[test]
+    fn multiplying_by_one_is_identity(a in number(), unit in unit()) {
+        let a = format!("{}{}", a, unit);
+        prop_assert_eq!(eval(&format!("{} * 1", a)), a);
+    }
+
+    #[test]
+    fn dividing_by_self_is_one(a in number(), unit in unit()) {
+        prop_assume!(a != "0");
+        prop_assert_eq!(eval(&format!("({0}{1} / {0}{1})", a, unit)), "1");
+    }
+
+    #[test]
+    fn adding_zero_is_identity(a in number(), unit in unit()) {
+        let a = format!("{}{}", a, unit);
+        prop_assert_eq!(eval(&format!("{} + 0", a)), a.clone());
+        prop_assert_eq!(eval(&format!("0 + {}", a)), a);
+    }
+
+    #[test]
+    fn converting_there_and_back_is_equal(
+        a in number(),
+        (a_unit, b_unit) in convertible_units(),
+    ) {
+        let a = format!("{}{}", a, a_unit);
+        prop_assert_eq!(
+            eval(&format!("(0{} + {}) == {}", b_unit, a, a)),
+            "true"
+        );
+    }
+}
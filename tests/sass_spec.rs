@@ -0,0 +1,355 @@
+//! Runs the cases of the official Sass specification against `grass`
+//!
+//! This requires the `sass-spec` submodule to be checked out, and fails if it
+//! is not. As no lists of the cases known to fail have yet been generated,
+//! these tests are ignored by default:
+//!
+//! ```bash
+//! git submodule update --init
+//! cargo test --test sass_spec -- --ignored --nocapture
+//! ```
+//!
+//! Cases are read both from directories and from `.hrx` archives. A case is
+//! any directory containing an `input.scss`, along with the other files it may
+//! import.
+//!
+//! The cases of each subset which are known to fail are listed, one per line,
+//! in `tests/sass_spec/{subset}.txt`, and a missing list is treated as empty.
+//! A subset fails if any other case fails, or if a listed case passes or no
+//! longer exists. To write the lists with the cases which currently fail, run
+//!
+//! ```bash
+//! SASS_SPEC_UPDATE_FAILURES=1 cargo test --test sass_spec -- --ignored
+//! ```
+#![cfg(test)]
+
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+/// Files by path, relative to the root of a subset of the spec
+type Files = BTreeMap<String, String>;
+
+#[derive(Debug, PartialEq, Eq)]
+enum Outcome {
+    Pass,
+    Fail(String),
+    Skip,
+}
+
+/// Parse a [human-readable archive](https://github.com/google/hrx), returning
+/// its files. Directory entries and comments are ignored
+fn parse_hrx(archive: &str) -> Files {
+    let mut files = Files::new();
+
+    let boundary_len = match archive.find('>') {
+        Some(idx) if archive.starts_with('<') => idx + 1,
+        _ => return files,
+    };
+    let boundary = &archive[..boundary_len];
+
+    let mut entry: Option<String> = None;
+    let mut contents: Vec<&str> = Vec::new();
+
+    let mut finish = |entry: &mut Option<String>, contents: &mut Vec<&str>| {
+        if let Some(path) = entry.take() {
+            if !path.ends_with('/') {
+                files.insert(path, contents.join("\n"));
+            }
+        }
+        contents.clear();
+    };
+
+    for line in archive.split('\n') {
+        if let Some(rest) = line.strip_prefix(boundary) {
+            finish(&mut entry, &mut contents);
+            // a boundary without a path begins a comment
+            entry = rest.strip_prefix(' ').map(|path| path.trim().to_owned());
+        } else {
+            contents.push(line);
+        }
+    }
+    finish(&mut entry, &mut contents);
+
+    files
+}
+
+/// Read every file beneath `dir`, expanding `.hrx` archives into a directory
+/// named after the archive
+fn read_files(dir: &Path, prefix: &str, files: &mut Files) {
+    let mut entries: Vec<PathBuf> = match fs::read_dir(dir) {
+        Ok(entries) => entries.filter_map(|e| e.ok()).map(|e| e.path()).collect(),
+        Err(..) => return,
+    };
+    entries.sort();
+
+    for path in entries {
+        let name = path.file_name().unwrap().to_string_lossy().into_owned();
+        if path.is_dir() {
+            read_files(&path, &format!("{}{}/", prefix, name), files);
+        } else if let Some(stem) = name.strip_suffix(".hrx") {
+            let archive = fs::read_to_string(&path).unwrap_or_default();
+            for (inner, contents) in parse_hrx(&archive) {
+                files.insert(format!("{}{}/{}", prefix, stem, inner), contents);
+            }
+        } else if let Ok(contents) = fs::read_to_string(&path) {
+            files.insert(format!("{}{}", prefix, name), contents);
+        }
+    }
+}
+
+/// Normalize output for comparison, ignoring line endings and leading and
+/// trailing whitespace
+fn normalize(css: &str) -> String {
+    css.replace("\r\n", "\n").trim().to_owned()
+}
+
+/// Compile the case rooted at `dir` within `files`
+fn run_case(dir: &str, files: &Files) -> Outcome {
+    let case: Files = files
+        .iter()
+        .filter_map(|(path, contents)| {
+            path.strip_prefix(dir)
+                .map(|path| (path.to_owned(), contents.clone()))
+        })
+        .collect();
+
+    if let Some(options) = case.get("options.yml") {
+        // cases that dart-sass itself does not pass
+        if options.contains(":todo:") || options.contains(":ignore_for:") {
+            return Outcome::Skip;
+        }
+    }
+
+    let expected = case
+        .get("output-dart-sass.css")
+        .or_else(|| case.get("output.css"));
+    let expects_error = case.contains_key("error-dart-sass") || case.contains_key("error");
+
+    if expected.is_none() && !expects_error {
+        return Outcome::Skip;
+    }
+
+    let tmp = tempfile::tempdir().unwrap();
+    for (path, contents) in &case {
+        let path = tmp.path().join(path);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, contents).unwrap();
+    }
+
+    let input = tmp.path().join("input.scss");
+    let result = std::panic::catch_unwind(|| {
+        grass::from_path(input.to_str().unwrap(), &grass::Options::default())
+    });
+
+    match (result, expected) {
+        (Err(..), _) => Outcome::Fail("panicked".to_owned()),
+        (Ok(Ok(css)), Some(expected)) if normalize(&css) == normalize(expected) => Outcome::Pass,
+        (Ok(Ok(css)), Some(expected)) => Outcome::Fail(format!(
+            "expected:\n{}\nfound:\n{}",
+            normalize(expected),
+            normalize(&css)
+        )),
+        (Ok(Ok(css)), None) => Outcome::Fail(format!("expected an error, found:\n{}", css)),
+        (Ok(Err(..)), None) => Outcome::Pass,
+        (Ok(Err(e)), Some(..)) => Outcome::Fail(e.to_string()),
+    }
+}
+
+/// Run every case in `files`, returning the outcome of each by name
+fn run_cases(files: &Files) -> BTreeMap<String, Outcome> {
+    files
+        .keys()
+        .filter_map(|path| {
+            if path == "input.scss" {
+                Some("")
+            } else {
+                path.strip_suffix("/input.scss")
+            }
+        })
+        .map(|name| {
+            let dir = if name.is_empty() {
+                String::new()
+            } else {
+                format!("{}/", name)
+            };
+            (name.to_owned(), run_case(&dir, files))
+        })
+        .collect()
+}
+
+/// The file listing the cases of `subset` which are known to fail
+fn known_failures_path(subset: &str) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("sass_spec")
+        .join(format!("{}.txt", subset))
+}
+
+/// Parse a list of cases known to fail, one per line. Blank lines and lines
+/// beginning with `#` are ignored
+fn parse_known_failures(list: &str) -> BTreeSet<String> {
+    list.lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(str::to_owned)
+        .collect()
+}
+
+/// Describe each way in which `outcomes` differ from the cases known to fail
+fn unexpected_outcomes(
+    outcomes: &BTreeMap<String, Outcome>,
+    known_failures: &BTreeSet<String>,
+) -> Vec<String> {
+    let mut unexpected = Vec::new();
+
+    for (name, outcome) in outcomes {
+        match (outcome, known_failures.contains(name)) {
+            (Outcome::Fail(reason), false) => {
+                unexpected.push(format!("FAIL {}\n{}\n", name, reason))
+            }
+            (Outcome::Pass, true) => unexpected.push(format!(
+                "PASS {}, which is listed as a known failure\n",
+                name
+            )),
+            (Outcome::Skip, true) => unexpected.push(format!(
+                "SKIP {}, which is listed as a known failure\n",
+                name
+            )),
+            _ => {}
+        }
+    }
+
+    for name in known_failures {
+        if !outcomes.contains_key(name) {
+            unexpected.push(format!(
+                "{} is listed as a known failure, but is not a case\n",
+                name
+            ));
+        }
+    }
+
+    unexpected
+}
+
+/// Run every case within `sass-spec/spec/{subset}`, printing a summary, and
+/// failing if the cases which fail differ from those known to fail
+fn run_subset(subset: &str) {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("sass-spec")
+        .join("spec")
+        .join(subset);
+
+    assert!(
+        dir.is_dir(),
+        "{} does not exist; run `git submodule update --init` to check out sass-spec",
+        dir.display()
+    );
+
+    let mut files = Files::new();
+    read_files(&dir, "", &mut files);
+
+    let outcomes = run_cases(&files);
+    let failures: Vec<&str> = outcomes
+        .iter()
+        .filter(|(.., outcome)| matches!(outcome, Outcome::Fail(..)))
+        .map(|(name, ..)| name.as_str())
+        .collect();
+
+    let passed = outcomes
+        .values()
+        .filter(|outcome| **outcome == Outcome::Pass)
+        .count();
+    println!(
+        "{}: {} passed, {} failed, {} skipped",
+        subset,
+        passed,
+        failures.len(),
+        outcomes.len() - passed - failures.len()
+    );
+
+    let path = known_failures_path(subset);
+
+    if env::var_os("SASS_SPEC_UPDATE_FAILURES").is_some() {
+        let mut list = format!(
+            "# sass-spec cases in spec/{} which are known to fail, one per line\n",
+            subset
+        );
+        for name in failures {
+            list.push_str(name);
+            list.push('\n');
+        }
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, list).unwrap();
+        return;
+    }
+
+    let known_failures = parse_known_failures(&fs::read_to_string(&path).unwrap_or_default());
+    let unexpected = unexpected_outcomes(&outcomes, &known_failures);
+
+    for description in &unexpected {
+        println!("{}", description);
+    }
+
+    assert!(
+        unexpected.is_empty(),
+        "{} sass-spec cases in {} differ from {}",
+        unexpected.len(),
+        subset,
+        path.display()
+    );
+}
+
+#[test]
+#[ignore = "the cases known to fail have not yet been listed"]
+fn spec_css() {
+    run_subset("css");
+}
+
+#[test]
+#[ignore = "the cases known to fail have not yet been listed"]
+fn spec_core_functions() {
+    run_subset("core_functions");
+}
+
+#[test]
+#[ignore = "the cases known to fail have not yet been listed"]
+fn spec_selectors() {
+    run_subset("selectors");
+}
+
+#[test]
+fn runs_cases_from_hrx_archive() {
+    let archive = "<===> passes/input.scss\na {\n  color: red;\n}\n\n<===> passes/output.css\na {\n  color: red;\n}\n\n<===>\n================================================================================\n<===> fails/input.scss\na {\n  color: red;\n}\n\n<===> fails/output.css\na {\n  color: blue;\n}\n\n<===>\n================================================================================\n<===> error/input.scss\na {\n  color: $a;\n}\n\n<===> error/error\nError: Undefined variable.\n\n<===>\n================================================================================\n<===> todo/options.yml\n---\n:todo:\n- dart-sass\n\n<===> todo/input.scss\na {}\n\n<===> todo/output.css\nb {}\n\n<===>\n================================================================================\n<===> imports/input.scss\n@import \"other\";\n\n<===> imports/_other.scss\na {\n  color: red;\n}\n\n<===> imports/output.css\na {\n  color: red;\n}\n";
+
+    let outcomes = run_cases(&parse_hrx(archive));
+
+    assert_eq!(outcomes.len(), 5);
+    assert_eq!(outcomes["passes"], Outcome::Pass);
+    assert!(matches!(outcomes["fails"], Outcome::Fail(..)));
+    assert_eq!(outcomes["error"], Outcome::Pass);
+    assert_eq!(outcomes["todo"], Outcome::Skip);
+    assert_eq!(outcomes["imports"], Outcome::Pass);
+}
+
+#[test]
+fn compares_outcomes_with_known_failures() {
+    let mut outcomes = BTreeMap::new();
+    outcomes.insert("passes".to_owned(), Outcome::Pass);
+    outcomes.insert("known".to_owned(), Outcome::Fail(String::new()));
+    outcomes.insert("fixed".to_owned(), Outcome::Pass);
+    outcomes.insert("new".to_owned(), Outcome::Fail(String::new()));
+
+    let known_failures = parse_known_failures("# known to fail\nknown\n\nfixed\nremoved\n");
+
+    assert_eq!(
+        vec![
+            "PASS fixed, which is listed as a known failure\n".to_owned(),
+            "FAIL new\n\n".to_owned(),
+            "removed is listed as a known failure, but is not a case\n".to_owned(),
+        ],
+        unexpected_outcomes(&outcomes, &known_failures)
+    );
+}
@@ -0,0 +1,72 @@
+#![cfg(test)]
+
+#[macro_use]
+mod macros;
+
+/// Calls which should produce the same value through the `sass:selector`
+/// module as through the equivalent global function, as
+/// `(module function, global function, arguments)`
+const GLOBAL_ALIASES: &[(&str, &str, &str)] = &[
+    ("is-superselector", "is-superselector", "\"a\", \"a.b\""),
+    ("is-superselector", "is-superselector", "\"a.b\", \"a\""),
+    ("simple-selectors", "simple-selectors", "\"a.b:c\""),
+    ("parse", "selector-parse", "\"a b, c\""),
+    ("nest", "selector-nest", "\"a\", \"&:hover\""),
+    ("nest", "selector-nest", "\"a, b\", \"c\""),
+    ("append", "selector-append", "\"a\", \".b\""),
+    ("extend", "selector-extend", "\"a.b\", \".b\", \".c\""),
+    ("replace", "selector-replace", "\"a.b\", \".b\", \".c\""),
+    ("unify", "selector-unify", "\"a\", \".b\""),
+    ("unify", "selector-unify", "\"a\", \"b\""),
+];
+
+fn compile(input: String) -> String {
+    grass::from_string(input.clone(), &grass::Options::default())
+        .unwrap_or_else(|e| panic!("failed to compile {:?}: {}", input, e))
+}
+
+#[test]
+fn module_functions_match_global_functions() {
+    for (module_fn, global_fn, args) in GLOBAL_ALIASES {
+        let namespaced = compile(format!(
+            "@use \"sass:selector\";\na {{\n  color: inspect(selector.{}({}));\n}}\n",
+            module_fn, args
+        ));
+        let global = compile(format!(
+            "a {{\n  color: inspect({}({}));\n}}\n",
+            global_fn, args
+        ));
+        assert_eq!(global, namespaced, "selector.{}({})", module_fn, args);
+    }
+}
+
+test!(
+    selector_nest,
+    "@use \"sass:selector\";\na {\n  color: selector.nest(\"a\", \"&:hover\");\n}\n",
+    "a {\n  color: a:hover;\n}\n"
+);
+test!(
+    selector_unify,
+    "@use \"sass:selector\";\na {\n  color: selector.unify(\"a\", \".b\");\n}\n",
+    "a {\n  color: a.b;\n}\n"
+);
+test!(
+    selector_is_superselector,
+    "@use \"sass:selector\";\na {\n  color: selector.is-superselector(\"a\", \"a.b\");\n}\n",
+    "a {\n  color: true;\n}\n"
+);
+test!(
+    selector_used_in_style_rule,
+    "@use \"sass:selector\";\n#{selector.append(\"a\", \".b\")} {\n  color: red;\n}\n",
+    "a.b {\n  color: red;\n}\n"
+);
+test!(
+    selector_global_aliases_remain,
+    "@use \"sass:selector\";\na {\n  color: selector-append(\"a\", \".b\");\n}\n",
+    "a {\n  color: a.b;\n}\n"
+);
+error!(
+    selector_prefixed_name_is_undefined,
+    "@use \"sass:selector\";\na {\n  color: selector.selector-append(\"a\", \".b\");\n}\n",
+    "Error: Undefined function."
+);
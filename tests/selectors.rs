@@ -95,6 +95,30 @@ test!(
     selector_attribute_namespace,
     "[*|foo] {\n  color: red;\n}\n"
 );
+test!(
+    selector_attribute_named_namespace,
+    "[xml|lang] {\n  color: red;\n}\n"
+);
+test!(
+    selector_attribute_empty_namespace,
+    "[|foo] {\n  color: red;\n}\n"
+);
+test!(
+    selector_attribute_namespace_with_value,
+    "[xml|lang=en] a {\n  color: red;\n}\n"
+);
+test!(
+    selector_attribute_empty_namespace_with_hyphen_or_exact,
+    "[|foo|=val] {\n  color: red;\n}\n"
+);
+test!(
+    selector_attribute_namespace_followed_by_class,
+    "[*|foo].bar {\n  color: red;\n}\n"
+);
+error!(
+    selector_attribute_asterisk_without_namespace,
+    "[*] {\n  color: red;\n}\n", "Error: expected \"|\"."
+);
 error!(
     selector_attribute_missing_equal,
     "[a~b] {\n  color: red;\n}\n", "Error: expected \"=\"."
@@ -300,6 +324,41 @@ test!(
     "a {\n  + {\n    b {\n      color: red;\n  }\n}\n",
     "a + b {\n  color: red;\n}\n"
 );
+test!(
+    child_combinator_following,
+    "a > {\n  b {\n    color: red;\n  }\n}\n",
+    "a > b {\n  color: red;\n}\n"
+);
+test!(
+    child_combinator_preceding,
+    "a {\n  > b {\n    color: red;\n  }\n}\n",
+    "a > b {\n  color: red;\n}\n"
+);
+test!(
+    sibling_combinator_following,
+    "a ~ {\n  b {\n    color: red;\n  }\n}\n",
+    "a ~ b {\n  color: red;\n}\n"
+);
+test!(
+    sibling_combinator_preceding,
+    "a {\n  ~ b {\n    color: red;\n  }\n}\n",
+    "a ~ b {\n  color: red;\n}\n"
+);
+test!(
+    combinator_preceding_multiple_levels,
+    "a {\n  > b {\n    + c {\n      ~ d {\n        color: red;\n      }\n    }\n  }\n}\n",
+    "a > b + c ~ d {\n  color: red;\n}\n"
+);
+test!(
+    combinator_following_multiple_levels,
+    "a > {\n  b ~ {\n    c {\n      color: red;\n    }\n  }\n}\n",
+    "a > b ~ c {\n  color: red;\n}\n"
+);
+test!(
+    combinator_preceding_in_selector_list,
+    "a, b {\n  > c, ~ d {\n    color: red;\n  }\n}\n",
+    "a > c, a ~ d, b > c, b ~ d {\n  color: red;\n}\n"
+);
 test!(simple_multiple_newline, "a,\nb {\n  color: red;\n}\n");
 test!(
     nested_multiple_newline,
@@ -590,6 +649,27 @@ test!(
     ":nth-child(2n+1 of b, c) {\n  color: &;\n}\n",
     ":nth-child(2n+1 of b, c) {\n  color: :nth-child(2n+1 of b, c);\n}\n"
 );
+test!(
+    a_n_plus_b_n_without_b_of,
+    ":nth-child(2n of b, c) {\n  color: red;\n}\n"
+);
+test!(
+    a_n_plus_b_number_alone_of,
+    ":nth-child(2 of .b) {\n  color: red;\n}\n"
+);
+test!(
+    a_n_plus_b_keyword_of,
+    ":nth-last-child(odd of .b) {\n  color: red;\n}\n"
+);
+test!(
+    a_n_plus_b_of_interpolation,
+    "$b: highlighted;\n:nth-child(2n+1 of .#{$b}) {\n  color: red;\n}\n",
+    ":nth-child(2n+1 of .highlighted) {\n  color: red;\n}\n"
+);
+test!(
+    nth_of_type_passes_through_of,
+    ":nth-of-type(2n+1 of .b) {\n  color: red;\n}\n"
+);
 test!(
     a_n_plus_b_n_number_alone,
     ":nth-child(5) {\n  color: &;\n}\n",
@@ -0,0 +1,138 @@
+#![cfg(test)]
+
+use grass::{Options, SourceMapOption};
+
+const PREFIX: &str = "/*# sourceMappingURL=data:application/json;base64,";
+
+/// Compile `input` with an inline source map, returning the CSS without the
+/// source map comment and the decoded source map
+fn compile(input: &str) -> (String, String) {
+    let css = grass::from_string(
+        input.to_string(),
+        &Options::default().source_map(SourceMapOption::Inline),
+    )
+    .expect(input);
+    let start = css.find(PREFIX).expect("no source map comment");
+    assert!(css.ends_with(" */\n"));
+    let encoded = &css[start + PREFIX.len()..css.len() - " */\n".len()];
+    let json = String::from_utf8(decode_base64(encoded)).unwrap();
+    (css[..start].to_string(), json)
+}
+
+fn decode_base64(s: &str) -> Vec<u8> {
+    let mut bits = 0_u32;
+    let mut len = 0;
+    let mut out = Vec::new();
+    for c in s.bytes().filter(|&c| c != b'=') {
+        bits = (bits << 6) | base64_digit(c);
+        len += 6;
+        if len >= 8 {
+            len -= 8;
+            out.push((bits >> len) as u8);
+        }
+    }
+    out
+}
+
+fn base64_digit(c: u8) -> u32 {
+    u32::from(match c {
+        b'A'..=b'Z' => c - b'A',
+        b'a'..=b'z' => c - b'a' + 26,
+        b'0'..=b'9' => c - b'0' + 52,
+        b'+' => 62,
+        b'/' => 63,
+        _ => panic!("invalid base64 digit {:?}", c as char),
+    })
+}
+
+/// Decode the `mappings` field into absolute
+/// `(generated line, generated column, source, source line, source column)`
+/// entries
+fn mappings(json: &str) -> Vec<(i64, i64, i64, i64, i64)> {
+    let start = json.find("\"mappings\":\"").unwrap() + "\"mappings\":\"".len();
+    let mappings = &json[start..start + json[start..].find('"').unwrap()];
+
+    let mut entries = Vec::new();
+    let mut state = [0_i64; 4];
+    for (line, segments) in mappings.split(';').enumerate() {
+        state[0] = 0;
+        for segment in segments.split(',').filter(|s| !s.is_empty()) {
+            let mut field = 0;
+            let mut value = 0_i64;
+            let mut shift = 0;
+            for c in segment.bytes() {
+                let digit = i64::from(base64_digit(c));
+                value |= (digit & 0b1_1111) << shift;
+                shift += 5;
+                if digit & 0b10_0000 == 0 {
+                    let delta = if value & 1 == 1 {
+                        -(value >> 1)
+                    } else {
+                        value >> 1
+                    };
+                    state[field] += delta;
+                    field += 1;
+                    value = 0;
+                    shift = 0;
+                }
+            }
+            assert_eq!(field, 4, "segment {:?}", segment);
+            entries.push((line as i64, state[0], state[1], state[2], state[3]));
+        }
+    }
+    entries
+}
+
+#[test]
+fn no_source_map_by_default() {
+    let input = "a {\n  color: red;\n}";
+    assert_eq!(
+        "a {\n  color: red;\n}\n",
+        &grass::from_string(input.to_string(), &Options::default()).expect(input)
+    );
+}
+
+#[test]
+fn inline_source_map_is_appended() {
+    let (css, json) = compile("a {\n  color: red;\n}");
+    assert_eq!("a {\n  color: red;\n}\n", css);
+    assert!(json.starts_with("{\"version\":3,\"sources\":[\"stdin\"],"));
+}
+
+#[test]
+fn maps_selectors_and_declarations() {
+    let (_, json) = compile("a {\n  color: red;\n  width: 1px + 2px;\n}");
+    assert_eq!(
+        vec![(0, 0, 0, 0, 0), (1, 2, 0, 1, 9), (2, 2, 0, 2, 9)],
+        mappings(&json)
+    );
+}
+
+#[test]
+fn maps_nested_rules() {
+    let (css, json) = compile("a {\n  color: red;\n  b {\n    color: blue;\n  }\n}");
+    assert_eq!("a {\n  color: red;\n}\na b {\n  color: blue;\n}\n", css);
+    assert_eq!(
+        vec![
+            (0, 0, 0, 0, 0),
+            (1, 2, 0, 1, 9),
+            (3, 0, 0, 2, 2),
+            (4, 2, 0, 3, 11)
+        ],
+        mappings(&json)
+    );
+}
+
+#[test]
+fn maps_rules_inside_media() {
+    let (css, json) = compile("@media screen {\n  a {\n    color: red;\n  }\n}");
+    assert_eq!("@media screen {\n  a {\n    color: red;\n  }\n}\n", css);
+    assert_eq!(vec![(1, 2, 0, 1, 2), (2, 4, 0, 2, 11)], mappings(&json));
+}
+
+#[test]
+fn charset_shifts_mappings() {
+    let (css, json) = compile("a {\n  content: \"é\";\n}");
+    assert!(css.starts_with("@charset \"UTF-8\";\n"));
+    assert_eq!(vec![(1, 0, 0, 0, 0), (2, 2, 0, 1, 11)], mappings(&json));
+}
@@ -46,6 +46,56 @@ test!(
     "a {\n  color: cAlC(1 + 1);\n}\n",
     "a {\n  color: calc(1 + 1);\n}\n"
 );
+test!(
+    calc_var_with_interpolated_variable,
+    "$base: 16px;\na {\n  width: calc(var(--gap) * 2 + #{$base});\n}\n",
+    "a {\n  width: calc(var(--gap) * 2 + 16px);\n}\n"
+);
+test!(
+    calc_interpolated_arithmetic_with_units,
+    "$base: 16px;\na {\n  width: calc(100% - #{$base * 2});\n}\n",
+    "a {\n  width: calc(100% - 32px);\n}\n"
+);
+test!(
+    calc_interpolation_within_var_fallback,
+    "$base: 16px;\na {\n  width: calc(1px + var(--x, #{$base}));\n}\n",
+    "a {\n  width: calc(1px + var(--x, 16px));\n}\n"
+);
+test!(
+    calc_does_not_evaluate_division,
+    "a {\n  width: calc(var(--a) / 2);\n}\n"
+);
+test!(
+    calc_parens_in_string,
+    "a {\n  width: calc(var(--a, \")\") + 1px);\n}\n"
+);
+test!(
+    vendor_prefixed_calc,
+    "$base: 16px;\na {\n  width: -webkit-calc(1px + #{$base});\n}\n",
+    "a {\n  width: -webkit-calc(1px + 16px);\n}\n"
+);
+test!(
+    env_with_fallback,
+    "a {\n  padding-top: env(safe-area-inset-top, 16px);\n}\n"
+);
+test!(
+    env_with_custom_property_name_and_fallback,
+    "a {\n  padding-top: env(--safe-inset, 0px);\n}\n"
+);
+test!(
+    env_with_interpolated_fallback,
+    "$default: 16px;\na {\n  padding-top: env(safe-area-inset-top, #{$default});\n}\n",
+    "a {\n  padding-top: env(safe-area-inset-top, 16px);\n}\n"
+);
+test!(
+    env_with_variable_fallback,
+    "$default: 16px;\na {\n  padding-top: env(safe-area-inset-top, $default);\n}\n",
+    "a {\n  padding-top: env(safe-area-inset-top, 16px);\n}\n"
+);
+test!(
+    env_with_space_separated_fallback,
+    "a {\n  padding: env(safe-area-inset, 1px 2px);\n}\n"
+);
 test!(
     element_whitespace,
     "a {\n  color: element(       1      );\n}\n",
@@ -0,0 +1,116 @@
+#![cfg(test)]
+
+#[macro_use]
+mod macros;
+
+/// Calls which should produce the same value through the `sass:string`
+/// module as through the equivalent global function, as
+/// `(module function, global function, arguments)`
+const GLOBAL_ALIASES: &[(&str, &str, &str)] = &[
+    ("quote", "quote", "abc"),
+    ("unquote", "unquote", "\"abc\""),
+    ("index", "str-index", "\"abcd\", \"c\""),
+    ("index", "str-index", "\"abcd\", \"e\""),
+    ("insert", "str-insert", "\"abd\", \"c\", 3"),
+    ("insert", "str-insert", "abd, \"c\", -1"),
+    ("length", "str-length", "\"abc\""),
+    ("slice", "str-slice", "\"abcd\", 2, 3"),
+    ("slice", "str-slice", "\"abcd\", -2"),
+    ("to-upper-case", "to-upper-case", "\"abc\""),
+    ("to-lower-case", "to-lower-case", "ABC"),
+];
+
+fn compile(input: String) -> String {
+    grass::from_string(input.clone(), &grass::Options::default())
+        .unwrap_or_else(|e| panic!("failed to compile {:?}: {}", input, e))
+}
+
+#[test]
+fn module_functions_match_global_functions() {
+    for (module_fn, global_fn, args) in GLOBAL_ALIASES {
+        let namespaced = compile(format!(
+            "@use \"sass:string\";\na {{\n  color: inspect(string.{}({}));\n}}\n",
+            module_fn, args
+        ));
+        let global = compile(format!(
+            "a {{\n  color: inspect({}({}));\n}}\n",
+            global_fn, args
+        ));
+        assert_eq!(global, namespaced, "string.{}({})", module_fn, args);
+    }
+}
+
+test!(
+    string_quote,
+    "@use \"sass:string\";\na {\n  color: string.quote(abc);\n}\n",
+    "a {\n  color: \"abc\";\n}\n"
+);
+test!(
+    string_index,
+    "@use \"sass:string\";\na {\n  color: string.index(\"abcd\", \"c\");\n}\n",
+    "a {\n  color: 3;\n}\n"
+);
+test!(
+    string_with_namespace,
+    "@use \"sass:string\" as str;\na {\n  color: str.length(\"abc\");\n}\n",
+    "a {\n  color: 3;\n}\n"
+);
+test!(
+    string_as_star_prefers_module_function,
+    "@use \"sass:string\" as *;\na {\n  color: index(\"abc\", \"b\");\n}\n",
+    "a {\n  color: 2;\n}\n"
+);
+test!(
+    string_global_aliases_remain,
+    "@use \"sass:string\";\na {\n  color: str-length(\"abc\");\n}\n",
+    "a {\n  color: 3;\n}\n"
+);
+test!(
+    string_split,
+    "@use \"sass:string\";\na {\n  color: string.split(\"a b c\", \" \");\n}\n",
+    "a {\n  color: [\"a\", \"b\", \"c\"];\n}\n"
+);
+test!(
+    string_split_unquoted,
+    "@use \"sass:string\";\na {\n  color: string.split(a-b-c, \"-\");\n}\n",
+    "a {\n  color: [a, b, c];\n}\n"
+);
+test!(
+    string_split_with_limit,
+    "@use \"sass:string\";\na {\n  color: string.split(\"Segoe UI Emoji\", \" \", $limit: 1);\n}\n",
+    "a {\n  color: [\"Segoe\", \"UI Emoji\"];\n}\n"
+);
+test!(
+    string_split_empty_separator,
+    "@use \"sass:string\";\na {\n  color: string.split(\"abc\", \"\");\n}\n",
+    "a {\n  color: [\"a\", \"b\", \"c\"];\n}\n"
+);
+test!(
+    string_split_empty_string,
+    "@use \"sass:string\";\na {\n  color: string.split(\"\", \",\");\n}\n",
+    "a {\n  color: [];\n}\n"
+);
+test!(
+    string_split_length,
+    "@use \"sass:string\";\na {\n  color: length(string.split(\"a,b,,c\", \",\"));\n}\n",
+    "a {\n  color: 4;\n}\n"
+);
+error!(
+    string_split_limit_zero,
+    "@use \"sass:string\";\na {\n  color: string.split(\"a b\", \" \", 0);\n}\n",
+    "Error: $limit: Must be 1 or greater, was 0."
+);
+error!(
+    string_split_separator_not_string,
+    "@use \"sass:string\";\na {\n  color: string.split(\"a b\", 1);\n}\n",
+    "Error: $separator: 1 is not a string."
+);
+test!(
+    string_split_is_not_global,
+    "a {\n  color: split(\"a b\", \" \");\n}\n"
+);
+error!(
+    string_undefined_function,
+    "@use \"sass:string\";\na {\n  color: string.str-length(\"abc\");\n}\n",
+    "Error: Undefined function."
+);
@@ -237,3 +237,8 @@ test!(
     "a {\n  color: \"Xfoo\";\n}\n"
 );
 test!(hash_in_string, "a {\n  color: \"#foo\";\n}\n");
+error!(
+    #[cfg(not(feature = "random"))]
+    unique_id_without_random_feature,
+    "a {\n  color: unique-id();\n}\n", "Error: grass was built without the 'random' feature"
+);
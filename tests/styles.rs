@@ -30,6 +30,17 @@ test!(
     "a b {\n  color: red;\n}\n"
 );
 test!(removes_empty_styles, "a {}\n", "");
+test!(removes_nested_empty_styles, "a {\n  b {}\n}\n", "");
+test!(
+    removes_styles_with_only_null_values,
+    "a {\n  color: null;\n  b {\n    c {\n      color: null;\n    }\n  }\n}\n",
+    ""
+);
+test!(
+    empty_nested_styles_between_styles_emit_one_newline,
+    "a {\n  color: red;\n}\n\nb {\n  c {}\n}\n\nd {\n  color: red;\n}\n",
+    "a {\n  color: red;\n}\n\nd {\n  color: red;\n}\n"
+);
 test!(
     doesnt_eat_style_after_ruleset,
     "a {\n  b {\n  color: red;\n}\n  color: blue;\n}\n",
@@ -0,0 +1,62 @@
+#![cfg(test)]
+
+use std::io::Write;
+
+use grass::{LineEnding, Options, SourceMapOption};
+
+use tempfile::Builder;
+
+#[test]
+fn to_css_matches_from_string() {
+    let input = "$a: red;\na {\n  b {\n    color: $a;\n  }\n}\n";
+    let stylesheet = grass::parse_string(input.to_string(), &Options::default()).expect(input);
+    assert_eq!(
+        grass::from_string(input.to_string(), &Options::default()).expect(input),
+        stylesheet.to_css(&Options::default()).expect(input)
+    );
+}
+
+#[test]
+fn to_css_with_different_options() {
+    let input = "a {\n  color: red;\n}\n";
+    let stylesheet = grass::parse_string(input.to_string(), &Options::default()).expect(input);
+    assert_eq!(
+        "a {\n  color: red;\n}\n",
+        stylesheet.to_css(&Options::default()).expect(input)
+    );
+    assert_eq!(
+        "a {\r\n  color: red;\r\n}\r\n",
+        stylesheet
+            .to_css(&Options::default().line_ending(LineEnding::CrLf))
+            .expect(input)
+    );
+    assert!(stylesheet
+        .to_css(&Options::default().source_map(SourceMapOption::Inline))
+        .expect(input)
+        .contains("/*# sourceMappingURL=data:application/json;base64,"));
+}
+
+#[test]
+fn parse_string_errors_before_to_css() {
+    match grass::parse_string("a {\n  color: $a;\n}\n".to_string(), &Options::default()) {
+        Ok(..) => panic!("did not fail"),
+        Err(e) => assert_eq!(
+            "Error: Undefined variable.",
+            e.to_string().lines().next().unwrap()
+        ),
+    }
+}
+
+#[test]
+fn parse_path() {
+    let mut file = Builder::new().suffix(".scss").tempfile().unwrap();
+    write!(file, "a {{\n  color: red;\n}}\n").unwrap();
+    let path = file.path().to_str().unwrap();
+    assert_eq!(
+        "a {\n  color: red;\n}\n",
+        grass::parse_path(path, &Options::default())
+            .unwrap()
+            .to_css(&Options::default())
+            .unwrap()
+    );
+}
@@ -16,3 +16,8 @@ test!(
     "@false;\n"
 );
 test!(nothing_after_hash, "@foo #", "@foo #;\n");
+test!(
+    font_face_in_mixin_included_at_root,
+    "@mixin font {\n  @font-face {\n    font-family: foo;\n  }\n}\na {\n  color: red;\n}\n@include font;\n",
+    "a {\n  color: red;\n}\n\n@font-face {\n  font-family: foo;\n}\n"
+);
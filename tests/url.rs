@@ -146,3 +146,8 @@ error!(
     url_nothing_after_hash_in_interpolation_in_quote,
     "a { color: url(#{\"#", "Error: Expected \"."
 );
+test!(
+    url_value_followed_by_closing_brace,
+    "a {\n  b: url(foo.css)}\nc {\n  color: red;\n}\n",
+    "a {\n  b: url(foo.css);\n}\n\nc {\n  color: red;\n}\n"
+);
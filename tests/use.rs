@@ -0,0 +1,362 @@
+#![cfg(test)]
+
+#[macro_use]
+mod macros;
+
+#[test]
+fn use_default_namespace() {
+    let input = "@use \"use_default_ns__a\";\na {\n color: use_default_ns__a.$a;\n}";
+    tempfile!("use_default_ns__a.scss", "$a: red;");
+    assert_eq!(
+        "a {\n  color: red;\n}\n",
+        &grass::from_string(input.to_string(), &grass::Options::default()).expect(input)
+    );
+}
+
+#[test]
+fn use_as_namespace() {
+    let input = "@use \"use_as_ns__a\" as foo;\na {\n color: foo.$a;\n}";
+    tempfile!("use_as_ns__a.scss", "$a: red;");
+    assert_eq!(
+        "a {\n  color: red;\n}\n",
+        &grass::from_string(input.to_string(), &grass::Options::default()).expect(input)
+    );
+}
+
+#[test]
+fn use_as_star() {
+    let input = "@use \"use_as_star__a\" as *;\na {\n color: $a;\n @include m;\n width: f();\n}";
+    tempfile!(
+        "use_as_star__a.scss",
+        "$a: red;\n@mixin m {\n height: 1px;\n}\n@function f() {\n @return 2px;\n}"
+    );
+    assert_eq!(
+        "a {\n  color: red;\n  height: 1px;\n  width: 2px;\n}\n",
+        &grass::from_string(input.to_string(), &grass::Options::default()).expect(input)
+    );
+}
+
+#[test]
+fn use_members_see_their_own_module() {
+    let input =
+        "$a: blue;\n@use \"use_own_module__a\" as m;\na {\n color: m.f();\n @include m.g;\n}";
+    tempfile!(
+        "use_own_module__a.scss",
+        "$a: red;\n@function f() {\n @return $a;\n}\n@mixin g {\n width: $a;\n}"
+    );
+    assert_eq!(
+        "a {\n  color: red;\n  width: red;\n}\n",
+        &grass::from_string(input.to_string(), &grass::Options::default()).expect(input)
+    );
+}
+
+#[test]
+fn use_content_block_sees_caller() {
+    let input =
+        "@use \"use_content__a\" as m;\n$a: blue;\na {\n @include m.g {\n color: $a;\n }\n}";
+    tempfile!(
+        "use_content__a.scss",
+        "$a: red;\n@mixin g {\n width: $a;\n @content;\n}"
+    );
+    assert_eq!(
+        "a {\n  width: red;\n  color: blue;\n}\n",
+        &grass::from_string(input.to_string(), &grass::Options::default()).expect(input)
+    );
+}
+
+#[test]
+fn use_emits_css() {
+    let input = "@use \"use_css__a\";\nb {\n color: red;\n}";
+    tempfile!("use_css__a.scss", "a {\n color: red;\n}");
+    assert_eq!(
+        "a {\n  color: red;\n}\n\nb {\n  color: red;\n}\n",
+        &grass::from_string(input.to_string(), &grass::Options::default()).expect(input)
+    );
+}
+
+error!(
+    use_unknown_namespace,
+    "a {\n color: foo.$a;\n}", "Error: There is no module with the namespace \"foo\"."
+);
+error!(
+    use_unknown_namespace_function_call,
+    "a {\n color: foo.bar(1);\n}", "Error: There is no module with the namespace \"foo\"."
+);
+error!(
+    use_unknown_namespace_mixin,
+    "a {\n @include foo.bar;\n}", "Error: There is no module with the namespace \"foo\"."
+);
+error!(
+    use_builtin_module_undefined_function,
+    "@use \"sass:math\";\na {\n color: math.bar(1);\n}", "Error: Undefined function."
+);
+error!(
+    use_builtin_module_undefined_mixin,
+    "@use \"sass:math\";\na {\n @include math.bar;\n}", "Error: Undefined mixin."
+);
+test!(
+    use_builtin_module_function,
+    "@use \"sass:math\";\na {\n color: math.floor(1.5);\n}",
+    "a {\n  color: 1;\n}\n"
+);
+error!(
+    use_missing_file,
+    "@use \"use_missing_file__does_not_exist\";", "Error: Can't find stylesheet to import."
+);
+
+#[test]
+fn use_with_configures_default_variables() {
+    let input = "@use \"use_with__a\" with ($a: blue, $b: 2px);\nb {\n color: use_with__a.$a;\n}";
+    tempfile!(
+        "use_with__a.scss",
+        "$a: red !default;\n$b: 1px !default;\na {\n color: $a;\n width: $b;\n}"
+    );
+    assert_eq!(
+        "a {\n  color: blue;\n  width: 2px;\n}\n\nb {\n  color: blue;\n}\n",
+        &grass::from_string(input.to_string(), &grass::Options::default()).expect(input)
+    );
+}
+
+#[test]
+fn use_with_null_keeps_default() {
+    let input = "@use \"use_with_null__a\" with ($a: null);\nb {\n color: use_with_null__a.$a;\n}";
+    tempfile!("use_with_null__a.scss", "$a: red !default;");
+    assert_eq!(
+        "b {\n  color: red;\n}\n",
+        &grass::from_string(input.to_string(), &grass::Options::default()).expect(input)
+    );
+}
+
+#[test]
+fn use_with_variable_not_default() {
+    let input = "@use \"use_with_not_default__a\" with ($a: blue);";
+    tempfile!("use_with_not_default__a.scss", "$a: red;");
+    match grass::from_string(input.to_string(), &grass::Options::default()) {
+        Ok(..) => panic!("did not fail"),
+        Err(e) => assert!(e.to_string().starts_with(
+            "Error: This variable was not declared with !default in the @used module."
+        )),
+    }
+}
+
+#[test]
+fn use_with_variable_not_declared() {
+    let input = "@use \"use_with_not_declared__a\" with ($b: blue);";
+    tempfile!("use_with_not_declared__a.scss", "$a: red !default;");
+    match grass::from_string(input.to_string(), &grass::Options::default()) {
+        Ok(..) => panic!("did not fail"),
+        Err(e) => assert!(e.to_string().starts_with(
+            "Error: This variable was not declared with !default in the @used module."
+        )),
+    }
+}
+
+#[test]
+fn use_with_variable_default_only_in_nested_scope() {
+    tempfile!(
+        "use_with_nested_default__a.scss",
+        "@mixin m {\n  $a: red !default;\n}\na {\n  $b: red !default;\n}"
+    );
+    for input in &[
+        "@use \"use_with_nested_default__a\" with ($a: blue);",
+        "@use \"use_with_nested_default__a\" with ($b: blue);",
+    ] {
+        match grass::from_string(input.to_string(), &grass::Options::default()) {
+            Ok(..) => panic!("did not fail: {}", input),
+            Err(e) => assert!(e.to_string().starts_with(
+                "Error: This variable was not declared with !default in the @used module."
+            )),
+        }
+    }
+}
+
+#[test]
+fn use_with_module_already_loaded() {
+    let input = "@use \"use_with_loaded__a\";\n@use \"use_with_loaded__a\" as b with ($a: blue);";
+    tempfile!("use_with_loaded__a.scss", "$a: red !default;");
+    match grass::from_string(input.to_string(), &grass::Options::default()) {
+        Ok(..) => panic!("did not fail"),
+        Err(e) => assert!(e.to_string().starts_with(
+            "Error: This module was already loaded, so it can't be configured using \"with\"."
+        )),
+    }
+}
+
+#[test]
+fn load_css_at_root() {
+    let input = "@use \"sass:meta\";\n@include meta.load-css(\"load_css_root__a\");";
+    tempfile!("load_css_root__a.scss", "a {\n color: red;\n}");
+    assert_eq!(
+        "a {\n  color: red;\n}\n",
+        &grass::from_string(input.to_string(), &grass::Options::default()).expect(input)
+    );
+}
+
+#[test]
+fn load_css_nested_in_style_rule() {
+    let input = "@use \"sass:meta\";\n.dark {\n @include meta.load-css(\"load_css_nested__a\");\n}";
+    tempfile!("load_css_nested__a.scss", "a {\n color: red;\n}");
+    assert_eq!(
+        ".dark a {\n  color: red;\n}\n",
+        &grass::from_string(input.to_string(), &grass::Options::default()).expect(input)
+    );
+}
+
+#[test]
+fn load_css_inside_mixin_with_configuration() {
+    let input = "@use \"sass:meta\";\n@mixin theme($color) {\n .theme {\n @include meta.load-css(\"load_css_mixin__a\", $with: (\"color\": $color));\n }\n}\n@include theme(blue);\n@include theme(green);";
+    tempfile!(
+        "load_css_mixin__a.scss",
+        "$color: red !default;\na {\n color: $color;\n}"
+    );
+    assert_eq!(
+        ".theme a {\n  color: blue;\n}\n\n.theme a {\n  color: green;\n}\n",
+        &grass::from_string(input.to_string(), &grass::Options::default()).expect(input)
+    );
+}
+
+#[test]
+fn load_css_builtin_module_is_empty() {
+    let input = "@use \"sass:meta\";\n@include meta.load-css(\"sass:meta\");\na {\n color: red;\n}";
+    assert_eq!(
+        "a {\n  color: red;\n}\n",
+        &grass::from_string(input.to_string(), &grass::Options::default()).expect(input)
+    );
+}
+
+error!(
+    load_css_with_not_map,
+    "@use \"sass:meta\";\n@include meta.load-css(\"a\", $with: 1);",
+    "Error: $with: 1 is not a map."
+);
+error!(
+    load_css_content_block,
+    "@use \"sass:meta\";\n@include meta.load-css(\"a\") {}",
+    "Error: Mixin doesn't accept a content block."
+);
+error!(
+    use_configure_builtin_module,
+    "@use \"sass:meta\" with ($a: 1);", "Error: Built-in modules can't be configured."
+);
+
+#[test]
+fn use_assign_module_variable() {
+    tempfile!(
+        "use_assign_var__a.scss",
+        "$a: red;\n@function get() {\n @return $a;\n}"
+    );
+    for input in &[
+        "@use \"use_assign_var__a\" as lib;\nlib.$a: blue;",
+        "@use \"use_assign_var__a\" as lib;\nlib.$a: blue !default;",
+        "@use \"use_assign_var__a\" as lib;\na {\n lib.$a: blue;\n}",
+    ] {
+        match grass::from_string(input.to_string(), &grass::Options::default()) {
+            Ok(..) => panic!("did not fail: {}", input),
+            Err(e) => assert_eq!(
+                "Error: Cannot modify variable in other module.",
+                e.to_string()
+                    .chars()
+                    .take_while(|c| *c != '\n')
+                    .collect::<String>()
+                    .as_str()
+            ),
+        }
+    }
+}
+
+#[test]
+fn use_assign_undefined_module_variable() {
+    let input = "@use \"use_assign_undefined__a\" as lib;\nlib.$b: blue;";
+    tempfile!("use_assign_undefined__a.scss", "$a: red;");
+    match grass::from_string(input.to_string(), &grass::Options::default()) {
+        Ok(..) => panic!("did not fail"),
+        Err(e) => assert_eq!(
+            "Error: Undefined variable.",
+            e.to_string()
+                .chars()
+                .take_while(|c| *c != '\n')
+                .collect::<String>()
+                .as_str()
+        ),
+    }
+}
+
+#[test]
+fn use_private_members_are_inaccessible() {
+    tempfile!(
+        "use_private__a.scss",
+        "$_a: red;\n$-b: blue;\n@function _f() {\n  @return 1;\n}\n@mixin -m {\n  color: red;\n}\n"
+    );
+    for input in &[
+        "@use \"use_private__a\" as m;\na {\n color: m.$_a;\n}",
+        "@use \"use_private__a\" as m;\na {\n color: m.$-b;\n}",
+        "@use \"use_private__a\" as m;\nm.$_a: blue;",
+        "@use \"use_private__a\" as m;\na {\n color: m._f();\n}",
+        "@use \"use_private__a\" as m;\na {\n @include m.-m;\n}",
+    ] {
+        match grass::from_string(input.to_string(), &grass::Options::default()) {
+            Ok(..) => panic!("did not fail: {}", input),
+            Err(e) => assert!(
+                e.to_string().starts_with(
+                    "Error: Private members can't be accessed from outside their modules."
+                ),
+                "{}",
+                e
+            ),
+        }
+    }
+}
+
+#[test]
+fn use_private_members_are_hidden() {
+    let input = "@use \"use_private_hidden__a\" as m;\n@use \"sass:meta\";\na {\n color: meta.inspect(meta.module-variables(m));\n color: m.f();\n}";
+    tempfile!(
+        "use_private_hidden__a.scss",
+        "$a: red;\n$_b: blue;\n@function f() {\n  @return $_b;\n}\n"
+    );
+    assert_eq!(
+        "a {\n  color: (\"a\": red);\n  color: blue;\n}\n",
+        &grass::from_string(input.to_string(), &grass::Options::default()).expect(input)
+    );
+}
+
+#[test]
+fn use_private_members_not_available_globally() {
+    let input = "@use \"use_private_global__a\" as *;\na {\n color: $_a;\n}";
+    tempfile!("use_private_global__a.scss", "$_a: red;\n");
+    match grass::from_string(input.to_string(), &grass::Options::default()) {
+        Ok(..) => panic!("did not fail"),
+        Err(e) => assert!(e.to_string().starts_with("Error: Undefined variable.")),
+    }
+}
+
+#[test]
+fn use_partial_with_explicit_underscore() {
+    let input = "@use \"_use_explicit_partial\";\na {\n color: use_explicit_partial.$a;\n}";
+    tempfile!("_use_explicit_partial.scss", "$a: red;");
+    assert_eq!(
+        "a {\n  color: red;\n}\n",
+        &grass::from_string(input.to_string(), &grass::Options::default()).expect(input)
+    );
+}
+
+#[test]
+fn use_partial_without_underscore() {
+    let input = "@use \"use_implicit_partial\";\na {\n color: use_implicit_partial.$a;\n}";
+    tempfile!("_use_implicit_partial.scss", "$a: red;");
+    assert_eq!(
+        "a {\n  color: red;\n}\n",
+        &grass::from_string(input.to_string(), &grass::Options::default()).expect(input)
+    );
+}
+
+#[test]
+fn use_partial_with_explicit_underscore_and_extension() {
+    let input =
+        "@use \"_use_explicit_partial_ext.scss\";\na {\n color: use_explicit_partial_ext.$a;\n}";
+    tempfile!("_use_explicit_partial_ext.scss", "$a: red;");
+    assert_eq!(
+        "a {\n  color: red;\n}\n",
+        &grass::from_string(input.to_string(), &grass::Options::default()).expect(input)
+    );
+}
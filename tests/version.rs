@@ -0,0 +1,43 @@
+#![cfg(test)]
+
+use grass::{Version, VERSION};
+
+#[test]
+fn current_matches_version_constant() {
+    assert_eq!(VERSION, env!("CARGO_PKG_VERSION"));
+    assert_eq!(Version::current().to_string(), VERSION);
+}
+
+#[test]
+fn parse_version() {
+    assert_eq!(
+        Some(Version {
+            major: 1,
+            minor: 32,
+            patch: 0
+        }),
+        Version::parse("1.32.0")
+    );
+}
+
+#[test]
+fn parse_ignores_pre_release_and_build_metadata() {
+    assert_eq!(
+        Version::parse("0.10.0"),
+        Version::parse("0.10.0-beta.1+abc")
+    );
+}
+
+#[test]
+fn parse_invalid_version() {
+    assert_eq!(None, Version::parse("1.2"));
+    assert_eq!(None, Version::parse("1.2.3.4"));
+    assert_eq!(None, Version::parse("1.x.3"));
+    assert_eq!(None, Version::parse(""));
+}
+
+#[test]
+fn versions_are_ordered() {
+    assert!(Version::parse("0.9.5") < Version::parse("0.10.0"));
+    assert!(Version::parse("1.0.0") > Version::parse("0.10.0"));
+}
@@ -0,0 +1,124 @@
+#![cfg(test)]
+
+#[macro_use]
+mod macros;
+
+test!(
+    warn_does_not_halt_compilation,
+    "a {\n  @warn \"hi\";\n  color: red;\n}\n",
+    "a {\n  color: red;\n}\n"
+);
+test!(
+    warn_inside_mixin,
+    "@mixin foo {\n  @warn \"hi\";\n  color: red;\n}\n\na {\n  @include foo;\n}\n",
+    "a {\n  color: red;\n}\n"
+);
+test!(
+    warn_inside_function_inside_mixin,
+    "@function foo() {\n  @warn \"hi\";\n  @return red;\n}\n\n@mixin bar {\n  color: foo();\n}\n\na {\n  @include bar;\n  color: foo();\n}\n",
+    "a {\n  color: red;\n  color: red;\n}\n"
+);
+error!(
+    warn_undefined_variable,
+    "a {\n  @warn $foo;\n}\n", "Error: Undefined variable."
+);
+test!(
+    warn_function_reference,
+    "a {\n  @warn get-function(darken);\n  color: red;\n}\n",
+    "a {\n  color: red;\n}\n"
+);
+test!(
+    warn_map,
+    "a {\n  @warn (a: b);\n  color: red;\n}\n",
+    "a {\n  color: red;\n}\n"
+);
+
+/// Compile `path` with the `grass` binary, which must succeed, returning what
+/// it wrote to stdout and stderr
+#[cfg(feature = "commandline")]
+fn compile_with_output(path: &str) -> (String, String) {
+    let output = std::process::Command::new(env!("CARGO_BIN_EXE_grass"))
+        .arg(path)
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    (
+        String::from_utf8(output.stdout).unwrap(),
+        String::from_utf8(output.stderr).unwrap(),
+    )
+}
+
+/// The members of the stack trace line up after the longest location
+#[test]
+#[cfg(feature = "commandline")]
+fn warn_stack_trace_format() {
+    tempfile!(
+        "warn_stack_trace_format.scss",
+        "@function foo() {\n  @warn \"hi\";\n  @return red;\n}\n\n@mixin bar {\n  color: foo();\n}\n\na {\n  @include bar;\n}\n"
+    );
+    assert_eq!(
+        "WARNING: hi\n    \
+         warn_stack_trace_format.scss 2:3   foo()\n    \
+         warn_stack_trace_format.scss 7:14  bar()\n    \
+         warn_stack_trace_format.scss 11:3  root stylesheet\n",
+        compile_with_output("warn_stack_trace_format.scss").1
+    );
+}
+
+#[test]
+#[cfg(feature = "commandline")]
+fn warn_does_not_halt_compilation_output() {
+    tempfile!(
+        "warn_does_not_halt_compilation_output.scss",
+        "a {\n  @warn \"hi\";\n  color: red;\n}\n"
+    );
+    assert_eq!(
+        (
+            "a {\n  color: red;\n}\n".to_owned(),
+            "WARNING: hi\n    \
+             warn_does_not_halt_compilation_output.scss 2:3  root stylesheet\n"
+                .to_owned()
+        ),
+        compile_with_output("warn_does_not_halt_compilation_output.scss")
+    );
+}
+
+#[test]
+#[cfg(feature = "commandline")]
+fn warn_inside_mixin_output() {
+    tempfile!(
+        "warn_inside_mixin_output.scss",
+        "@mixin foo {\n  @warn \"hi\";\n  color: red;\n}\n\na {\n  @include foo;\n}\n"
+    );
+    assert_eq!(
+        (
+            "a {\n  color: red;\n}\n".to_owned(),
+            "WARNING: hi\n    \
+             warn_inside_mixin_output.scss 2:3  foo()\n    \
+             warn_inside_mixin_output.scss 7:3  root stylesheet\n"
+                .to_owned()
+        ),
+        compile_with_output("warn_inside_mixin_output.scss")
+    );
+}
+
+/// Values other than strings are printed as `inspect()` would
+#[test]
+#[cfg(feature = "commandline")]
+fn warn_value_output() {
+    tempfile!(
+        "warn_value_output.scss",
+        "a {\n  @warn (a: b);\n  @warn get-function(darken);\n  color: red;\n}\n"
+    );
+    assert_eq!(
+        (
+            "a {\n  color: red;\n}\n".to_owned(),
+            "WARNING: (a: b)\n    \
+             warn_value_output.scss 2:3  root stylesheet\n\
+             WARNING: get-function(\"darken\")\n    \
+             warn_value_output.scss 3:3  root stylesheet\n"
+                .to_owned()
+        ),
+        compile_with_output("warn_value_output.scss")
+    );
+}
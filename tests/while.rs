@@ -125,6 +125,26 @@ test!(
     }",
     "a {\n  color: red;\n}\n\na {\n  color: blue;\n}\n"
 );
+test!(
+    counter_in_interpolated_selector,
+    "$i: 0;\n@while $i < 3 {\n  .item-#{$i} {\n    order: $i;\n  }\n  $i: $i + 1;\n}\n",
+    ".item-0 {\n  order: 0;\n}\n\n.item-1 {\n  order: 1;\n}\n\n.item-2 {\n  order: 2;\n}\n"
+);
+test!(
+    counter_keeps_final_value_after_loop,
+    "$i: 0;\n@while $i < 5 {\n  $i: $i + 1;\n}\na {\n  color: $i;\n}\n",
+    "a {\n  color: 5;\n}\n"
+);
+test!(
+    assignment_updates_enclosing_local,
+    "a {\n  $i: 0;\n  @while $i < 2 {\n    $i: $i + 1;\n  }\n  color: $i;\n}\n",
+    "a {\n  color: 2;\n}\n"
+);
+error!(
+    new_variable_not_visible_after_loop,
+    "a {\n  $i: 0;\n  @while $i < 2 {\n    $i: $i + 1;\n    $j: $i;\n  }\n  color: $j;\n}\n",
+    "Error: Undefined variable."
+);
 error!(
     missing_closing_curly_brace,
     "@while true {", "Error: expected \"}\"."